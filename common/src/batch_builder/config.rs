@@ -1,4 +1,7 @@
+use super::AdaptiveMinTxsConfig;
+use crate::fork_info::ForkInfo;
 use alloy::primitives::Address;
+use std::time::Duration;
 
 /// Configuration for batching L2 transactions
 #[derive(Clone)]
@@ -17,15 +20,80 @@ pub struct BatchBuilderConfig {
     pub default_coinbase: Address,
     /// Minimum number of transactions in a preconfirmed block
     pub preconf_min_txs: u64,
+    /// Adaptive `preconf_min_txs` parameters, static threshold is used when not set
+    pub preconf_min_txs_adaptive: Option<AdaptiveMinTxsConfig>,
     /// Maximum number of skipped slots in a preconfirmed block
     pub preconf_max_skipped_l2_slots: u64,
+    /// Maximum number of skipped slots without pending transactions before an empty block
+    /// is produced, `preconf_max_skipped_l2_slots` is used when not set
+    pub force_empty_block_after_skipped_l2_slots: Option<u64>,
     /// Duration in seconds for which we build a proposal before sending it to L1
     pub proposal_max_time_sec: u64,
     /// Maximum number of forced inclusions in a proposal
     pub max_forced_inclusions: u16,
+    /// Age in seconds after which a proposal is submitted even if it is not full
+    pub max_batch_age_sec: Option<u64>,
+    /// Fork limits held back while the conservative limits of a fork switch grace window apply
+    pub fork_switch_grace: Option<ForkSwitchGrace>,
+}
+
+/// Protocol limits of a fork that constrain the batch size.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ForkBatchLimits {
+    pub max_blocks_per_batch: u16,
+    pub max_anchor_height_offset: u64,
+}
+
+/// Fork limits to restore once the fork switch grace window is over.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ForkSwitchGrace {
+    pub grace_period_end: Duration,
+    pub fork_limits: ForkBatchLimits,
 }
 
 impl BatchBuilderConfig {
+    /// Applies the more conservative of the configured and the previous fork limits while
+    /// within the grace window after the switch to the current fork.
+    /// Returns the end of the grace window when the limits were applied.
+    pub fn apply_fork_switch_grace_limits(
+        &mut self,
+        fork_info: &ForkInfo,
+        current_time: Duration,
+        previous_fork_limits: ForkBatchLimits,
+    ) -> Option<Duration> {
+        let grace_period_end = fork_info.fork_switch_grace_period_end(current_time)?;
+        self.fork_switch_grace = Some(ForkSwitchGrace {
+            grace_period_end,
+            fork_limits: ForkBatchLimits {
+                max_blocks_per_batch: self.max_blocks_per_batch,
+                max_anchor_height_offset: self.max_anchor_height_offset,
+            },
+        });
+        self.max_blocks_per_batch = self
+            .max_blocks_per_batch
+            .min(previous_fork_limits.max_blocks_per_batch);
+        self.max_anchor_height_offset = self
+            .max_anchor_height_offset
+            .min(previous_fork_limits.max_anchor_height_offset);
+        Some(grace_period_end)
+    }
+
+    /// Restores the fork limits once the grace window is over. Called when a new batch is
+    /// started, so a batch is never built under two different sets of limits.
+    /// Returns true when the fork limits were restored.
+    pub fn end_fork_switch_grace_if_over(&mut self, current_time: Duration) -> bool {
+        let Some(grace) = self.fork_switch_grace else {
+            return false;
+        };
+        if current_time < grace.grace_period_end {
+            return false;
+        }
+        self.max_blocks_per_batch = grace.fork_limits.max_blocks_per_batch;
+        self.max_anchor_height_offset = grace.fork_limits.max_anchor_height_offset;
+        self.fork_switch_grace = None;
+        true
+    }
+
     pub fn is_within_block_limit(&self, num_blocks: u16) -> bool {
         num_blocks <= self.max_blocks_per_batch
     }
@@ -38,4 +106,112 @@ impl BatchBuilderConfig {
         let elapsed_time_sec = current_time.saturating_sub(created_at);
         elapsed_time_sec <= self.proposal_max_time_sec
     }
+
+    /// Maximum number of L2 slots that can be skipped before a new block has to be created.
+    pub fn max_skipped_l2_slots(&self, number_of_pending_txs: u64) -> u64 {
+        match self.force_empty_block_after_skipped_l2_slots {
+            Some(max_skipped_slots) if number_of_pending_txs == 0 => max_skipped_slots,
+            _ => self.preconf_max_skipped_l2_slots,
+        }
+    }
+
+    pub fn is_older_than_max_batch_age(&self, created_at: u64, current_time: u64) -> bool {
+        self.max_batch_age_sec
+            .is_some_and(|max_age| current_time.saturating_sub(created_at) >= max_age)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fork_info::{Fork, config::ForkInfoConfig};
+
+    fn make_config() -> BatchBuilderConfig {
+        BatchBuilderConfig {
+            max_bytes_size_of_batch: 1000,
+            max_blocks_per_batch: 768,
+            l1_slot_duration_sec: 12,
+            max_time_shift_between_blocks_sec: 255,
+            max_anchor_height_offset: 256,
+            default_coinbase: Address::ZERO,
+            preconf_min_txs: 0,
+            preconf_min_txs_adaptive: None,
+            preconf_max_skipped_l2_slots: 0,
+            force_empty_block_after_skipped_l2_slots: None,
+            proposal_max_time_sec: 0,
+            max_forced_inclusions: 0,
+            max_batch_age_sec: None,
+            fork_switch_grace: None,
+        }
+    }
+
+    #[test]
+    fn test_conservative_limits_during_fork_switch_grace_period() {
+        let fork_info = ForkInfo {
+            fork: Fork::Permissionless,
+            config: ForkInfoConfig {
+                fork_switch_timestamps: vec![
+                    Duration::from_secs(0),           // Shasta
+                    Duration::from_secs(100),         // Permissionless
+                    Duration::from_secs(99999999999), // Realtime
+                ],
+                fork_switch_transition_period: Duration::from_secs(15),
+                fork_switch_grace_period: Duration::from_secs(30),
+            },
+        };
+        let previous_fork_limits = ForkBatchLimits {
+            max_blocks_per_batch: 384,
+            max_anchor_height_offset: 128,
+        };
+
+        // At the fork boundary
+        let mut config = make_config();
+        assert_eq!(
+            config.apply_fork_switch_grace_limits(
+                &fork_info,
+                Duration::from_secs(100),
+                previous_fork_limits
+            ),
+            Some(Duration::from_secs(130))
+        );
+        assert_eq!(config.max_blocks_per_batch, 384);
+        assert_eq!(config.max_anchor_height_offset, 128);
+
+        // A batch started within the grace window keeps the conservative limits
+        assert!(!config.end_fork_switch_grace_if_over(Duration::from_secs(129)));
+        assert_eq!(config.max_blocks_per_batch, 384);
+
+        // The first batch started after the grace window gets the fork limits
+        assert!(config.end_fork_switch_grace_if_over(Duration::from_secs(130)));
+        assert_eq!(config.max_blocks_per_batch, 768);
+        assert_eq!(config.max_anchor_height_offset, 256);
+        assert!(config.fork_switch_grace.is_none());
+        assert!(!config.end_fork_switch_grace_if_over(Duration::from_secs(131)));
+
+        // Last second of the grace window
+        let mut config = make_config();
+        assert!(
+            config
+                .apply_fork_switch_grace_limits(
+                    &fork_info,
+                    Duration::from_secs(129),
+                    previous_fork_limits
+                )
+                .is_some()
+        );
+        assert_eq!(config.max_anchor_height_offset, 128);
+
+        // After the grace window
+        let mut config = make_config();
+        assert_eq!(
+            config.apply_fork_switch_grace_limits(
+                &fork_info,
+                Duration::from_secs(130),
+                previous_fork_limits
+            ),
+            None
+        );
+        assert_eq!(config.max_blocks_per_batch, 768);
+        assert_eq!(config.max_anchor_height_offset, 256);
+    }
 }
@@ -157,7 +157,7 @@ impl<B: BatchLike, F> BatchBuilderCore<B, F> {
 
         let number_of_l2_slots = (current_l2_slot_timestamp - self.last_l2_block_timestamp) * 1000
             / self.slot_clock.get_preconf_heartbeat_ms();
-        number_of_l2_slots > self.config.preconf_max_skipped_l2_slots
+        number_of_l2_slots > self.config.max_skipped_l2_slots(number_of_pending_txs)
     }
 
     /// Checks if an empty block is required to prevent time shift overflow.
@@ -373,9 +373,13 @@ mod tests {
                 max_anchor_height_offset: 10,
                 default_coinbase: Address::ZERO,
                 preconf_min_txs: 5,
+                preconf_min_txs_adaptive: None,
                 preconf_max_skipped_l2_slots: 3,
+                force_empty_block_after_skipped_l2_slots: None,
                 proposal_max_time_sec: 100,
                 max_forced_inclusions: 10,
+                max_batch_age_sec: None,
+                fork_switch_grace: None,
             },
             Arc::new(SlotClock::new(0, 5, 12, 32, 3000)),
             Arc::new(Metrics::new()),
@@ -397,9 +401,13 @@ mod tests {
             max_anchor_height_offset: 10,
             default_coinbase: Address::ZERO,
             preconf_min_txs: 5,
+            preconf_min_txs_adaptive: None,
             preconf_max_skipped_l2_slots: 3,
+            force_empty_block_after_skipped_l2_slots: None,
             proposal_max_time_sec: 100,
             max_forced_inclusions: 10,
+            max_batch_age_sec: None,
+            fork_switch_grace: None,
         };
 
         let slot_clock = Arc::new(SlotClock::new(0, 5, 12, 32, 2000));
@@ -0,0 +1,133 @@
+//! Controller deciding the minimum number of pending transactions required to preconfirm a block.
+
+use std::collections::VecDeque;
+
+/// Parameters of the adaptive `preconf_min_txs` mode.
+#[derive(Debug, Clone)]
+pub struct AdaptiveMinTxsConfig {
+    /// Threshold used when the mempool is busy
+    pub lower_min_txs: u64,
+    /// Threshold used when the mempool is idle
+    pub upper_min_txs: u64,
+    /// Average mempool depth at which the mempool is considered busy
+    pub busy_mempool_depth: u64,
+    /// Number of recent mempool depth samples taken into account
+    pub window: usize,
+}
+
+/// Derives the `preconf_min_txs` threshold fed into `should_new_block_be_created`.
+///
+/// In static mode the configured value is returned unchanged. In adaptive mode the
+/// threshold is interpolated between `upper_min_txs` (idle) and `lower_min_txs` (busy)
+/// based on the average of the recently observed mempool depths.
+#[derive(Clone)]
+pub struct MinTxsController {
+    static_min_txs: u64,
+    adaptive: Option<AdaptiveMinTxsConfig>,
+    recent_depths: VecDeque<u64>,
+}
+
+impl MinTxsController {
+    pub fn new(static_min_txs: u64, adaptive: Option<AdaptiveMinTxsConfig>) -> Self {
+        let capacity = adaptive.as_ref().map_or(0, |config| config.window);
+        Self {
+            static_min_txs,
+            adaptive,
+            recent_depths: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Records the number of pending transactions seen in the current L2 slot.
+    pub fn observe_mempool_depth(&mut self, depth: u64) {
+        let Some(config) = self.adaptive.as_ref() else {
+            return;
+        };
+        if self.recent_depths.len() >= config.window {
+            self.recent_depths.pop_front();
+        }
+        self.recent_depths.push_back(depth);
+    }
+
+    /// Returns the current minimum number of transactions required to create a block.
+    pub fn min_txs(&self) -> u64 {
+        let Some(config) = self.adaptive.as_ref() else {
+            return self.static_min_txs;
+        };
+
+        if self.recent_depths.is_empty() {
+            return self
+                .static_min_txs
+                .clamp(config.lower_min_txs, config.upper_min_txs);
+        }
+
+        let average_depth =
+            self.recent_depths.iter().sum::<u64>() / self.recent_depths.len() as u64;
+        if average_depth >= config.busy_mempool_depth {
+            return config.lower_min_txs;
+        }
+
+        let range = config.upper_min_txs - config.lower_min_txs;
+        config.upper_min_txs - range * average_depth / config.busy_mempool_depth
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn adaptive_controller() -> MinTxsController {
+        MinTxsController::new(
+            5,
+            Some(AdaptiveMinTxsConfig {
+                lower_min_txs: 1,
+                upper_min_txs: 11,
+                busy_mempool_depth: 50,
+                window: 4,
+            }),
+        )
+    }
+
+    #[test]
+    fn test_static_mode_ignores_mempool_depth() {
+        let mut controller = MinTxsController::new(5, None);
+        assert_eq!(controller.min_txs(), 5);
+
+        controller.observe_mempool_depth(1000);
+        assert_eq!(controller.min_txs(), 5);
+    }
+
+    #[test]
+    fn test_adaptive_mode_without_samples_uses_static_value() {
+        let controller = adaptive_controller();
+        assert_eq!(controller.min_txs(), 5);
+    }
+
+    #[test]
+    fn test_adaptive_mode_across_busy_and_idle_inputs() {
+        let mut controller = adaptive_controller();
+
+        // Idle: empty mempool raises the threshold to the upper bound
+        for _ in 0..4 {
+            controller.observe_mempool_depth(0);
+        }
+        assert_eq!(controller.min_txs(), 11);
+
+        // Half busy: threshold is interpolated
+        for _ in 0..4 {
+            controller.observe_mempool_depth(25);
+        }
+        assert_eq!(controller.min_txs(), 6);
+
+        // Busy: deep mempool lowers the threshold to the lower bound
+        for _ in 0..4 {
+            controller.observe_mempool_depth(200);
+        }
+        assert_eq!(controller.min_txs(), 1);
+
+        // Back to idle: old samples fall out of the window
+        for _ in 0..4 {
+            controller.observe_mempool_depth(0);
+        }
+        assert_eq!(controller.min_txs(), 11);
+    }
+}
@@ -2,8 +2,10 @@
 
 mod config;
 mod core;
+mod min_txs_controller;
 mod traits;
 
-pub use config::BatchBuilderConfig;
+pub use config::{BatchBuilderConfig, ForkBatchLimits, ForkSwitchGrace};
 pub use core::{BatchBuilderCore, is_last_slot_for_empty_block};
+pub use min_txs_controller::{AdaptiveMinTxsConfig, MinTxsController};
 pub use traits::*;
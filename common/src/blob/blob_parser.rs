@@ -90,9 +90,18 @@ async fn get_data_from_consensus_layer<T: ELTrait>(
     let slot = ethereum_l1
         .slot_clock
         .slot_of(Duration::from_secs(block_timestamp))?;
+    // Forced inclusions are decoded while building a block, so the retries of a timed out
+    // fetch must end well within the slot
+    let slot_duration = ethereum_l1.slot_clock.get_slot_duration();
     let blobs = ethereum_l1
-        .consensus_layer
-        .get_blobs(slot, &blob_hashes)
+        .blob_consensus_layer()
+        .get_blobs_retrying_on_timeout(
+            slot,
+            &blob_hashes,
+            ethereum_l1.consensus_rpc_timeout_max_retries,
+            slot_duration / 24,
+            slot_duration / 4,
+        )
         .await?;
     // Create a BlobTransactionSidecar from the blobs to obtain versioned hashes.
     // Note: BlobTransactionSidecar is preferred for performance reasons, as it is less time-consuming to create than BlobTransactionSidecarEip7594.
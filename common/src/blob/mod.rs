@@ -1,13 +1,26 @@
 pub mod blob_parser;
 
 use alloy::consensus::EnvKzgSettings;
+use std::time::{Duration, Instant};
 
 pub fn build_default_kzg_settings() {
     EnvKzgSettings::Default.get();
 }
 
+/// Runs `build` when eager warmup is enabled and returns how long it took,
+/// returns `None` when the warmup is skipped.
+pub fn warmup_kzg_settings(eager: bool, build: impl FnOnce()) -> Option<Duration> {
+    if !eager {
+        return None;
+    }
+    let start = Instant::now();
+    build();
+    Some(start.elapsed())
+}
+
 #[cfg(test)]
 mod tests {
+    use super::warmup_kzg_settings;
     use alloy::consensus::{Blob, EnvKzgSettings, SidecarBuilder};
     use alloy::eips::eip4844::BlobTransactionSidecar;
     use alloy::eips::eip7594::BlobTransactionSidecarEip7594;
@@ -170,4 +183,14 @@ mod tests {
             .expect("assert: can decode taiko blob");
         assert_eq!(data, decoded_data);
     }
+
+    #[test]
+    fn test_warmup_kzg_settings_skip_does_not_build() {
+        let mut built = false;
+        assert!(warmup_kzg_settings(false, || built = true).is_none());
+        assert!(!built);
+
+        assert!(warmup_kzg_settings(true, || built = true).is_some());
+        assert!(built);
+    }
 }
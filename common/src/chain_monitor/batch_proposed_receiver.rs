@@ -1,3 +1,4 @@
+use crate::metrics::Metrics;
 use crate::utils::{
     cancellation_token::CancellationToken,
     event_listener::{EventListenerConfig, listen_for_event},
@@ -5,6 +6,7 @@ use crate::utils::{
 use alloy::primitives::Address;
 use alloy::sol_types::SolEvent;
 use anyhow::Error;
+use std::sync::Arc;
 use tokio::{sync::mpsc::Sender, time::Duration};
 use tracing::info;
 
@@ -17,6 +19,8 @@ pub struct EventReceiver<T> {
     event_tx: Sender<T>,
     cancel_token: CancellationToken,
     event_name: &'static str,
+    log_chunk_blocks: u64,
+    metrics: Arc<Metrics>,
 }
 
 impl<T> EventReceiver<T>
@@ -29,6 +33,8 @@ where
         event_tx: Sender<T>,
         cancel_token: CancellationToken,
         event_name: &'static str,
+        log_chunk_blocks: u64,
+        metrics: Arc<Metrics>,
     ) -> Result<Self, Error> {
         Ok(Self {
             rpc_url,
@@ -36,6 +42,8 @@ where
             event_tx,
             cancel_token,
             event_name,
+            log_chunk_blocks,
+            metrics,
         })
     }
 
@@ -46,6 +54,8 @@ where
         let event_tx = self.event_tx.clone();
         let cancel_token = self.cancel_token.clone();
         let event_name = self.event_name;
+        let log_chunk_blocks = self.log_chunk_blocks;
+        let metrics = self.metrics.clone();
 
         tokio::spawn(async move {
             listen_for_event(
@@ -56,6 +66,8 @@ where
                     signature_hash: T::SIGNATURE_HASH,
                     reconnect_timeout: RECONNECT_TIMEOUT,
                     poll_interval: POLL_INTERVAL,
+                    log_chunk_blocks,
+                    metrics,
                 },
                 |log| Ok(T::decode_log(&log.inner)?.data),
                 event_tx,
@@ -14,6 +14,8 @@ mod l2_block_receiver;
 
 const MESSAGE_QUEUE_SIZE: usize = 20;
 
+type EventHandler<T> = Arc<dyn Fn(&T) + Send + Sync>;
+
 struct TaikoGethStatus {
     height: u64,
     hash: B256,
@@ -30,7 +32,8 @@ where
     taiko_geth_status: Arc<Mutex<TaikoGethStatus>>,
     cancel_token: CancellationToken,
     event_name: &'static str,
-    event_handler: fn(&T),
+    event_handler: EventHandler<T>,
+    log_chunk_blocks: u64,
     metrics: Arc<Metrics>,
 }
 
@@ -38,13 +41,15 @@ impl<T> ChainMonitor<T>
 where
     T: SolEvent + Send + 'static,
 {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         ws_l1_rpc_url: String,
         ws_l2_rpc_url: String,
         contract: Address,
         cancel_token: CancellationToken,
         event_name: &'static str,
-        event_handler: fn(&T),
+        event_handler: impl Fn(&T) + Send + Sync + 'static,
+        log_chunk_blocks: u64,
         metrics: Arc<Metrics>,
     ) -> Result<Self, Error> {
         debug!(
@@ -64,7 +69,8 @@ where
             taiko_geth_status,
             cancel_token,
             event_name,
-            event_handler,
+            event_handler: Arc::new(event_handler),
+            log_chunk_blocks,
             metrics,
         })
     }
@@ -86,6 +92,8 @@ where
             event_tx,
             self.cancel_token.clone(),
             self.event_name,
+            self.log_chunk_blocks,
+            self.metrics.clone(),
         )
         .await?;
         event_receiver.start();
@@ -108,7 +116,7 @@ where
             l2_block_rx,
             taiko_geth_status,
             cancel_token,
-            self.event_handler,
+            self.event_handler.clone(),
             self.metrics.clone(),
         ));
 
@@ -120,7 +128,7 @@ where
         mut l2_block_rx: Receiver<L2BlockInfo>,
         taiko_geth_status: Arc<Mutex<TaikoGethStatus>>,
         cancel_token: CancellationToken,
-        event_handler: fn(&T),
+        event_handler: EventHandler<T>,
         metrics: Arc<Metrics>,
     ) {
         info!("ChainMonitor message loop running");
@@ -1,6 +1,8 @@
 mod config_trait;
 pub use config_trait::ConfigTrait;
 
+use crate::batch_builder::AdaptiveMinTxsConfig;
+use crate::l1::fees_per_gas::PriorityFeeStrategy;
 use alloy::primitives::Address;
 use anyhow::Error;
 use std::str::FromStr;
@@ -21,6 +23,8 @@ pub struct Config {
     pub l1_rpc_urls: Vec<String>,
     pub l1_beacon_url: String,
     pub l1_beacon_timeout: Duration,
+    pub l1_beacon_timeout_max_retries: u64,
+    pub blob_cl_url: Option<String>,
     pub blob_indexer_url: Option<String>,
     pub l1_slot_duration_sec: u64,
     pub l1_slots_per_epoch: u64,
@@ -35,6 +39,7 @@ pub struct Config {
     pub rpc_driver_preconf_timeout: Duration,
     pub rpc_driver_status_timeout: Duration,
     pub rpc_driver_retry_timeout: Duration,
+    pub driver_sync_tolerance_blocks: u64,
     // L2 contracts
     pub anchor_address: Address,
     pub bridge_l2_address: Address,
@@ -46,18 +51,25 @@ pub struct Config {
     pub max_forced_inclusions_per_proposal: u16,
     /// Minimum offset between calculated anchor block ID and latest L1 height
     pub min_anchor_offset: u64,
+    pub anchor_confirmation_depth: u64,
     // Transaction parameters
     pub min_priority_fee_per_gas_wei: u64,
+    pub priority_fee_strategy: PriorityFeeStrategy,
     pub tx_fees_increase_percentage: u64,
     pub max_attempts_to_send_tx: u64,
     pub max_attempts_to_wait_tx: u64,
     pub delay_between_tx_attempts_sec: u64,
+    pub tx_resubmit_after_slots: u64,
+    pub tx_fee_bump_basis_points: u64,
     pub extra_gas_percentage: u64,
     // Thresholds for balances
     pub funds_monitor_interval_sec: u64,
     pub threshold_eth: u128,
+    pub eth_burn_alert_horizon_sec: Option<u64>,
     // Bridging
     pub disable_bridging: bool,
+    pub max_consecutive_bridge_failures: Option<u64>,
+    pub cancel_on_bridge_failures: bool,
     pub amount_to_bridge_from_l2_to_l1: u128,
     pub bridge_relayer_fee: u64,
     pub bridge_transaction_fee: u64,
@@ -66,20 +78,44 @@ pub struct Config {
     pub min_bytes_per_tx_list: u64,
     pub throttling_factor: u64,
     pub preconf_min_txs: u64,
+    pub preconf_min_txs_adaptive: Option<AdaptiveMinTxsConfig>,
     pub preconf_max_skipped_l2_slots: u64,
+    pub force_empty_block_after_skipped_l2_slots: Option<u64>,
     pub proposal_max_time_sec: u64,
+    pub max_batch_age_sec: Option<u64>,
     // fork info
     pub fork_switch_transition_period_sec: u64,
+    pub fork_switch_grace_period_sec: u64,
     pub shasta_timestamp_sec: u64,
     pub permissionless_timestamp_sec: u64,
     pub realtime_timestamp_sec: u64,
     // Whitelist monitor
     pub whitelist_monitor_interval_sec: u64,
+    // Chain monitor
+    pub chain_monitor_log_chunk_blocks: u64,
+    pub chain_monitor_l1_url: Option<String>,
+    // RPC health monitor
+    pub rpc_health_check_interval_sec: u64,
+    pub l1_rpc_height_divergence_threshold: Option<u64>,
+    pub l1_rpc_failover_cooldown_sec: u64,
     // Watchdog
     pub watchdog_max_counter: u64,
+    pub heartbeat_step_max_retries: u64,
+    pub warmup_retry_delay_sec: u64,
+    // Clock
+    pub max_l1_clock_skew_sec: u64,
+    pub refuse_start_on_clock_skew: bool,
+    // Blob
+    pub kzg_eager_warmup: bool,
+    // Transaction monitor
+    pub log_submitted_tx_hash: bool,
     // Internal server
     pub internal_server_ip: [u8; 4],
     pub internal_server_port: u16,
+    pub internal_server_bind_max_retries: u64,
+    pub internal_server_required: bool,
+    // Panic hook
+    pub panic_flush_delay_ms: u64,
 }
 
 /// Creates a formatted error message for address parsing failures.
@@ -97,6 +133,46 @@ pub fn address_parse_error(
     )
 }
 
+const MAX_FUNDS_MONITOR_INTERVAL_SEC: u64 = 24 * 60 * 60;
+
+/// Checks that the funds monitor interval is neither zero nor longer than a day.
+fn validate_funds_monitor_interval_sec(interval_sec: u64) -> Result<u64, Error> {
+    if interval_sec == 0 || interval_sec > MAX_FUNDS_MONITOR_INTERVAL_SEC {
+        return Err(anyhow::anyhow!(
+            "FUNDS_MONITOR_INTERVAL_SEC must be between 1 and {MAX_FUNDS_MONITOR_INTERVAL_SEC}, got {interval_sec}"
+        ));
+    }
+    Ok(interval_sec)
+}
+
+/// Transaction pools reject a replacement that bumps the fees by less than 10%.
+const MIN_TX_FEE_BUMP_BASIS_POINTS: u64 = 1000;
+
+/// Parses the fee bump of a replacement transaction, which must be at least the 10% replacement minimum.
+fn parse_tx_fee_bump_basis_points(value: &str) -> Result<u64, Error> {
+    let basis_points = value
+        .parse::<u64>()
+        .map_err(|e| anyhow::anyhow!("TX_FEE_BUMP_BASIS_POINTS must be a number: {}", e))?;
+    if basis_points < MIN_TX_FEE_BUMP_BASIS_POINTS {
+        return Err(anyhow::anyhow!(
+            "TX_FEE_BUMP_BASIS_POINTS must be at least {MIN_TX_FEE_BUMP_BASIS_POINTS}, got {basis_points}"
+        ));
+    }
+    Ok(basis_points)
+}
+
+/// Returns the L1 URL used by the chain monitor: the dedicated one when configured,
+/// otherwise the main L1 RPC URL.
+fn select_chain_monitor_l1_url(
+    chain_monitor_l1_url: Option<&str>,
+    l1_rpc_urls: &[String],
+) -> Result<String, Error> {
+    chain_monitor_l1_url
+        .or(l1_rpc_urls.first().map(String::as_str))
+        .map(str::to_string)
+        .ok_or_else(|| anyhow::anyhow!("L1 RPC URL is required"))
+}
+
 fn get_env_with_deprecation(new_key: &str, deprecated_key: &str) -> Option<String> {
     let new_val = std::env::var(new_key).ok();
     let deprecated_val = std::env::var(deprecated_key).ok();
@@ -122,6 +198,10 @@ fn get_env_with_deprecation(new_key: &str, deprecated_key: &str) -> Option<Strin
 }
 
 impl Config {
+    pub fn chain_monitor_l1_url(&self) -> Result<String, Error> {
+        select_chain_monitor_l1_url(self.chain_monitor_l1_url.as_deref(), &self.l1_rpc_urls)
+    }
+
     pub fn read_env_variables() -> Result<Self, Error> {
         // Load environment variables from .env file
         dotenvy::dotenv().ok();
@@ -171,12 +251,27 @@ impl Config {
             url
         };
 
+        // Beacon node used for blob fetches only, the main one is used when not set
+        let blob_cl_url = std::env::var("BLOB_CL_URL").ok().map(|mut url| {
+            if !url.ends_with('/') {
+                url.push('/');
+            }
+            url
+        });
+
         let l1_beacon_timeout = std::env::var("L1_BEACON_TIMEOUT_MS")
             .unwrap_or("1000".to_string())
             .parse::<u64>()
             .map_err(|e| anyhow::anyhow!("L1_BEACON_TIMEOUT_MS must be a number: {}", e))
             .map(Duration::from_millis)?;
 
+        let l1_beacon_timeout_max_retries = std::env::var("L1_BEACON_TIMEOUT_MAX_RETRIES")
+            .unwrap_or("3".to_string())
+            .parse::<u64>()
+            .map_err(|e| {
+                anyhow::anyhow!("L1_BEACON_TIMEOUT_MAX_RETRIES must be a number: {}", e)
+            })?;
+
         let extra_gas_percentage = std::env::var("EXTRA_GAS_PERCENTAGE")
             .unwrap_or("100".to_string())
             .parse::<u64>()
@@ -252,6 +347,11 @@ impl Config {
             .map_err(|e| anyhow::anyhow!("RPC_DRIVER_RETRY_TIMEOUT_MS must be a number: {}", e))?;
         let rpc_driver_retry_timeout = Duration::from_millis(rpc_driver_retry_timeout);
 
+        let driver_sync_tolerance_blocks = std::env::var("DRIVER_SYNC_TOLERANCE_BLOCKS")
+            .unwrap_or("0".to_string())
+            .parse::<u64>()
+            .map_err(|e| anyhow::anyhow!("DRIVER_SYNC_TOLERANCE_BLOCKS must be a number: {}", e))?;
+
         let rpc_l2_execution_layer_timeout = std::env::var("RPC_L2_EXECUTION_LAYER_TIMEOUT_MS")
             .unwrap_or("1000".to_string())
             .parse::<u64>()
@@ -349,6 +449,11 @@ impl Config {
                 }
             })?;
 
+        let anchor_confirmation_depth = std::env::var("ANCHOR_CONFIRMATION_DEPTH")
+            .unwrap_or("0".to_string())
+            .parse::<u64>()
+            .map_err(|e| anyhow::anyhow!("ANCHOR_CONFIRMATION_DEPTH must be a number: {}", e))?;
+
         let min_priority_fee_per_gas_wei = std::env::var("MIN_PRIORITY_FEE_PER_GAS_WEI")
             .unwrap_or("1000000000".to_string()) // 1 Gwei
             .parse::<u64>()
@@ -361,6 +466,53 @@ impl Config {
                 }
             })?;
 
+        let priority_fee_strategy = match std::env::var("PRIORITY_FEE_STRATEGY")
+            .unwrap_or("estimate".to_string())
+            .as_str()
+        {
+            "estimate" => PriorityFeeStrategy::Estimate,
+            "fixed" => {
+                let gwei = std::env::var("PRIORITY_FEE_FIXED_GWEI")
+                    .unwrap_or("1".to_string())
+                    .parse::<u64>()
+                    .map_err(|e| {
+                        anyhow::anyhow!("PRIORITY_FEE_FIXED_GWEI must be a number: {}", e)
+                    })?;
+                PriorityFeeStrategy::Fixed(u128::from(gwei) * 1_000_000_000)
+            }
+            "percentile" => {
+                let percentile = std::env::var("PRIORITY_FEE_PERCENTILE")
+                    .unwrap_or("50".to_string())
+                    .parse::<f64>()
+                    .map_err(|e| anyhow::anyhow!("PRIORITY_FEE_PERCENTILE must be a number: {}", e))
+                    .and_then(|val| {
+                        if (0.0..=100.0).contains(&val) {
+                            Ok(val)
+                        } else {
+                            Err(anyhow::anyhow!(
+                                "PRIORITY_FEE_PERCENTILE must be between 0 and 100"
+                            ))
+                        }
+                    })?;
+                PriorityFeeStrategy::Percentile(percentile)
+            }
+            "base_fee_multiplier" => {
+                let percentage = std::env::var("PRIORITY_FEE_BASE_FEE_PERCENTAGE")
+                    .unwrap_or("10".to_string())
+                    .parse::<u64>()
+                    .map_err(|e| {
+                        anyhow::anyhow!("PRIORITY_FEE_BASE_FEE_PERCENTAGE must be a number: {}", e)
+                    })?;
+                PriorityFeeStrategy::BaseFeePercentage(percentage)
+            }
+            other => {
+                return Err(anyhow::anyhow!(
+                    "PRIORITY_FEE_STRATEGY must be one of estimate, fixed, percentile, base_fee_multiplier, got {}",
+                    other
+                ));
+            }
+        };
+
         let tx_fees_increase_percentage = std::env::var("TX_FEES_INCREASE_PERCENTAGE")
             .unwrap_or("0".to_string())
             .parse::<u64>()
@@ -383,10 +535,26 @@ impl Config {
                 anyhow::anyhow!("DELAY_BETWEEN_TX_ATTEMPTS_SEC must be a number: {}", e)
             })?;
 
+        let tx_resubmit_after_slots = std::env::var("TX_RESUBMIT_AFTER_SLOTS")
+            .unwrap_or("1".to_string())
+            .parse::<u64>()
+            .map_err(|e| anyhow::anyhow!("TX_RESUBMIT_AFTER_SLOTS must be a number: {}", e))?;
+        if tx_resubmit_after_slots == 0 {
+            return Err(anyhow::anyhow!(
+                "TX_RESUBMIT_AFTER_SLOTS must be greater than 0"
+            ));
+        }
+
+        // 12.5% by default, above the 10% replacement minimum of the transaction pool
+        let tx_fee_bump_basis_points = parse_tx_fee_bump_basis_points(
+            &std::env::var("TX_FEE_BUMP_BASIS_POINTS").unwrap_or("1250".to_string()),
+        )?;
+
         let funds_monitor_interval_sec = std::env::var("FUNDS_MONITOR_INTERVAL_SEC")
             .unwrap_or("60".to_string())
             .parse::<u64>()
-            .map_err(|e| anyhow::anyhow!("FUNDS_MONITOR_INTERVAL_SEC must be a number: {}", e))?;
+            .map_err(|e| anyhow::anyhow!("FUNDS_MONITOR_INTERVAL_SEC must be a number: {}", e))
+            .and_then(validate_funds_monitor_interval_sec)?;
 
         // 0.5 ETH
         let threshold_eth =
@@ -395,6 +563,15 @@ impl Config {
             .parse::<u128>()
             .map_err(|e| anyhow::anyhow!("THRESHOLD_ETH must be a number: {}", e))?;
 
+        let eth_burn_alert_horizon_sec = std::env::var("ETH_BURN_ALERT_HORIZON_SEC")
+            .ok()
+            .map(|val| {
+                val.parse::<u64>().map_err(|e| {
+                    anyhow::anyhow!("ETH_BURN_ALERT_HORIZON_SEC must be a number: {}", e)
+                })
+            })
+            .transpose()?;
+
         // 1 ETH
         let amount_to_bridge_from_l2_to_l1 = std::env::var("AMOUNT_TO_BRIDGE_FROM_L2_TO_L1")
             .unwrap_or("1000000000000000000".to_string())
@@ -408,6 +585,20 @@ impl Config {
             .parse::<bool>()
             .map_err(|e| anyhow::anyhow!("DISABLE_BRIDGING must be a boolean: {}", e))?;
 
+        let max_consecutive_bridge_failures = std::env::var("MAX_CONSECUTIVE_BRIDGE_FAILURES")
+            .ok()
+            .map(|val| {
+                val.parse::<u64>().map_err(|e| {
+                    anyhow::anyhow!("MAX_CONSECUTIVE_BRIDGE_FAILURES must be a number: {}", e)
+                })
+            })
+            .transpose()?;
+
+        let cancel_on_bridge_failures = std::env::var("CANCEL_ON_BRIDGE_FAILURES")
+            .unwrap_or("false".to_string())
+            .parse::<bool>()
+            .map_err(|e| anyhow::anyhow!("CANCEL_ON_BRIDGE_FAILURES must be a boolean: {}", e))?;
+
         let max_bytes_per_tx_list = std::env::var("MAX_BYTES_PER_TX_LIST")
             .unwrap_or(BLOB_MAX_DATA_SIZE.to_string())
             .parse::<u64>()
@@ -429,16 +620,101 @@ impl Config {
             .parse::<u64>()
             .map_err(|e| anyhow::anyhow!("PRECONF_MIN_TXS must be a number: {}", e))?;
 
+        let preconf_min_txs_adaptive = std::env::var("PRECONF_MIN_TXS_ADAPTIVE")
+            .unwrap_or("false".to_string())
+            .parse::<bool>()
+            .map_err(|e| anyhow::anyhow!("PRECONF_MIN_TXS_ADAPTIVE must be a boolean: {}", e))?;
+        let preconf_min_txs_adaptive = if preconf_min_txs_adaptive {
+            let lower_min_txs = std::env::var("PRECONF_MIN_TXS_ADAPTIVE_LOWER")
+                .unwrap_or("1".to_string())
+                .parse::<u64>()
+                .map_err(|e| {
+                    anyhow::anyhow!("PRECONF_MIN_TXS_ADAPTIVE_LOWER must be a number: {}", e)
+                })?;
+            let upper_min_txs = std::env::var("PRECONF_MIN_TXS_ADAPTIVE_UPPER")
+                .unwrap_or("10".to_string())
+                .parse::<u64>()
+                .map_err(|e| {
+                    anyhow::anyhow!("PRECONF_MIN_TXS_ADAPTIVE_UPPER must be a number: {}", e)
+                })?;
+            if lower_min_txs > upper_min_txs {
+                return Err(anyhow::anyhow!(
+                    "PRECONF_MIN_TXS_ADAPTIVE_LOWER ({}) must not be greater than PRECONF_MIN_TXS_ADAPTIVE_UPPER ({})",
+                    lower_min_txs,
+                    upper_min_txs
+                ));
+            }
+            let busy_mempool_depth = std::env::var("PRECONF_MIN_TXS_BUSY_MEMPOOL_DEPTH")
+                .unwrap_or("50".to_string())
+                .parse::<u64>()
+                .map_err(|e| {
+                    anyhow::anyhow!("PRECONF_MIN_TXS_BUSY_MEMPOOL_DEPTH must be a number: {}", e)
+                })
+                .and_then(|val| {
+                    if val == 0 {
+                        Err(anyhow::anyhow!(
+                            "PRECONF_MIN_TXS_BUSY_MEMPOOL_DEPTH must be a positive number"
+                        ))
+                    } else {
+                        Ok(val)
+                    }
+                })?;
+            let window = std::env::var("PRECONF_MIN_TXS_ADAPTIVE_WINDOW")
+                .unwrap_or("10".to_string())
+                .parse::<usize>()
+                .map_err(|e| {
+                    anyhow::anyhow!("PRECONF_MIN_TXS_ADAPTIVE_WINDOW must be a number: {}", e)
+                })
+                .and_then(|val| {
+                    if val == 0 {
+                        Err(anyhow::anyhow!(
+                            "PRECONF_MIN_TXS_ADAPTIVE_WINDOW must be a positive number"
+                        ))
+                    } else {
+                        Ok(val)
+                    }
+                })?;
+            Some(AdaptiveMinTxsConfig {
+                lower_min_txs,
+                upper_min_txs,
+                busy_mempool_depth,
+                window,
+            })
+        } else {
+            None
+        };
+
         let preconf_max_skipped_l2_slots = std::env::var("PRECONF_MAX_SKIPPED_L2_SLOTS")
             .unwrap_or("2".to_string())
             .parse::<u64>()
             .map_err(|e| anyhow::anyhow!("PRECONF_MAX_SKIPPED_L2_SLOTS must be a number: {}", e))?;
 
+        let force_empty_block_after_skipped_l2_slots =
+            std::env::var("FORCE_EMPTY_BLOCK_AFTER_SKIPPED_L2_SLOTS")
+                .ok()
+                .map(|val| {
+                    val.parse::<u64>().map_err(|e| {
+                        anyhow::anyhow!(
+                            "FORCE_EMPTY_BLOCK_AFTER_SKIPPED_L2_SLOTS must be a number: {}",
+                            e
+                        )
+                    })
+                })
+                .transpose()?;
+
         let proposal_max_time_sec = std::env::var("PROPOSAL_MAX_TIME_SEC")
             .unwrap_or("384".to_string())
             .parse::<u64>()
             .map_err(|e| anyhow::anyhow!("PROPOSAL_MAX_TIME_SEC must be a number: {}", e))?;
 
+        let max_batch_age_sec = std::env::var("MAX_BATCH_AGE_SEC")
+            .ok()
+            .map(|val| {
+                val.parse::<u64>()
+                    .map_err(|e| anyhow::anyhow!("MAX_BATCH_AGE_SEC must be a number: {}", e))
+            })
+            .transpose()?;
+
         // 0.003 eth
         let bridge_relayer_fee = std::env::var("BRIDGE_RELAYER_FEE")
             .unwrap_or("3047459064000000".to_string())
@@ -459,6 +735,12 @@ impl Config {
                     anyhow::anyhow!("FORK_SWITCH_TRANSITION_PERIOD_SEC must be a number: {}", e)
                 })?,
             };
+
+        // Window after a fork switch during which the more conservative batch limits apply
+        let fork_switch_grace_period_sec = std::env::var("FORK_SWITCH_GRACE_PERIOD_SEC")
+            .unwrap_or("0".to_string())
+            .parse::<u64>()
+            .map_err(|e| anyhow::anyhow!("FORK_SWITCH_GRACE_PERIOD_SEC must be a number: {}", e))?;
         let shasta_timestamp_sec = std::env::var("SHASTA_TIMESTAMP_SEC")
             .unwrap_or("0".to_string())
             .parse::<u64>()
@@ -479,11 +761,93 @@ impl Config {
                 anyhow::anyhow!("WHITELIST_MONITOR_INTERVAL_SEC must be a number: {}", e)
             })?;
 
+        let chain_monitor_log_chunk_blocks = std::env::var("CHAIN_MONITOR_LOG_CHUNK_BLOCKS")
+            .unwrap_or("1000".to_string())
+            .parse::<u64>()
+            .map_err(|e| {
+                anyhow::anyhow!("CHAIN_MONITOR_LOG_CHUNK_BLOCKS must be a number: {}", e)
+            })?;
+        if chain_monitor_log_chunk_blocks == 0 {
+            return Err(anyhow::anyhow!(
+                "CHAIN_MONITOR_LOG_CHUNK_BLOCKS must be greater than 0"
+            ));
+        }
+
+        // Dedicated L1 endpoint for the chain monitor, the main L1 RPC URL is used when not set
+        let chain_monitor_l1_url = std::env::var("CHAIN_MONITOR_L1_URL")
+            .ok()
+            .map(|url| {
+                reqwest::Url::parse(&url)
+                    .map(|_| url)
+                    .map_err(|e| anyhow::anyhow!("CHAIN_MONITOR_L1_URL must be a valid URL: {}", e))
+            })
+            .transpose()?;
+
+        let rpc_health_check_interval_sec = std::env::var("RPC_HEALTH_CHECK_INTERVAL_SEC")
+            .unwrap_or("60".to_string())
+            .parse::<u64>()
+            .map_err(|e| {
+                anyhow::anyhow!("RPC_HEALTH_CHECK_INTERVAL_SEC must be a number: {}", e)
+            })?;
+        if rpc_health_check_interval_sec == 0 {
+            return Err(anyhow::anyhow!(
+                "RPC_HEALTH_CHECK_INTERVAL_SEC must be greater than 0"
+            ));
+        }
+
+        let l1_rpc_height_divergence_threshold =
+            std::env::var("L1_RPC_HEIGHT_DIVERGENCE_THRESHOLD")
+                .ok()
+                .map(|val| {
+                    val.parse::<u64>().map_err(|e| {
+                        anyhow::anyhow!(
+                            "L1_RPC_HEIGHT_DIVERGENCE_THRESHOLD must be a number: {}",
+                            e
+                        )
+                    })
+                })
+                .transpose()?;
+
+        let l1_rpc_failover_cooldown_sec = std::env::var("L1_RPC_FAILOVER_COOLDOWN_SEC")
+            .unwrap_or("30".to_string())
+            .parse::<u64>()
+            .map_err(|e| anyhow::anyhow!("L1_RPC_FAILOVER_COOLDOWN_SEC must be a number: {}", e))?;
+
         let watchdog_max_counter = std::env::var("WATCHDOG_MAX_COUNTER")
             .unwrap_or("96".to_string())
             .parse::<u64>()
             .map_err(|e| anyhow::anyhow!("WATCHDOG_MAX_COUNTER must be a number: {}", e))?;
 
+        let heartbeat_step_max_retries = std::env::var("HEARTBEAT_STEP_MAX_RETRIES")
+            .unwrap_or("0".to_string())
+            .parse::<u64>()
+            .map_err(|e| anyhow::anyhow!("HEARTBEAT_STEP_MAX_RETRIES must be a number: {}", e))?;
+
+        let warmup_retry_delay_sec = std::env::var("WARMUP_RETRY_DELAY_SEC")
+            .unwrap_or("30".to_string())
+            .parse::<u64>()
+            .map_err(|e| anyhow::anyhow!("WARMUP_RETRY_DELAY_SEC must be a number: {}", e))?;
+
+        let max_l1_clock_skew_sec = std::env::var("MAX_L1_CLOCK_SKEW_SEC")
+            .unwrap_or("24".to_string())
+            .parse::<u64>()
+            .map_err(|e| anyhow::anyhow!("MAX_L1_CLOCK_SKEW_SEC must be a number: {}", e))?;
+
+        let refuse_start_on_clock_skew = std::env::var("REFUSE_START_ON_CLOCK_SKEW")
+            .unwrap_or("false".to_string())
+            .parse::<bool>()
+            .map_err(|e| anyhow::anyhow!("REFUSE_START_ON_CLOCK_SKEW must be a boolean: {}", e))?;
+
+        let kzg_eager_warmup = std::env::var("KZG_EAGER_WARMUP")
+            .unwrap_or("true".to_string())
+            .parse::<bool>()
+            .map_err(|e| anyhow::anyhow!("KZG_EAGER_WARMUP must be a boolean: {}", e))?;
+
+        let log_submitted_tx_hash = std::env::var("LOG_SUBMITTED_TX_HASH")
+            .unwrap_or("false".to_string())
+            .parse::<bool>()
+            .map_err(|e| anyhow::anyhow!("LOG_SUBMITTED_TX_HASH must be a boolean: {}", e))?;
+
         let internal_server_ip = std::env::var("INTERNAL_SERVER_IP")
             .unwrap_or_else(|_| "0.0.0.0".to_string())
             .parse::<std::net::Ipv4Addr>()
@@ -495,6 +859,24 @@ impl Config {
             .parse::<u16>()
             .map_err(|e| anyhow::anyhow!("INTERNAL_SERVER_PORT must be a number: {}", e))?;
 
+        let internal_server_bind_max_retries = std::env::var("INTERNAL_SERVER_BIND_MAX_RETRIES")
+            .unwrap_or("3".to_string())
+            .parse::<u64>()
+            .map_err(|e| {
+                anyhow::anyhow!("INTERNAL_SERVER_BIND_MAX_RETRIES must be a number: {}", e)
+            })?;
+
+        // When not required, the node keeps running without metrics if the port cannot be bound
+        let internal_server_required = std::env::var("INTERNAL_SERVER_REQUIRED")
+            .unwrap_or("false".to_string())
+            .parse::<bool>()
+            .map_err(|e| anyhow::anyhow!("INTERNAL_SERVER_REQUIRED must be a boolean: {}", e))?;
+
+        let panic_flush_delay_ms = std::env::var("PANIC_FLUSH_DELAY_MS")
+            .unwrap_or("100".to_string())
+            .parse::<u64>()
+            .map_err(|e| anyhow::anyhow!("PANIC_FLUSH_DELAY_MS must be a number: {}", e))?;
+
         let l2_rpc_url = get_env_with_deprecation("L2_RPC_URL", "TAIKO_GETH_RPC_URL")
             .unwrap_or_else(|| {
                 warn!("No L2 RPC URL found in L2_RPC_URL env var, using default");
@@ -528,6 +910,8 @@ impl Config {
                 .collect(),
             l1_beacon_url,
             l1_beacon_timeout,
+            l1_beacon_timeout_max_retries,
+            blob_cl_url,
             blob_indexer_url: std::env::var("BLOB_INDEXER_URL").ok(),
             web3signer_l1_url,
             web3signer_l2_url,
@@ -540,6 +924,7 @@ impl Config {
             rpc_driver_preconf_timeout,
             rpc_driver_status_timeout,
             rpc_driver_retry_timeout,
+            driver_sync_tolerance_blocks,
             anchor_address,
             bridge_l2_address,
             max_bytes_size_of_batch,
@@ -548,32 +933,57 @@ impl Config {
             max_anchor_height_offset_reduction,
             max_forced_inclusions_per_proposal,
             min_anchor_offset,
+            anchor_confirmation_depth,
             min_priority_fee_per_gas_wei,
+            priority_fee_strategy,
             tx_fees_increase_percentage,
             max_attempts_to_send_tx,
             max_attempts_to_wait_tx,
             delay_between_tx_attempts_sec,
+            tx_resubmit_after_slots,
+            tx_fee_bump_basis_points,
             funds_monitor_interval_sec,
             threshold_eth,
+            eth_burn_alert_horizon_sec,
             amount_to_bridge_from_l2_to_l1,
             disable_bridging,
+            max_consecutive_bridge_failures,
+            cancel_on_bridge_failures,
             max_bytes_per_tx_list,
             throttling_factor,
             min_bytes_per_tx_list,
             extra_gas_percentage,
             preconf_min_txs,
+            preconf_min_txs_adaptive,
             preconf_max_skipped_l2_slots,
+            force_empty_block_after_skipped_l2_slots,
             proposal_max_time_sec,
+            max_batch_age_sec,
             bridge_relayer_fee,
             bridge_transaction_fee,
             fork_switch_transition_period_sec,
+            fork_switch_grace_period_sec,
             shasta_timestamp_sec,
             permissionless_timestamp_sec,
             realtime_timestamp_sec,
             whitelist_monitor_interval_sec,
+            chain_monitor_log_chunk_blocks,
+            chain_monitor_l1_url,
+            rpc_health_check_interval_sec,
+            l1_rpc_height_divergence_threshold,
+            l1_rpc_failover_cooldown_sec,
             watchdog_max_counter,
+            heartbeat_step_max_retries,
+            warmup_retry_delay_sec,
+            max_l1_clock_skew_sec,
+            refuse_start_on_clock_skew,
+            kzg_eager_warmup,
+            log_submitted_tx_hash,
             internal_server_ip,
             internal_server_port,
+            internal_server_bind_max_retries,
+            internal_server_required,
+            panic_flush_delay_ms,
         };
 
         info!(
@@ -585,6 +995,8 @@ L2 driver URL: {},
 L1 RPC URL: {},
 Consensus layer URL: {},
 Consensus layer timeout: {}ms,
+Consensus layer timeout max retries: {},
+Blob consensus layer URL: {},
 Blob Indexer URL: {},
 Web3signer L1 URL: {},
 Web3signer L2 URL: {},
@@ -596,6 +1008,7 @@ rpc L2 EL timeout: {}ms
 rpc driver preconf timeout: {}ms
 rpc driver status timeout: {}ms
 rpc driver retry timeout: {}ms
+driver sync tolerance: {} blocks
 anchor address: {}
 bridge L2 address: {}
 max bytes per tx list from L2 driver: {}
@@ -607,28 +1020,53 @@ max time shift between blocks: {}s
 max anchor height offset reduction value: {}
 max forced inclusions per proposal: {}
 min anchor offset: {}
+anchor confirmation depth: {}
 min priority fee per gas: {}wei
+priority fee strategy: {}
 tx fees increase percentage: {}
 max attempts to send tx: {}
 max attempts to wait tx: {}
 delay between tx attempts: {}s
+tx resubmit after: {} slots
+tx fee bump: {}bps
 funds_monitor_interval_sec: {}s
 threshold_eth: {}
+eth burn alert horizon: {}
 amount to bridge from l2 to l1: {}
 disable bridging: {}
+max consecutive bridge failures: {}
+cancel on bridge failures: {}
 min number of transaction to create a L2 block: {}
+adaptive min number of transactions: {}
 max number of skipped L2 slots while creating a L2 block: {}
+force empty block after skipped L2 slots: {}
 max time before submit: {}s
+max batch age: {}
 bridge relayer fee: {}wei
 bridge transaction fee: {}wei
 fork switch transition time: {}s
+fork switch grace period: {}s
 shasta timestamp: {}s
 permissionless timestamp: {}s
 realtime timestamp: {}s
 whitelist monitor interval: {}s
+chain monitor log chunk: {} blocks
+chain monitor L1 URL: {}
+RPC health check interval: {}s
+L1 RPC height divergence threshold: {}
+L1 RPC failover cooldown: {}s
 watchdog max counter: {}
+heartbeat step max retries: {}
+warmup retry delay: {}s
+max L1 clock skew: {}s
+refuse start on clock skew: {}
+KZG eager warmup: {}
+log submitted tx hash: {}
 internal server IP: {}
 internal server port: {}
+internal server bind max retries: {}
+internal server required: {}
+panic flush delay: {}ms
 "#,
             if let Some(preconfer_address) = &config.preconfer_address {
                 format!("\npreconfer address: {preconfer_address}")
@@ -648,6 +1086,8 @@ internal server port: {}
             },
             config.l1_beacon_url,
             config.l1_beacon_timeout.as_millis(),
+            config.l1_beacon_timeout_max_retries,
+            config.blob_cl_url.as_deref().unwrap_or("not set"),
             config.blob_indexer_url.as_deref().unwrap_or("not set"),
             config.web3signer_l1_url.as_deref().unwrap_or("not set"),
             config.web3signer_l2_url.as_deref().unwrap_or("not set"),
@@ -659,6 +1099,7 @@ internal server port: {}
             config.rpc_driver_preconf_timeout.as_millis(),
             config.rpc_driver_status_timeout.as_millis(),
             config.rpc_driver_retry_timeout.as_millis(),
+            config.driver_sync_tolerance_blocks,
             config.anchor_address,
             config.bridge_l2_address,
             config.max_bytes_per_tx_list,
@@ -670,30 +1111,122 @@ internal server port: {}
             config.max_anchor_height_offset_reduction,
             config.max_forced_inclusions_per_proposal,
             config.min_anchor_offset,
+            config.anchor_confirmation_depth,
             config.min_priority_fee_per_gas_wei,
+            config.priority_fee_strategy,
             config.tx_fees_increase_percentage,
             config.max_attempts_to_send_tx,
             config.max_attempts_to_wait_tx,
             config.delay_between_tx_attempts_sec,
+            config.tx_resubmit_after_slots,
+            config.tx_fee_bump_basis_points,
             funds_monitor_interval_sec,
             threshold_eth,
+            config
+                .eth_burn_alert_horizon_sec
+                .map_or("disabled".to_string(), |horizon| format!("{horizon}s")),
             config.amount_to_bridge_from_l2_to_l1,
             config.disable_bridging,
+            config
+                .max_consecutive_bridge_failures
+                .map_or("disabled".to_string(), |max| max.to_string()),
+            config.cancel_on_bridge_failures,
             config.preconf_min_txs,
+            match &config.preconf_min_txs_adaptive {
+                Some(adaptive) => format!(
+                    "{}..{} txs, busy mempool depth: {}, window: {}",
+                    adaptive.lower_min_txs,
+                    adaptive.upper_min_txs,
+                    adaptive.busy_mempool_depth,
+                    adaptive.window
+                ),
+                None => "disabled".to_string(),
+            },
             config.preconf_max_skipped_l2_slots,
+            config
+                .force_empty_block_after_skipped_l2_slots
+                .map_or("disabled".to_string(), |slots| slots.to_string()),
             config.proposal_max_time_sec,
+            config
+                .max_batch_age_sec
+                .map_or("disabled".to_string(), |age| format!("{age}s")),
             config.bridge_relayer_fee,
             config.bridge_transaction_fee,
             config.fork_switch_transition_period_sec,
+            config.fork_switch_grace_period_sec,
             config.shasta_timestamp_sec,
             config.permissionless_timestamp_sec,
             config.realtime_timestamp_sec,
             config.whitelist_monitor_interval_sec,
+            config.chain_monitor_log_chunk_blocks,
+            config
+                .chain_monitor_l1_url
+                .as_deref()
+                .unwrap_or("main L1 RPC URL"),
+            config.rpc_health_check_interval_sec,
+            config
+                .l1_rpc_height_divergence_threshold
+                .map_or("disabled".to_string(), |blocks| format!("{blocks} blocks")),
+            config.l1_rpc_failover_cooldown_sec,
             config.watchdog_max_counter,
+            config.heartbeat_step_max_retries,
+            config.warmup_retry_delay_sec,
+            config.max_l1_clock_skew_sec,
+            config.refuse_start_on_clock_skew,
+            config.kzg_eager_warmup,
+            config.log_submitted_tx_hash,
             std::net::Ipv4Addr::from(config.internal_server_ip),
             config.internal_server_port,
+            config.internal_server_bind_max_retries,
+            config.internal_server_required,
+            config.panic_flush_delay_ms,
         );
 
         Ok(config)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_funds_monitor_interval_validation() {
+        assert_eq!(validate_funds_monitor_interval_sec(30).unwrap(), 30);
+        assert_eq!(
+            validate_funds_monitor_interval_sec(MAX_FUNDS_MONITOR_INTERVAL_SEC).unwrap(),
+            MAX_FUNDS_MONITOR_INTERVAL_SEC
+        );
+        assert!(validate_funds_monitor_interval_sec(0).is_err());
+        assert!(validate_funds_monitor_interval_sec(MAX_FUNDS_MONITOR_INTERVAL_SEC + 1).is_err());
+    }
+
+    #[test]
+    fn test_tx_fee_bump_basis_points_parsing() {
+        assert_eq!(parse_tx_fee_bump_basis_points("1250").unwrap(), 1250);
+        assert_eq!(
+            parse_tx_fee_bump_basis_points("1000").unwrap(),
+            MIN_TX_FEE_BUMP_BASIS_POINTS
+        );
+        assert!(parse_tx_fee_bump_basis_points("999").is_err());
+        assert!(parse_tx_fee_bump_basis_points("ten").is_err());
+    }
+
+    #[test]
+    fn test_chain_monitor_l1_url_selection() {
+        let l1_rpc_urls = vec![
+            "http://main:8545".to_string(),
+            "http://fallback:8545".to_string(),
+        ];
+
+        assert_eq!(
+            select_chain_monitor_l1_url(Some("ws://archive:8546"), &l1_rpc_urls).unwrap(),
+            "ws://archive:8546"
+        );
+        assert_eq!(
+            select_chain_monitor_l1_url(None, &l1_rpc_urls).unwrap(),
+            "http://main:8545"
+        );
+        assert!(select_chain_monitor_l1_url(None, &[]).is_err());
+    }
+}
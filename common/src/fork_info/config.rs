@@ -7,6 +7,7 @@ use strum::IntoEnumIterator;
 pub struct ForkInfoConfig {
     pub fork_switch_timestamps: Vec<Duration>,
     pub fork_switch_transition_period: Duration,
+    pub fork_switch_grace_period: Duration,
 }
 
 impl Default for ForkInfoConfig {
@@ -18,6 +19,7 @@ impl Default for ForkInfoConfig {
                 Duration::from_secs(99999999999), // Realtime
             ],
             fork_switch_transition_period: Duration::from_secs(15),
+            fork_switch_grace_period: Duration::ZERO,
         }
     }
 }
@@ -36,6 +38,7 @@ impl From<&Config> for ForkInfoConfig {
             fork_switch_transition_period: Duration::from_secs(
                 config.fork_switch_transition_period_sec,
             ),
+            fork_switch_grace_period: Duration::from_secs(config.fork_switch_grace_period_sec),
         }
     }
 }
@@ -66,6 +66,22 @@ impl ForkInfo {
 
         false
     }
+
+    /// Returns the end of the grace window following the switch to the current fork,
+    /// or None when `current_time` is outside of it.
+    pub fn fork_switch_grace_period_end(&self, current_time: Duration) -> Option<Duration> {
+        let current_fork_index = Fork::iter()
+            .position(|f| f == self.fork)
+            .expect("Fork should always be found in its own iterator");
+        if current_fork_index == 0 {
+            return None;
+        }
+
+        let fork_timestamp = *self.config.fork_switch_timestamps.get(current_fork_index)?;
+        let grace_period_end = fork_timestamp + self.config.fork_switch_grace_period;
+        (current_time >= fork_timestamp && current_time < grace_period_end)
+            .then_some(grace_period_end)
+    }
 }
 
 #[cfg(test)]
@@ -80,6 +96,7 @@ mod tests {
                 Duration::from_secs(10), // Permissionless
             ],
             fork_switch_transition_period: Duration::from_secs(5),
+            fork_switch_grace_period: Duration::ZERO,
         };
         let mut fork_info = ForkInfo::from_config(config).unwrap();
         // Set fork to Shasta to test transition to Permissionless
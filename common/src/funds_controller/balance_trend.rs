@@ -0,0 +1,100 @@
+use alloy::primitives::U256;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Keeps recent balance samples to estimate how fast the balance is draining.
+pub struct BalanceTrend {
+    samples: VecDeque<(Instant, U256)>,
+    max_samples: usize,
+}
+
+impl BalanceTrend {
+    pub fn new(max_samples: usize) -> Self {
+        Self {
+            samples: VecDeque::with_capacity(max_samples),
+            max_samples: max_samples.max(2),
+        }
+    }
+
+    pub fn record(&mut self, at: Instant, balance: U256) {
+        if self.samples.len() == self.max_samples {
+            self.samples.pop_front();
+        }
+        self.samples.push_back((at, balance));
+    }
+
+    /// Projected time until the balance reaches the threshold at the observed burn rate.
+    /// Returns `None` when there are not enough samples or the balance is not declining.
+    pub fn time_to_threshold(&self, threshold: U256) -> Option<Duration> {
+        let (first_at, first_balance) = self.samples.front()?;
+        let (last_at, last_balance) = self.samples.back()?;
+        if last_balance >= first_balance {
+            return None;
+        }
+        if last_balance <= &threshold {
+            return Some(Duration::ZERO);
+        }
+
+        let elapsed_ms = last_at.duration_since(*first_at).as_millis();
+        if elapsed_ms == 0 {
+            return None;
+        }
+
+        let burned = first_balance - last_balance;
+        let remaining_ms = (last_balance - threshold) * U256::from(elapsed_ms) / burned;
+        Some(Duration::from_millis(
+            u64::try_from(remaining_ms).unwrap_or(u64::MAX),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ETH: u64 = 1_000_000_000_000_000_000;
+
+    #[test]
+    fn test_declining_balance_projects_time_to_threshold() {
+        let start = Instant::now();
+        let mut trend = BalanceTrend::new(5);
+        // 0.1 ETH burned per minute
+        for minute in 0..6u64 {
+            trend.record(
+                start + Duration::from_secs(minute * 60),
+                U256::from(2 * ETH - minute * ETH / 10),
+            );
+        }
+
+        // 1.5 ETH left, 1 ETH above the 0.5 ETH threshold
+        assert_eq!(
+            trend.time_to_threshold(U256::from(ETH / 2)),
+            Some(Duration::from_secs(10 * 60))
+        );
+    }
+
+    #[test]
+    fn test_stable_or_growing_balance_has_no_projection() {
+        let start = Instant::now();
+        let mut trend = BalanceTrend::new(5);
+        assert_eq!(trend.time_to_threshold(U256::ZERO), None);
+
+        trend.record(start, U256::from(ETH));
+        assert_eq!(trend.time_to_threshold(U256::ZERO), None);
+
+        trend.record(start + Duration::from_secs(60), U256::from(2 * ETH));
+        assert_eq!(trend.time_to_threshold(U256::ZERO), None);
+    }
+
+    #[test]
+    fn test_balance_below_threshold() {
+        let start = Instant::now();
+        let mut trend = BalanceTrend::new(5);
+        trend.record(start, U256::from(ETH));
+        trend.record(start + Duration::from_secs(60), U256::from(ETH / 4));
+        assert_eq!(
+            trend.time_to_threshold(U256::from(ETH / 2)),
+            Some(Duration::ZERO)
+        );
+    }
+}
@@ -0,0 +1,60 @@
+/// Tracks consecutive L2 to L1 bridging failures and reports when they should be escalated.
+pub struct BridgeFailureStreak {
+    max_consecutive_failures: Option<u64>,
+    consecutive_failures: u64,
+}
+
+impl BridgeFailureStreak {
+    pub fn new(max_consecutive_failures: Option<u64>) -> Self {
+        Self {
+            max_consecutive_failures,
+            consecutive_failures: 0,
+        }
+    }
+
+    pub fn record_success(&mut self) {
+        self.consecutive_failures = 0;
+    }
+
+    /// Records a failed bridging attempt and returns true once the streak reaches the limit.
+    pub fn record_failure(&mut self) -> bool {
+        self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+        self.is_critical()
+    }
+
+    pub fn is_critical(&self) -> bool {
+        self.max_consecutive_failures
+            .is_some_and(|max| self.consecutive_failures >= max)
+    }
+
+    pub fn consecutive_failures(&self) -> u64 {
+        self.consecutive_failures
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escalates_after_max_consecutive_failures() {
+        let mut streak = BridgeFailureStreak::new(Some(3));
+        assert!(!streak.record_failure());
+        assert!(!streak.record_failure());
+        assert!(streak.record_failure());
+        assert!(streak.record_failure());
+        assert_eq!(streak.consecutive_failures(), 4);
+
+        streak.record_success();
+        assert!(!streak.is_critical());
+        assert!(!streak.record_failure());
+    }
+
+    #[test]
+    fn test_never_escalates_when_disabled() {
+        let mut streak = BridgeFailureStreak::new(None);
+        for _ in 0..100 {
+            assert!(!streak.record_failure());
+        }
+    }
+}
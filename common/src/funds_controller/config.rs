@@ -10,9 +10,12 @@ pub struct FundsControllerConfig {
     pub thresholds: Thresholds,
     pub amount_to_bridge_from_l2_to_l1: u128,
     pub disable_bridging: bool,
+    pub max_consecutive_bridge_failures: Option<u64>,
+    pub cancel_on_bridge_failures: bool,
     pub bridge_relayer_fee: u64,
     pub bridge_transaction_fee: u64,
     pub monitor_interval: Duration,
+    pub eth_burn_alert_horizon: Option<Duration>,
 }
 
 impl From<&Config> for FundsControllerConfig {
@@ -23,9 +26,12 @@ impl From<&Config> for FundsControllerConfig {
             },
             amount_to_bridge_from_l2_to_l1: config.amount_to_bridge_from_l2_to_l1,
             disable_bridging: config.disable_bridging,
+            max_consecutive_bridge_failures: config.max_consecutive_bridge_failures,
+            cancel_on_bridge_failures: config.cancel_on_bridge_failures,
             bridge_relayer_fee: config.bridge_relayer_fee,
             bridge_transaction_fee: config.bridge_transaction_fee,
             monitor_interval: Duration::from_secs(config.funds_monitor_interval_sec),
+            eth_burn_alert_horizon: config.eth_burn_alert_horizon_sec.map(Duration::from_secs),
         }
     }
 }
@@ -1,10 +1,15 @@
+mod balance_trend;
+mod bridge_failure_streak;
 mod config;
 
 use crate::utils::cancellation_token::CancellationToken;
 use alloy::primitives::U256;
 use anyhow::Error;
+use balance_trend::BalanceTrend;
+use bridge_failure_streak::BridgeFailureStreak;
 use config::FundsControllerConfig;
 use std::sync::Arc;
+use std::time::Instant;
 use tokio::time::sleep;
 use tracing::{error, info, warn};
 
@@ -24,8 +29,14 @@ where
     taiko: Arc<L2>,
     metrics: Arc<Metrics>,
     cancel_token: CancellationToken,
+    eth_balance_trend: BalanceTrend,
+    bridge_failure_streak: BridgeFailureStreak,
 }
 
+const ETH_BALANCE_TREND_SAMPLES: usize = 30;
+/// A bridge threshold this many times above the current L2 balance is treated as a misconfiguration.
+const IMPLAUSIBLE_BRIDGE_THRESHOLD_FACTOR: u64 = 10;
+
 impl<L1, L2> FundsController<L1, L2>
 where
     L1: ELTrait + PreconferProvider + Send + Sync + 'static,
@@ -38,12 +49,16 @@ where
         metrics: Arc<Metrics>,
         cancel_token: CancellationToken,
     ) -> Self {
+        let bridge_failure_streak =
+            BridgeFailureStreak::new(config.max_consecutive_bridge_failures);
         Self {
             config,
             l1_execution_layer,
             taiko,
             metrics,
             cancel_token,
+            eth_balance_trend: BalanceTrend::new(ETH_BALANCE_TREND_SAMPLES),
+            bridge_failure_streak,
         }
     }
 
@@ -54,15 +69,36 @@ where
         });
     }
 
-    async fn monitor_funds_level(self) {
-        if let Err(e) = self.check_initial_funds().await {
-            error!("{}", e);
-            self.cancel_token.cancel_on_critical_error();
-            return;
+    async fn monitor_funds_level(mut self) {
+        // Every await is raced against the token, so a pending RPC call doesn't delay shutdown
+        let cancel_token = self.cancel_token.clone();
+        match cancel_token
+            .run_until_cancelled(self.check_initial_funds())
+            .await
+        {
+            Some(Ok(())) => {}
+            Some(Err(e)) => {
+                error!("{}", e);
+                self.cancel_token.cancel_on_critical_error();
+                return;
+            }
+            None => {
+                info!(
+                    "Shutdown signal received during initial funds check, exiting funds monitor..."
+                );
+                return;
+            }
         }
 
         loop {
-            self.transfer_funds_from_l2_to_l1_when_needed().await;
+            if cancel_token
+                .run_until_cancelled(self.transfer_funds_from_l2_to_l1_when_needed())
+                .await
+                .is_none()
+            {
+                info!("Shutdown signal received, exiting funds monitor...");
+                return;
+            }
             tokio::select! {
                 _ = sleep(self.config.monitor_interval) => {},
                 _ = self.cancel_token.cancelled() => {
@@ -91,14 +127,47 @@ where
 
         info!("ETH balance is sufficient ({})", balance);
 
+        if !self.config.disable_bridging {
+            self.check_bridge_threshold_plausibility().await;
+        }
+
         Ok(())
     }
 
-    async fn transfer_funds_from_l2_to_l1_when_needed(&self) {
+    /// Soft guardrail against a bridge amount larger than the L2 balance we ever hold,
+    /// in which case bridging never triggers and L1 funds silently run out.
+    async fn check_bridge_threshold_plausibility(&self) {
+        let preconfer_address = self.l1_execution_layer.get_preconfer_address();
+        let l2_balance = match self.taiko.get_balance(preconfer_address).await {
+            Ok(balance) => balance,
+            Err(e) => {
+                warn!("Failed to get preconfer l2 eth balance: {}", e);
+                return;
+            }
+        };
+
+        let bridge_threshold = self.bridge_threshold();
+        if is_bridge_threshold_implausible(bridge_threshold, l2_balance) {
+            warn!(
+                "Bridging from L2 to L1 requires an L2 balance above {} (AMOUNT_TO_BRIDGE_FROM_L2_TO_L1 + BRIDGE_RELAYER_FEE + BRIDGE_TRANSACTION_FEE), which is more than {}x the current L2 balance ({}). Bridging may never trigger",
+                bridge_threshold, IMPLAUSIBLE_BRIDGE_THRESHOLD_FACTOR, l2_balance
+            );
+        }
+    }
+
+    /// L2 balance above which the configured amount is bridged to L1, including fees.
+    fn bridge_threshold(&self) -> U256 {
+        U256::from(self.config.amount_to_bridge_from_l2_to_l1)
+            + U256::from(self.config.bridge_relayer_fee)
+            + U256::from(self.config.bridge_transaction_fee) // estimated transaction fee
+    }
+
+    async fn transfer_funds_from_l2_to_l1_when_needed(&mut self) {
         let eth_balance = self.l1_execution_layer.get_preconfer_wallet_eth().await;
         let eth_balance_str = match eth_balance.as_ref() {
             Ok(balance) => {
                 self.metrics.set_preconfer_eth_balance(*balance);
+                self.check_eth_burn_rate(*balance);
                 balance.to_string()
             }
             Err(e) => {
@@ -127,12 +196,7 @@ where
 
         if !self.config.disable_bridging
             && let Ok(l2_eth_balance) = l2_eth_balance
-            && l2_eth_balance
-                > U256::from(
-                    self.config.amount_to_bridge_from_l2_to_l1
-                        + u128::from(self.config.bridge_relayer_fee)
-                        + u128::from(self.config.bridge_transaction_fee), // estimated transaction fee
-                )
+            && l2_eth_balance > self.bridge_threshold()
         {
             match self
                 .taiko
@@ -144,12 +208,206 @@ where
                 )
                 .await
             {
-                Ok(_) => info!(
-                    "Transferred {} ETH from L2 to L1",
-                    self.config.amount_to_bridge_from_l2_to_l1
-                ),
-                Err(e) => warn!("Failed to transfer ETH from L2 to L1: {}", e),
+                Ok(_) => {
+                    info!(
+                        "Transferred {} ETH from L2 to L1",
+                        self.config.amount_to_bridge_from_l2_to_l1
+                    );
+                    self.bridge_failure_streak.record_success();
+                    self.metrics.set_bridge_failures_critical(false);
+                }
+                Err(e) => {
+                    warn!("Failed to transfer ETH from L2 to L1: {}", e);
+                    self.handle_bridge_failure();
+                }
+            }
+        }
+    }
+
+    fn handle_bridge_failure(&mut self) {
+        if !self.bridge_failure_streak.record_failure() {
+            return;
+        }
+
+        error!(
+            "Bridging ETH from L2 to L1 failed {} times in a row, L1 funds may run out",
+            self.bridge_failure_streak.consecutive_failures()
+        );
+        self.metrics.set_bridge_failures_critical(true);
+        if self.config.cancel_on_bridge_failures {
+            self.cancel_token.cancel_on_critical_error();
+        }
+    }
+
+    fn check_eth_burn_rate(&mut self, balance: U256) {
+        let Some(horizon) = self.config.eth_burn_alert_horizon else {
+            return;
+        };
+
+        self.eth_balance_trend.record(Instant::now(), balance);
+        let alert = match self
+            .eth_balance_trend
+            .time_to_threshold(self.config.thresholds.eth)
+        {
+            Some(time_to_threshold) if time_to_threshold < horizon => {
+                warn!(
+                    "ETH balance ({}) is projected to reach the threshold ({}) in {}s, top up the preconfer wallet",
+                    balance,
+                    self.config.thresholds.eth,
+                    time_to_threshold.as_secs()
+                );
+                true
             }
+            _ => false,
+        };
+        self.metrics.set_preconfer_eth_burn_alert(alert);
+    }
+}
+
+/// An empty L2 balance is not treated as implausible, as it is expected on a fresh preconfer.
+fn is_bridge_threshold_implausible(bridge_threshold: U256, l2_balance: U256) -> bool {
+    !l2_balance.is_zero()
+        && bridge_threshold
+            > l2_balance.saturating_mul(U256::from(IMPLAUSIBLE_BRIDGE_THRESHOLD_FACTOR))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::{alloy_tools, execution_layer::ExecutionLayer};
+    use alloy::primitives::Address;
+    use config::Thresholds;
+    use serde_json::Value;
+    use std::time::Duration;
+
+    struct PendingBalanceL1 {
+        common: ExecutionLayer,
+    }
+
+    impl PendingBalanceL1 {
+        /// Backed by an L1 answering every JSON-RPC request with chain ID 1.
+        async fn new(server: &mut mockito::ServerGuard) -> Self {
+            server
+                .mock("POST", "/")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body_from_request(|request| {
+                    let body: Value = serde_json::from_slice(request.body().expect("request body"))
+                        .expect("valid JSON-RPC request");
+                    serde_json::json!({"jsonrpc": "2.0", "id": body["id"], "result": "0x1"})
+                        .to_string()
+                        .into()
+                })
+                .create_async()
+                .await;
+            let provider = alloy_tools::create_alloy_provider_without_wallet(&server.url())
+                .await
+                .expect("failed to create provider");
+            let common = ExecutionLayer::new(provider, Address::ZERO)
+                .await
+                .expect("failed to create execution layer");
+            Self { common }
+        }
+    }
+
+    impl ELTrait for PendingBalanceL1 {
+        type Config = ();
+
+        async fn new(
+            _common_config: crate::l1::config::EthereumL1Config,
+            _specific_config: Self::Config,
+            _transaction_error_channel: tokio::sync::mpsc::Sender<
+                crate::l1::transaction_error::TransactionError,
+            >,
+            _metrics: Arc<Metrics>,
+        ) -> Result<Self, Error> {
+            Err(anyhow::anyhow!("created from a mock server in the tests"))
+        }
+
+        fn common(&self) -> &ExecutionLayer {
+            &self.common
+        }
+    }
+
+    impl PreconferProvider for PendingBalanceL1 {
+        fn get_preconfer_address(&self) -> Address {
+            Address::ZERO
+        }
+
+        async fn get_preconfer_nonce_pending(&self) -> Result<u64, Error> {
+            Ok(0)
         }
+
+        async fn get_preconfer_nonce_latest(&self) -> Result<u64, Error> {
+            Ok(0)
+        }
+
+        // Simulates an RPC call that never returns
+        async fn get_preconfer_wallet_eth(&self) -> Result<U256, Error> {
+            std::future::pending().await
+        }
+    }
+
+    struct NoopL2;
+
+    impl Bridgeable for NoopL2 {
+        async fn get_balance(&self, _address: Address) -> Result<U256, Error> {
+            Ok(U256::ZERO)
+        }
+
+        async fn transfer_eth_from_l2_to_l1(
+            &self,
+            _amount: u128,
+            _chain_id: u64,
+            _address: Address,
+            _bridge_relayer_fee: u64,
+        ) -> Result<(), Error> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cancel_during_initial_balance_check() {
+        let mut l1_server = mockito::Server::new_async().await;
+        let metrics = Arc::new(Metrics::new());
+        let cancel_token = CancellationToken::new(metrics.clone());
+        let config = FundsControllerConfig {
+            thresholds: Thresholds { eth: U256::ZERO },
+            amount_to_bridge_from_l2_to_l1: 0,
+            disable_bridging: true,
+            max_consecutive_bridge_failures: None,
+            cancel_on_bridge_failures: false,
+            bridge_relayer_fee: 0,
+            bridge_transaction_fee: 0,
+            monitor_interval: Duration::from_secs(60),
+            eth_burn_alert_horizon: None,
+        };
+        let controller = FundsController::new(
+            config,
+            Arc::new(PendingBalanceL1::new(&mut l1_server).await),
+            Arc::new(NoopL2),
+            metrics,
+            cancel_token.clone(),
+        );
+
+        let handle = tokio::spawn(controller.monitor_funds_level());
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        cancel_token.cancel();
+
+        tokio::time::timeout(Duration::from_secs(1), handle)
+            .await
+            .expect("funds monitor should exit promptly after cancellation")
+            .expect("funds monitor task should not panic");
+    }
+
+    #[test]
+    fn test_implausible_bridge_threshold() {
+        let eth = U256::from(1_000_000_000_000_000_000u128);
+        // 1000 ETH to bridge while holding 1 ETH on L2
+        assert!(is_bridge_threshold_implausible(eth * U256::from(1000), eth));
+        assert!(!is_bridge_threshold_implausible(eth / U256::from(2), eth));
+        assert!(!is_bridge_threshold_implausible(eth * U256::from(10), eth));
+        // Fresh preconfer without L2 funds
+        assert!(!is_bridge_threshold_implausible(eth, U256::ZERO));
     }
 }
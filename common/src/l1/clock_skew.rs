@@ -0,0 +1,69 @@
+use super::slot_clock::Clock;
+use crate::{metrics::Metrics, shared::execution_layer::ExecutionLayer};
+use anyhow::Error;
+use std::time::UNIX_EPOCH;
+use tracing::{info, warn};
+
+/// Difference between the local clock and the given L1 block timestamp in seconds.
+/// Positive when the local clock is ahead of L1.
+pub fn l1_clock_skew_sec<T: Clock>(clock: &T, l1_block_timestamp: u64) -> Result<i64, Error> {
+    let now = clock.now().duration_since(UNIX_EPOCH)?.as_secs();
+    Ok(i64::try_from(now)? - i64::try_from(l1_block_timestamp)?)
+}
+
+/// Compares the latest L1 block timestamp with the local clock at startup.
+/// Handover and end of sequencing timing rely on an accurate local clock, so a skew above
+/// `max_skew_sec` is reported, and fails the startup when `refuse_start` is set.
+pub async fn check_l1_clock_skew<T: Clock>(
+    execution_layer: &ExecutionLayer,
+    clock: &T,
+    max_skew_sec: u64,
+    refuse_start: bool,
+    metrics: &Metrics,
+) -> Result<(), Error> {
+    let (block_number, block_timestamp) = execution_layer
+        .get_latest_block_number_and_timestamp()
+        .await?;
+    let skew = l1_clock_skew_sec(clock, block_timestamp)?;
+    metrics.set_l1_clock_skew(skew);
+
+    if skew.unsigned_abs() <= max_skew_sec {
+        info!(
+            "Local clock skew relative to L1 block {}: {}s",
+            block_number, skew
+        );
+        return Ok(());
+    }
+
+    let msg = format!(
+        "Local clock is {skew}s off the latest L1 block {block_number} timestamp {block_timestamp}, more than the allowed {max_skew_sec}s. Check the system clock synchronization"
+    );
+    if refuse_start {
+        return Err(anyhow::anyhow!(msg));
+    }
+    warn!("{}", msg);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::DateTime;
+    use std::time::SystemTime;
+
+    #[derive(Default)]
+    struct SkewedClock;
+    impl Clock for SkewedClock {
+        fn now(&self) -> SystemTime {
+            SystemTime::from(DateTime::from_timestamp(1_000, 0).unwrap())
+        }
+    }
+
+    #[test]
+    fn test_l1_clock_skew() {
+        // Local clock ahead of L1
+        assert_eq!(l1_clock_skew_sec(&SkewedClock, 940).unwrap(), 60);
+        // Local clock behind L1
+        assert_eq!(l1_clock_skew_sec(&SkewedClock, 1_030).unwrap(), -30);
+    }
+}
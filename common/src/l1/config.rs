@@ -1,4 +1,5 @@
 use crate::config::Config;
+use crate::l1::fees_per_gas::PriorityFeeStrategy;
 use crate::signer::{Signer, create_signer};
 use alloy::primitives::Address;
 use anyhow::Error;
@@ -8,10 +9,15 @@ use std::time::Duration;
 #[derive(Clone)]
 pub struct EthereumL1Config {
     pub execution_rpc_urls: Vec<String>,
+    pub l1_rpc_height_divergence_threshold: Option<u64>,
+    pub l1_rpc_failover_cooldown_sec: u64,
     pub consensus_rpc_url: String,
     pub consensus_rpc_timeout: Duration,
+    pub consensus_rpc_timeout_max_retries: u64,
+    pub blob_consensus_rpc_url: Option<String>,
     pub blob_indexer_url: Option<String>,
     pub min_priority_fee_per_gas_wei: u64,
+    pub priority_fee_strategy: PriorityFeeStrategy,
     pub tx_fees_increase_percentage: u64,
     pub slot_duration_sec: u64,
     pub slots_per_epoch: u64,
@@ -19,9 +25,12 @@ pub struct EthereumL1Config {
     pub max_attempts_to_send_tx: u64,
     pub max_attempts_to_wait_tx: u64,
     pub delay_between_tx_attempts_sec: u64,
+    pub tx_resubmit_after_slots: u64,
+    pub tx_fee_bump_basis_points: u64,
     pub signer: Arc<Signer>,
     pub preconfer_address: Option<Address>,
     pub extra_gas_percentage: u64,
+    pub log_submitted_tx_hash: bool,
 }
 
 impl EthereumL1Config {
@@ -35,20 +44,28 @@ impl EthereumL1Config {
 
         Ok(Self {
             execution_rpc_urls: config.l1_rpc_urls.clone(),
+            l1_rpc_height_divergence_threshold: config.l1_rpc_height_divergence_threshold,
+            l1_rpc_failover_cooldown_sec: config.l1_rpc_failover_cooldown_sec,
             consensus_rpc_url: config.l1_beacon_url.clone(),
             consensus_rpc_timeout: config.l1_beacon_timeout,
+            consensus_rpc_timeout_max_retries: config.l1_beacon_timeout_max_retries,
+            blob_consensus_rpc_url: config.blob_cl_url.clone(),
             blob_indexer_url: config.blob_indexer_url.clone(),
             slot_duration_sec: config.l1_slot_duration_sec,
             slots_per_epoch: config.l1_slots_per_epoch,
             preconf_heartbeat_ms: config.preconf_heartbeat_ms,
             min_priority_fee_per_gas_wei: config.min_priority_fee_per_gas_wei,
+            priority_fee_strategy: config.priority_fee_strategy,
             tx_fees_increase_percentage: config.tx_fees_increase_percentage,
             max_attempts_to_send_tx: config.max_attempts_to_send_tx,
             max_attempts_to_wait_tx: config.max_attempts_to_wait_tx,
             delay_between_tx_attempts_sec: config.delay_between_tx_attempts_sec,
+            tx_resubmit_after_slots: config.tx_resubmit_after_slots,
+            tx_fee_bump_basis_points: config.tx_fee_bump_basis_points,
             signer,
             preconfer_address: config.preconfer_address,
             extra_gas_percentage: config.extra_gas_percentage,
+            log_submitted_tx_hash: config.log_submitted_tx_hash,
         })
     }
 }
@@ -5,6 +5,19 @@ use alloy::primitives::B256;
 use alloy::rpc::types::beacon::sidecar::{BeaconBlobBundle, GetBlobsResponse};
 use anyhow::Error;
 use reqwest;
+use tracing::warn;
+
+/// Consensus layer request which did not complete within the configured timeout.
+#[derive(Debug)]
+pub struct ConsensusLayerTimeout(pub String);
+
+impl std::fmt::Display for ConsensusLayerTimeout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Consensus layer request timed out: {}", self.0)
+    }
+}
+
+impl std::error::Error for ConsensusLayerTimeout {}
 
 pub struct ConsensusLayer {
     client: reqwest::Client,
@@ -43,6 +56,23 @@ impl ConsensusLayer {
         Ok(blobs.data)
     }
 
+    /// Same as `get_blobs`, but re-requests the blobs up to `max_retries` times, `backoff` apart,
+    /// when the request times out, as the blobs are usually served on a later attempt.
+    /// No retry is started after `max_duration`.
+    pub async fn get_blobs_retrying_on_timeout(
+        &self,
+        slot: u64,
+        versioned_hashes: &[B256],
+        max_retries: u64,
+        backoff: Duration,
+        max_duration: Duration,
+    ) -> Result<Vec<Blob>, Error> {
+        retry_on_timeout(max_retries, backoff, max_duration, || {
+            self.get_blobs(slot, versioned_hashes)
+        })
+        .await
+    }
+
     #[deprecated(
         note = "This method is deprecated in favor of get_blobs, which allows fetching only specific blobs."
     )]
@@ -130,7 +160,7 @@ impl ConsensusLayer {
             .await
             .map_err(|e| {
                 if e.is_timeout() {
-                    anyhow::anyhow!("Consensus layer request timed out: {}", path)
+                    Error::new(ConsensusLayerTimeout(path.to_string()))
                 } else {
                     anyhow::anyhow!(
                         "Consensus layer request failed with error: {}. Source: {:?}",
@@ -159,11 +189,113 @@ impl ConsensusLayer {
     }
 }
 
+/// Runs `f`, retrying it up to `max_retries` times after a consensus layer timeout, as long as
+/// the retry starts within `max_duration`. Any other error is returned right away.
+pub async fn retry_on_timeout<T, F, Fut>(
+    max_retries: u64,
+    backoff: Duration,
+    max_duration: Duration,
+    mut f: F,
+) -> Result<T, Error>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, Error>>,
+{
+    let start = tokio::time::Instant::now();
+    let mut attempt = 0;
+    loop {
+        match f().await {
+            Err(err)
+                if err.is::<ConsensusLayerTimeout>()
+                    && attempt < max_retries
+                    && start.elapsed() + backoff <= max_duration =>
+            {
+                attempt += 1;
+                warn!(
+                    "{}, retrying in {:?} ({}/{})",
+                    err, backoff, attempt, max_retries
+                );
+                tokio::time::sleep(backoff).await;
+            }
+            result => return result,
+        }
+    }
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
+    use std::cell::Cell;
     use tokio;
 
+    #[tokio::test(start_paused = true)]
+    async fn test_retry_on_timeout() {
+        let attempts = Cell::new(0);
+        let result = retry_on_timeout(3, Duration::from_secs(1), Duration::from_secs(3), || {
+            let attempts = &attempts;
+            async move {
+                attempts.set(attempts.get() + 1);
+                if attempts.get() < 3 {
+                    return Err(Error::new(ConsensusLayerTimeout(
+                        "eth/v1/beacon/blobs/1".into(),
+                    )));
+                }
+                Ok(attempts.get())
+            }
+        })
+        .await;
+        assert_eq!(result.unwrap(), 3);
+
+        // Retries exhausted, the timeout is returned
+        attempts.set(0);
+        let result: Result<(), Error> =
+            retry_on_timeout(1, Duration::from_secs(1), Duration::from_secs(3), || {
+                let attempts = &attempts;
+                async move {
+                    attempts.set(attempts.get() + 1);
+                    Err(Error::new(ConsensusLayerTimeout(
+                        "eth/v1/beacon/blobs/1".into(),
+                    )))
+                }
+            })
+            .await;
+        assert!(result.unwrap_err().is::<ConsensusLayerTimeout>());
+        assert_eq!(attempts.get(), 2);
+
+        // Other errors are not retried
+        attempts.set(0);
+        let result: Result<(), Error> =
+            retry_on_timeout(3, Duration::from_secs(1), Duration::from_secs(3), || {
+                let attempts = &attempts;
+                async move {
+                    attempts.set(attempts.get() + 1);
+                    Err(anyhow::anyhow!(
+                        "Consensus layer request failed with status: 404"
+                    ))
+                }
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(attempts.get(), 1);
+
+        // No retry is started past the maximum duration
+        attempts.set(0);
+        let result: Result<(), Error> =
+            retry_on_timeout(5, Duration::from_secs(1), Duration::from_secs(3), || {
+                let attempts = &attempts;
+                async move {
+                    attempts.set(attempts.get() + 1);
+                    tokio::time::sleep(Duration::from_secs(2)).await;
+                    Err(Error::new(ConsensusLayerTimeout(
+                        "eth/v1/beacon/blobs/1".into(),
+                    )))
+                }
+            })
+            .await;
+        assert!(result.unwrap_err().is::<ConsensusLayerTimeout>());
+        assert_eq!(attempts.get(), 2);
+    }
+
     #[tokio::test]
     async fn test_get_genesis_data() {
         let server = setup_server().await;
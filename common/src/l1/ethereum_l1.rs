@@ -11,8 +11,12 @@ use crate::metrics::Metrics;
 pub struct EthereumL1<T: ELTrait> {
     pub slot_clock: Arc<SlotClock>,
     pub consensus_layer: ConsensusLayer,
+    /// Dedicated beacon node for blob fetches, e.g. a blob archive node.
+    pub blob_consensus_layer: Option<ConsensusLayer>,
     pub execution_layer: Arc<T>,
     pub blob_indexer: Option<Arc<BlobIndexer>>,
+    /// Re-requests of a timed out blob fetch before giving up.
+    pub consensus_rpc_timeout_max_retries: u64,
 }
 
 impl<T: ELTrait> EthereumL1<T> {
@@ -27,6 +31,16 @@ impl<T: ELTrait> EthereumL1<T> {
         let consensus_layer =
             ConsensusLayer::new(&config.consensus_rpc_url, config.consensus_rpc_timeout)?;
 
+        let blob_consensus_layer = if let Some(blob_rpc_url) = &config.blob_consensus_rpc_url {
+            tracing::info!("Blob consensus layer configured at {}", blob_rpc_url);
+            Some(ConsensusLayer::new(
+                blob_rpc_url,
+                config.consensus_rpc_timeout,
+            )?)
+        } else {
+            None
+        };
+
         let blob_indexer = if let Some(blob_indexer_url) = &config.blob_indexer_url {
             tracing::info!("Blob Indexer configured at {}", blob_indexer_url);
             Some(Arc::new(BlobIndexer::new(
@@ -38,6 +52,7 @@ impl<T: ELTrait> EthereumL1<T> {
             None
         };
 
+        let consensus_rpc_timeout_max_retries = config.consensus_rpc_timeout_max_retries;
         let genesis_time = consensus_layer.get_genesis_time().await?;
         let slot_clock = Arc::new(SlotClock::new(
             0u64,
@@ -53,8 +68,54 @@ impl<T: ELTrait> EthereumL1<T> {
         Ok(Self {
             slot_clock,
             consensus_layer,
+            blob_consensus_layer,
             execution_layer: Arc::new(execution_layer),
             blob_indexer,
+            consensus_rpc_timeout_max_retries,
         })
     }
+
+    /// Consensus layer to fetch blobs from, falling back to the main one.
+    pub fn blob_consensus_layer(&self) -> &ConsensusLayer {
+        select_blob_consensus_layer(&self.consensus_layer, self.blob_consensus_layer.as_ref())
+    }
+}
+
+fn select_blob_consensus_layer<'a>(
+    consensus_layer: &'a ConsensusLayer,
+    blob_consensus_layer: Option<&'a ConsensusLayer>,
+) -> &'a ConsensusLayer {
+    blob_consensus_layer.unwrap_or(consensus_layer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn setup_blobs_server(status: usize) -> mockito::ServerGuard {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/eth/v1/beacon/blobs/10")
+            .with_status(status)
+            .with_body(r#"{"execution_optimistic":false,"finalized":true,"data":[]}"#)
+            .create_async()
+            .await;
+        server
+    }
+
+    #[tokio::test]
+    async fn test_blob_consensus_layer_is_used_when_configured() {
+        let main_server = setup_blobs_server(500).await;
+        let blob_server = setup_blobs_server(200).await;
+        let timeout = Duration::from_secs(1);
+        let main_cl = ConsensusLayer::new(&format!("{}/", main_server.url()), timeout).unwrap();
+        let blob_cl = ConsensusLayer::new(&format!("{}/", blob_server.url()), timeout).unwrap();
+
+        let selected = select_blob_consensus_layer(&main_cl, Some(&blob_cl));
+        assert!(selected.get_blobs(10, &[]).await.unwrap().is_empty());
+
+        // Falls back to the main consensus layer
+        let selected = select_blob_consensus_layer(&main_cl, None);
+        assert!(selected.get_blobs(10, &[]).await.is_err());
+    }
 }
@@ -0,0 +1,145 @@
+use super::fees_per_gas::{FeesPerGas, PriorityFeeStrategy};
+use alloy::providers::DynProvider;
+use anyhow::Error;
+use std::{sync::Arc, time::Duration};
+use tokio::{sync::RwLock, task::JoinHandle, time::Instant};
+use tracing::{debug, warn};
+
+struct CachedFeesPerGas {
+    fees_per_gas: FeesPerGas,
+    last_updated: Instant,
+}
+
+/// Fees per gas refreshed in the background, so building a transaction does not wait for the
+/// fee history RPC, which can take seconds right after a restart.
+#[derive(Clone)]
+pub struct FeeHistoryCache {
+    provider: DynProvider,
+    strategy: PriorityFeeStrategy,
+    max_age: Duration,
+    cached: Arc<RwLock<Option<CachedFeesPerGas>>>,
+}
+
+impl FeeHistoryCache {
+    /// Entries older than `max_age` are ignored and fetched again on read.
+    pub fn new(provider: DynProvider, strategy: PriorityFeeStrategy, max_age: Duration) -> Self {
+        Self {
+            provider,
+            strategy,
+            max_age,
+            cached: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    /// Refreshes the cache every `interval` until the returned task is aborted.
+    pub fn spawn_refresh_task(&self, interval: Duration) -> JoinHandle<()> {
+        let cache = self.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(interval);
+            loop {
+                interval.tick().await;
+                if let Err(err) = cache.refresh().await {
+                    warn!("Failed to refresh L1 fee history: {}", err);
+                }
+            }
+        })
+    }
+
+    /// Fetches the fees per gas and stores them in the cache.
+    pub async fn refresh(&self) -> Result<FeesPerGas, Error> {
+        let fees_per_gas = FeesPerGas::get_fees_per_gas(&self.provider, self.strategy).await?;
+        *self.cached.write().await = Some(CachedFeesPerGas {
+            fees_per_gas: fees_per_gas.clone(),
+            last_updated: Instant::now(),
+        });
+        Ok(fees_per_gas)
+    }
+
+    /// Time of the last successful refresh, `None` before the first one.
+    pub async fn last_updated(&self) -> Option<Instant> {
+        self.cached
+            .read()
+            .await
+            .as_ref()
+            .map(|cached| cached.last_updated)
+    }
+
+    /// Returns the cached fees per gas, fetching them only when the cache is empty or stale.
+    pub async fn get(&self) -> Result<FeesPerGas, Error> {
+        if let Some(fees_per_gas) = self.get_cached().await {
+            return Ok(fees_per_gas);
+        }
+        debug!("No fresh L1 fee history cached, fetching");
+        self.refresh().await
+    }
+
+    /// Returns the cached fees per gas if they are fresh, without fetching them.
+    pub async fn get_cached(&self) -> Option<FeesPerGas> {
+        self.cached
+            .read()
+            .await
+            .as_ref()
+            .filter(|cached| cached.last_updated.elapsed() <= self.max_age)
+            .map(|cached| cached.fees_per_gas.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::alloy_tools;
+    use serde_json::Value;
+
+    #[tokio::test]
+    async fn test_fresh_cache_entry_does_not_call_rpc() {
+        let mut server = mockito::Server::new_async().await;
+        let fee_history = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::Regex("eth_feeHistory".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body_from_request(|request| {
+                let body: Value = serde_json::from_slice(request.body().expect("request body"))
+                    .expect("valid JSON-RPC request");
+                serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "id": body["id"],
+                    "result": {
+                        "oldestBlock": "0x1",
+                        "baseFeePerGas": ["0x3b9aca00", "0x3b9aca00"],
+                        "gasUsedRatio": [0.5],
+                        "baseFeePerBlobGas": ["0x1", "0x1"],
+                        "blobGasUsedRatio": [0.5]
+                    }
+                })
+                .to_string()
+                .into()
+            })
+            .expect(1)
+            .create_async()
+            .await;
+
+        let provider = alloy_tools::create_alloy_provider_without_wallet(&server.url())
+            .await
+            .expect("failed to create provider");
+        let cache = FeeHistoryCache::new(
+            provider,
+            PriorityFeeStrategy::Fixed(1_000_000_000),
+            Duration::from_secs(24),
+        );
+        assert!(cache.last_updated().await.is_none());
+        assert!(cache.get_cached().await.is_none());
+
+        // Empty cache falls back to a blocking fetch
+        cache.get().await.expect("failed to fetch fees");
+        assert!(cache.last_updated().await.is_some());
+
+        // Fresh entry is served from the cache
+        cache.get().await.expect("failed to read cached fees");
+        assert_eq!(
+            cache.get_cached().await.map(|fees| fees.base_fee_per_gas()),
+            Some(1_000_000_000)
+        );
+        fee_history.assert_async().await;
+    }
+}
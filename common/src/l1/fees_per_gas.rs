@@ -1,10 +1,63 @@
 use alloy::{
     network::{TransactionBuilder, TransactionBuilder4844},
     providers::{DynProvider, Provider},
-    rpc::types::TransactionRequest,
+    rpc::types::{FeeHistory, TransactionRequest},
 };
 use anyhow::Error;
 
+/// Number of recent blocks used by the percentile priority fee strategy
+const PRIORITY_FEE_HISTORY_BLOCKS: u64 = 10;
+
+/// How the max priority fee per gas is chosen for L1 transactions
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum PriorityFeeStrategy {
+    /// Provider EIP-1559 fee estimation
+    #[default]
+    Estimate,
+    /// Fixed priority fee in wei
+    Fixed(u128),
+    /// Average of the given reward percentile over recent blocks
+    Percentile(f64),
+    /// Percentage of the latest base fee
+    BaseFeePercentage(u64),
+}
+
+impl std::fmt::Display for PriorityFeeStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Estimate => write!(f, "estimate"),
+            Self::Fixed(wei) => write!(f, "fixed {wei}wei"),
+            Self::Percentile(percentile) => write!(f, "percentile {percentile}"),
+            Self::BaseFeePercentage(percentage) => write!(f, "{percentage}% of base fee"),
+        }
+    }
+}
+
+impl PriorityFeeStrategy {
+    /// Computes the priority fee from the fee history, `None` for the `Estimate` strategy.
+    fn priority_fee_from_history(&self, fee_history: &FeeHistory) -> Option<u128> {
+        match self {
+            Self::Estimate => None,
+            Self::Fixed(wei) => Some(*wei),
+            Self::Percentile(_) => {
+                let rewards: Vec<u128> = fee_history
+                    .reward
+                    .as_ref()?
+                    .iter()
+                    .filter_map(|block_rewards| block_rewards.first().copied())
+                    .collect();
+                let count = u128::try_from(rewards.len()).ok().filter(|c| *c > 0)?;
+                Some(rewards.iter().sum::<u128>() / count)
+            }
+            Self::BaseFeePercentage(percentage) => {
+                let base_fee = fee_history.base_fee_per_gas.last().copied()?;
+                Some(base_fee * u128::from(*percentage) / 100)
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
 pub struct FeesPerGas {
     base_fee_per_gas: u128,
     base_fee_per_blob_gas: u128,
@@ -13,6 +66,10 @@ pub struct FeesPerGas {
 }
 
 impl FeesPerGas {
+    pub fn base_fee_per_gas(&self) -> u128 {
+        self.base_fee_per_gas
+    }
+
     pub fn update_eip1559(&self, tx: TransactionRequest, gas_limit: u64) -> TransactionRequest {
         tx.with_gas_limit(gas_limit)
             .with_max_fee_per_gas(self.max_fee_per_gas)
@@ -38,11 +95,27 @@ impl FeesPerGas {
         execution_gas_cost + blob_gas_cost
     }
 
-    pub async fn get_fees_per_gas(provider_ws: &DynProvider) -> Result<Self, Error> {
+    pub async fn get_fees_per_gas(
+        provider_ws: &DynProvider,
+        strategy: PriorityFeeStrategy,
+    ) -> Result<Self, Error> {
         // Get base fee per gas
-        let fee_history = provider_ws
-            .get_fee_history(2, alloy::eips::BlockNumberOrTag::Latest, &[])
-            .await?;
+        let fee_history = match strategy {
+            PriorityFeeStrategy::Percentile(percentile) => {
+                provider_ws
+                    .get_fee_history(
+                        PRIORITY_FEE_HISTORY_BLOCKS,
+                        alloy::eips::BlockNumberOrTag::Latest,
+                        &[percentile],
+                    )
+                    .await?
+            }
+            _ => {
+                provider_ws
+                    .get_fee_history(2, alloy::eips::BlockNumberOrTag::Latest, &[])
+                    .await?
+            }
+        };
 
         let base_fee_per_gas = fee_history
             .base_fee_per_gas
@@ -58,6 +131,22 @@ impl FeesPerGas {
                 anyhow::Error::msg("Failed to get base_fee_per_blob_gas from fee history")
             })?;
 
+        if let Some(max_priority_fee_per_gas) = strategy.priority_fee_from_history(&fee_history) {
+            let max_fee_per_gas = 2 * base_fee_per_gas + max_priority_fee_per_gas;
+            tracing::info!(
+                ">max_fee_per_gas: {} priority fee ({}): {}",
+                max_fee_per_gas,
+                strategy,
+                max_priority_fee_per_gas
+            );
+            return Ok(Self {
+                base_fee_per_gas,
+                base_fee_per_blob_gas,
+                max_fee_per_gas,
+                max_priority_fee_per_gas,
+            });
+        }
+
         let eip1559_estimation = provider_ws.estimate_eip1559_fees().await?;
 
         tracing::info!(
@@ -74,3 +163,58 @@ impl FeesPerGas {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_fee_history(base_fees: Vec<u128>, rewards: Vec<u128>) -> FeeHistory {
+        FeeHistory {
+            base_fee_per_gas: base_fees,
+            reward: Some(rewards.into_iter().map(|reward| vec![reward]).collect()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_estimate_strategy_uses_provider() {
+        let fee_history = make_fee_history(vec![10, 20], vec![1, 2]);
+        assert_eq!(
+            PriorityFeeStrategy::Estimate.priority_fee_from_history(&fee_history),
+            None
+        );
+    }
+
+    #[test]
+    fn test_fixed_strategy() {
+        let fee_history = make_fee_history(vec![10, 20], vec![1, 2]);
+        assert_eq!(
+            PriorityFeeStrategy::Fixed(2_000_000_000).priority_fee_from_history(&fee_history),
+            Some(2_000_000_000)
+        );
+    }
+
+    #[test]
+    fn test_percentile_strategy() {
+        let fee_history = make_fee_history(vec![10, 20], vec![100, 200, 300, 400]);
+        assert_eq!(
+            PriorityFeeStrategy::Percentile(50.0).priority_fee_from_history(&fee_history),
+            Some(250)
+        );
+
+        let without_rewards = FeeHistory::default();
+        assert_eq!(
+            PriorityFeeStrategy::Percentile(50.0).priority_fee_from_history(&without_rewards),
+            None
+        );
+    }
+
+    #[test]
+    fn test_base_fee_percentage_strategy() {
+        let fee_history = make_fee_history(vec![1_000, 3_000], vec![]);
+        assert_eq!(
+            PriorityFeeStrategy::BaseFeePercentage(10).priority_fee_from_history(&fee_history),
+            Some(300)
+        );
+    }
+}
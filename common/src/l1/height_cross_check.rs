@@ -0,0 +1,159 @@
+use crate::{l1::config::EthereumL1Config, metrics::Metrics, shared::alloy_tools};
+use alloy::providers::{DynProvider, Provider};
+use anyhow::Error;
+use futures_util::future::join_all;
+use std::{sync::Arc, time::Duration};
+use tokio::time::timeout;
+use tracing::{debug, warn};
+
+const HEIGHT_QUERY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Reads the L1 height from every configured endpoint and settles on the median, so a single
+/// endpoint lagging behind or running ahead does not drive the anchor and operator logic.
+pub struct L1HeightCrossCheck {
+    endpoints: Vec<(String, DynProvider)>,
+    divergence_threshold: u64,
+    metrics: Arc<Metrics>,
+}
+
+impl L1HeightCrossCheck {
+    pub async fn new(
+        urls: &[String],
+        divergence_threshold: u64,
+        metrics: Arc<Metrics>,
+    ) -> Result<Self, Error> {
+        let mut endpoints = Vec::with_capacity(urls.len());
+        for url in urls {
+            let provider = alloy_tools::create_alloy_provider_without_wallet(url).await?;
+            endpoints.push((url.clone(), provider));
+        }
+        Ok(Self {
+            endpoints,
+            divergence_threshold,
+            metrics,
+        })
+    }
+
+    /// Creates the cross check when a divergence threshold is configured and there is more than
+    /// one L1 endpoint to compare.
+    pub async fn from_config(
+        config: &EthereumL1Config,
+        metrics: Arc<Metrics>,
+    ) -> Result<Option<Self>, Error> {
+        match config.l1_rpc_height_divergence_threshold {
+            Some(threshold) if config.execution_rpc_urls.len() > 1 => Ok(Some(
+                Self::new(&config.execution_rpc_urls, threshold, metrics).await?,
+            )),
+            _ => Ok(None),
+        }
+    }
+
+    /// Returns the median height of the endpoints that answered, failing only when none did.
+    pub async fn get_latest_block_id(&self) -> Result<u64, Error> {
+        let heights: Vec<u64> = join_all(
+            self.endpoints
+                .iter()
+                .map(|(url, provider)| query_height(url, provider)),
+        )
+        .await
+        .into_iter()
+        .flatten()
+        .collect();
+
+        let (height, divergence) = median_height_and_divergence(&heights)
+            .ok_or_else(|| anyhow::anyhow!("No L1 RPC endpoint reported its height"))?;
+        self.metrics.set_l1_rpc_height_divergence(divergence);
+        if divergence > self.divergence_threshold {
+            warn!(
+                "L1 RPC endpoints diverge by {} blocks (threshold {}), heights {:?}, using median {}. Possible provider issue",
+                divergence, self.divergence_threshold, heights, height
+            );
+        } else {
+            debug!("L1 RPC endpoints heights {:?}, using {}", heights, height);
+        }
+        Ok(height)
+    }
+}
+
+async fn query_height(url: &str, provider: &DynProvider) -> Option<u64> {
+    match timeout(HEIGHT_QUERY_TIMEOUT, provider.get_block_number()).await {
+        Ok(Ok(height)) => Some(height),
+        Ok(Err(e)) => {
+            warn!("L1 RPC endpoint {} failed to report its height: {}", url, e);
+            None
+        }
+        Err(_) => {
+            warn!(
+                "L1 RPC endpoint {} timed out after {:?} reporting its height",
+                url, HEIGHT_QUERY_TIMEOUT
+            );
+            None
+        }
+    }
+}
+
+/// Median of the reported heights together with the spread between the highest and the lowest.
+/// With an even number of heights the lower of the two middle values is taken.
+pub fn median_height_and_divergence(heights: &[u64]) -> Option<(u64, u64)> {
+    let mut sorted = heights.to_vec();
+    sorted.sort_unstable();
+    let lowest = *sorted.first()?;
+    let highest = *sorted.last()?;
+    let median = sorted[(sorted.len() - 1) / 2];
+    Some((median, highest - lowest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    async fn server_at_height(height: u64) -> mockito::ServerGuard {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", "/")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body_from_request(move |request| {
+                let body: Value = serde_json::from_slice(request.body().expect("request body"))
+                    .expect("valid JSON-RPC request");
+                serde_json::json!({"jsonrpc": "2.0", "id": body["id"], "result": format!("{height:#x}")})
+                    .to_string()
+                    .into()
+            })
+            .create_async()
+            .await;
+        server
+    }
+
+    #[tokio::test]
+    async fn test_median_height_across_divergent_endpoints() {
+        let lagging = server_at_height(100).await;
+        let synced = server_at_height(102).await;
+        let ahead = server_at_height(110).await;
+
+        let metrics = Arc::new(Metrics::new());
+        let cross_check = L1HeightCrossCheck::new(
+            &[ahead.url(), lagging.url(), synced.url()],
+            2,
+            metrics.clone(),
+        )
+        .await
+        .expect("failed to create providers");
+
+        assert_eq!(cross_check.get_latest_block_id().await.unwrap(), 102);
+        assert!(
+            metrics
+                .gather()
+                .contains("catalyst_l1_rpc_height_divergence 10")
+        );
+    }
+
+    #[test]
+    fn test_median_height_and_divergence() {
+        assert_eq!(median_height_and_divergence(&[]), None);
+        assert_eq!(median_height_and_divergence(&[7]), Some((7, 0)));
+        assert_eq!(median_height_and_divergence(&[12, 10]), Some((10, 2)));
+        assert_eq!(median_height_and_divergence(&[5, 9, 6, 8]), Some((6, 4)));
+    }
+}
@@ -1,9 +1,12 @@
 pub mod bindings;
 pub mod blob_indexer;
+pub mod clock_skew;
 pub mod config;
 pub mod consensus_layer;
 pub mod ethereum_l1;
+pub mod fee_history_cache;
 pub mod fees_per_gas;
+pub mod height_cross_check;
 pub mod slot_clock;
 pub mod tools;
 pub mod traits;
@@ -204,7 +204,7 @@ impl<T: Clock> SlotClock<T> {
     }
 
     pub fn is_slot_in_last_n_slots_of_epoch(&self, slot: Slot, n: Slot) -> bool {
-        slot >= self.slots_per_epoch - n && slot < self.slots_per_epoch
+        slot >= self.slots_per_epoch.saturating_sub(n) && slot < self.slots_per_epoch
     }
 
     pub fn time_from_n_last_slots_of_epoch(
@@ -212,7 +212,7 @@ impl<T: Clock> SlotClock<T> {
         l1_slot_of_epoch: Slot,
         n: Slot,
     ) -> Result<Duration, Error> {
-        let boundary_slot = self.slots_per_epoch - n;
+        let boundary_slot = self.slots_per_epoch.saturating_sub(n);
         if l1_slot_of_epoch < boundary_slot {
             return Err(anyhow::anyhow!(
                 "time_from_n_last_slots_of_epoch: too early, slot {} is less than boundary slot {}",
@@ -223,8 +223,22 @@ impl<T: Clock> SlotClock<T> {
 
         let epoch = self.get_current_epoch()?;
         let boundary_slot_begin = self.start_of(boundary_slot + epoch * self.slots_per_epoch)?;
+        let now = self.clock.now().duration_since(UNIX_EPOCH)?;
 
-        Ok(self.clock.now().duration_since(UNIX_EPOCH)? - boundary_slot_begin)
+        now.checked_sub(boundary_slot_begin).ok_or_else(|| {
+            tracing::warn!(
+                "time_from_n_last_slots_of_epoch: slot {} of epoch {} is in the past, now {}ms, boundary slot begin {}ms",
+                l1_slot_of_epoch,
+                epoch,
+                now.as_millis(),
+                boundary_slot_begin.as_millis()
+            );
+            anyhow::anyhow!(
+                "time_from_n_last_slots_of_epoch: current time is before the handover boundary of epoch {}, slot {} does not belong to the current epoch",
+                epoch,
+                l1_slot_of_epoch
+            )
+        })
     }
 
     // 0 based L2 slot number within the current L1 slot
@@ -237,7 +251,13 @@ impl<T: Clock> SlotClock<T> {
 
     pub fn get_l2_slot_begin_timestamp(&self) -> Result<u64, Error> {
         let now = self.clock.now().duration_since(UNIX_EPOCH)?;
-        let now_from_genesis = now - self.genesis_duration;
+        let now_from_genesis = now.checked_sub(self.genesis_duration).ok_or_else(|| {
+            anyhow::anyhow!(
+                "get_l2_slot_begin_timestamp: current time {}s is before genesis {}s",
+                now.as_secs(),
+                self.genesis_duration.as_secs()
+            )
+        })?;
         let preconf_heartbeat_ms: u128 = u128::from(self.preconf_heartbeat_ms);
         let timestamp_sec = TryInto::<u64>::try_into(
             ((now_from_genesis.as_millis() / preconf_heartbeat_ms) * preconf_heartbeat_ms) / 1000,
@@ -265,10 +285,15 @@ impl<T: Clock> SlotClock<T> {
             current_slot
         );
 
+        // A block from the future can only be caused by clock skew, treat it as just produced
         if current_slot < block_slot {
-            return Err(anyhow::anyhow!(
-                "slots_since_l1_block: Current slot is less than block slot"
-            ));
+            tracing::warn!(
+                "slots_since_l1_block: block timestamp {} (slot {}) is ahead of current slot {}, clamping to 0",
+                l1_block_timestamp,
+                block_slot,
+                current_slot
+            );
+            return Ok(0);
         }
 
         Ok(current_slot - block_slot)
@@ -510,6 +535,25 @@ mod tests {
 
         let duration = slot_clock.time_from_n_last_slots_of_epoch(29, 3).unwrap();
         assert_eq!(duration, Duration::from_secs(5));
+
+        #[derive(Default)]
+        pub struct MockClock3;
+        impl Clock for MockClock3 {
+            fn now(&self) -> SystemTime {
+                SystemTime::from(DateTime::from_timestamp(400, 0).unwrap()) // epoch 1, slot 1
+            }
+        }
+
+        let slot_clock = SlotClock::<MockClock3>::new(
+            0u64,
+            0,
+            SLOT_DURATION,
+            SLOTS_PER_EPOCH,
+            PRECONF_HEART_BEAT_MS,
+        );
+
+        // Slot 29 of the previous epoch, current time is before this epoch's boundary
+        assert!(slot_clock.time_from_n_last_slots_of_epoch(29, 3).is_err());
     }
 
     #[test]
@@ -592,6 +636,9 @@ mod tests {
 
         // Test case 3: L1 block timestamp is at slot 8
         assert_eq!(slot_clock.slots_since_l1_block(96).unwrap(), 0);
+
+        // Test case 4: L1 block timestamp is in the future (slot 10)
+        assert_eq!(slot_clock.slots_since_l1_block(120).unwrap(), 0);
     }
 
     #[test]
@@ -1,53 +1,59 @@
 use super::transaction_error::TransactionError;
 
 // shasta
-// 0xe92c469f -> InsufficientBond()
+pub const INSUFFICIENT_BOND: &str = "0xe92c469f";
+
+pub const ANCHOR_BLOCK_ID_TOO_SMALL: &str = "0x46afbf54";
+pub const TIMESTAMP_TOO_SMALL: &str = "0x1999aed2";
+pub const ANCHOR_BLOCK_ID_SMALLER_THAN_PARENT: &str = "0xfe1698b2";
+pub const TIMESTAMP_SMALLER_THAN_PARENT: &str = "0x21389b84";
+
+pub const TIMESTAMP_TOO_LARGE: &str = "0x3d32ffdb";
+pub const ZERO_ANCHOR_BLOCK_HASH: &str = "0x2b44f010";
+// shasta
+pub const CANNOT_PROPOSE_IN_CURRENT_BLOCK: &str = "0x92a2f43a";
+
+pub const OLDEST_FORCED_INCLUSION_DUE: &str = "0x1e66a770";
+// shasta
+pub const UNPROCESSED_FORCED_INCLUSION_IS_DUE: &str = "0x02967fb6";
+
+// TODO: for new contracts version we should remove NotTheOperator
+// as it was renamed to NotPreconfer
+pub const NOT_THE_OPERATOR: &str = "0x47fac6c1";
+pub const NOT_PRECONFER: &str = "0x795e2f19";
+pub const NOT_PRECONFER_OR_FALLBACK: &str = "0xc0ec4b50";
+// shasta
+pub const INVALID_PROPOSER: &str = "0x4100ac03";
+
 pub fn check_for_insufficient_funds(err_str: &str) -> bool {
     err_str.contains("insufficient funds")
         || err_str.contains("insufficient allowance")
-        || err_str.contains("0xe92c469f")
+        || err_str.contains(INSUFFICIENT_BOND)
 }
 
-/// 0x46afbf54 -> AnchorBlockIdTooSmall()
-/// 0x1999aed2 -> TimestampTooSmall()
-/// 0xfe1698b2 -> AnchorBlockIdSmallerThanParent()
-/// 0x21389b84 -> TimestampSmallerThanParent()
 pub fn check_for_reanchor_required(err_str: &str) -> bool {
-    err_str.contains("0x46afbf54")
-        || err_str.contains("0x1999aed2")
-        || err_str.contains("0xfe1698b2")
-        || err_str.contains("0x21389b84")
+    err_str.contains(ANCHOR_BLOCK_ID_TOO_SMALL)
+        || err_str.contains(TIMESTAMP_TOO_SMALL)
+        || err_str.contains(ANCHOR_BLOCK_ID_SMALLER_THAN_PARENT)
+        || err_str.contains(TIMESTAMP_SMALLER_THAN_PARENT)
 }
 
-/// 0x3d32ffdb -> TimestampTooLarge()
-/// 0x2b44f010 -> ZeroAnchorBlockHash()
-/// shasta
-/// 0x92a2f43a -> CannotProposeInCurrentBlock()
 pub fn check_for_too_early_estimation(err_str: &str) -> bool {
-    err_str.contains("0x3d32ffdb")
-        || err_str.contains("0x2b44f010")
-        || err_str.contains("0x92a2f43a")
+    err_str.contains(TIMESTAMP_TOO_LARGE)
+        || err_str.contains(ZERO_ANCHOR_BLOCK_HASH)
+        || err_str.contains(CANNOT_PROPOSE_IN_CURRENT_BLOCK)
 }
 
-// 0x1e66a770 -> OldestForcedInclusionDue()
-// shasta
-// 0x02967fb6 -> UnprocessedForcedInclusionIsDue()
 pub fn check_oldest_forced_inclusion_due(err_str: &str) -> bool {
-    err_str.contains("0x1e66a770") || err_str.contains("0x02967fb6")
+    err_str.contains(OLDEST_FORCED_INCLUSION_DUE)
+        || err_str.contains(UNPROCESSED_FORCED_INCLUSION_IS_DUE)
 }
 
-// 0x47fac6c1 -> NotTheOperator()
-// 0x795e2f19 -> NotPreconfer()
-// 0xc0ec4b50 -> NotPreconferOrFallback()
-// shasta
-// 0x4100ac03 -> InvalidProposer()
 pub fn check_for_not_the_operator_in_current_epoch(err_str: &str) -> bool {
-    // TODO: for new contracts version we should remove NotTheOperator
-    // as it was renamed to NotPreconfer
-    err_str.contains("0x47fac6c1")
-        || err_str.contains("0x795e2f19")
-        || err_str.contains("0xc0ec4b50")
-        || err_str.contains("0x4100ac03")
+    err_str.contains(NOT_THE_OPERATOR)
+        || err_str.contains(NOT_PRECONFER)
+        || err_str.contains(NOT_PRECONFER_OR_FALLBACK)
+        || err_str.contains(INVALID_PROPOSER)
 }
 
 pub fn convert_error_payload(err: &str) -> Option<TransactionError> {
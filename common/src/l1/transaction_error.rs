@@ -1,3 +1,6 @@
+use super::tools;
+use alloy::primitives::Selector;
+
 #[derive(Debug, Clone)]
 pub enum TransactionError {
     BuildFailed,
@@ -11,6 +14,27 @@ pub enum TransactionError {
     ReanchorRequired,
     OldestForcedInclusionDue,
     NotTheOperatorInCurrentEpoch,
+    ContractRevert(ContractError),
+}
+
+impl TransactionError {
+    /// Classifies the revert data returned by a contract call by its 4-byte selector.
+    /// Data too short to hold a selector is reported as a plain revert.
+    pub fn from_revert_data(data: &[u8]) -> TransactionError {
+        match data.get(..4) {
+            Some(selector) => ContractError::from_selector(Selector::from_slice(selector))
+                .into_transaction_error(),
+            None => TransactionError::TransactionReverted,
+        }
+    }
+
+    /// Revert selector of a decoded contract error.
+    pub fn selector(&self) -> Option<Selector> {
+        match self {
+            TransactionError::ContractRevert(error) => Some(error.selector()),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for TransactionError {
@@ -18,3 +42,182 @@ impl std::fmt::Display for TransactionError {
         write!(f, "{self:?}")
     }
 }
+
+/// Contract errors known to the node, see `tools.rs` for the selectors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContractError {
+    NotTheOperator,
+    NotPreconfer,
+    NotPreconferOrFallback,
+    InvalidProposer,
+    InsufficientBond,
+    AnchorBlockIdTooSmall,
+    TimestampTooSmall,
+    AnchorBlockIdSmallerThanParent,
+    TimestampSmallerThanParent,
+    TimestampTooLarge,
+    ZeroAnchorBlockHash,
+    CannotProposeInCurrentBlock,
+    OldestForcedInclusionDue,
+    UnprocessedForcedInclusionIsDue,
+    Unknown(Selector),
+}
+
+impl ContractError {
+    const KNOWN: [ContractError; 14] = [
+        ContractError::NotTheOperator,
+        ContractError::NotPreconfer,
+        ContractError::NotPreconferOrFallback,
+        ContractError::InvalidProposer,
+        ContractError::InsufficientBond,
+        ContractError::AnchorBlockIdTooSmall,
+        ContractError::TimestampTooSmall,
+        ContractError::AnchorBlockIdSmallerThanParent,
+        ContractError::TimestampSmallerThanParent,
+        ContractError::TimestampTooLarge,
+        ContractError::ZeroAnchorBlockHash,
+        ContractError::CannotProposeInCurrentBlock,
+        ContractError::OldestForcedInclusionDue,
+        ContractError::UnprocessedForcedInclusionIsDue,
+    ];
+
+    pub fn from_selector(selector: Selector) -> Self {
+        let selector_str = selector.to_string();
+        Self::KNOWN
+            .into_iter()
+            .find(|known| known.known_selector() == Some(selector_str.as_str()))
+            .unwrap_or(ContractError::Unknown(selector))
+    }
+
+    pub fn selector(&self) -> Selector {
+        match self {
+            ContractError::Unknown(selector) => *selector,
+            known => known
+                .known_selector()
+                .and_then(|selector| selector.parse().ok())
+                .unwrap_or_default(),
+        }
+    }
+
+    fn known_selector(&self) -> Option<&'static str> {
+        let selector = match self {
+            ContractError::NotTheOperator => tools::NOT_THE_OPERATOR,
+            ContractError::NotPreconfer => tools::NOT_PRECONFER,
+            ContractError::NotPreconferOrFallback => tools::NOT_PRECONFER_OR_FALLBACK,
+            ContractError::InvalidProposer => tools::INVALID_PROPOSER,
+            ContractError::InsufficientBond => tools::INSUFFICIENT_BOND,
+            ContractError::AnchorBlockIdTooSmall => tools::ANCHOR_BLOCK_ID_TOO_SMALL,
+            ContractError::TimestampTooSmall => tools::TIMESTAMP_TOO_SMALL,
+            ContractError::AnchorBlockIdSmallerThanParent => {
+                tools::ANCHOR_BLOCK_ID_SMALLER_THAN_PARENT
+            }
+            ContractError::TimestampSmallerThanParent => tools::TIMESTAMP_SMALLER_THAN_PARENT,
+            ContractError::TimestampTooLarge => tools::TIMESTAMP_TOO_LARGE,
+            ContractError::ZeroAnchorBlockHash => tools::ZERO_ANCHOR_BLOCK_HASH,
+            ContractError::CannotProposeInCurrentBlock => tools::CANNOT_PROPOSE_IN_CURRENT_BLOCK,
+            ContractError::OldestForcedInclusionDue => tools::OLDEST_FORCED_INCLUSION_DUE,
+            ContractError::UnprocessedForcedInclusionIsDue => {
+                tools::UNPROCESSED_FORCED_INCLUSION_IS_DUE
+            }
+            ContractError::Unknown(_) => return None,
+        };
+        Some(selector)
+    }
+
+    /// Maps the contract error onto the transaction error the nodes already act on. Reverts
+    /// that only signal a lost operator slot, and unknown ones, stay a `ContractRevert`.
+    fn into_transaction_error(self) -> TransactionError {
+        match self {
+            ContractError::InsufficientBond => TransactionError::InsufficientFunds,
+            ContractError::AnchorBlockIdTooSmall
+            | ContractError::TimestampTooSmall
+            | ContractError::AnchorBlockIdSmallerThanParent
+            | ContractError::TimestampSmallerThanParent => TransactionError::ReanchorRequired,
+            ContractError::TimestampTooLarge
+            | ContractError::ZeroAnchorBlockHash
+            | ContractError::CannotProposeInCurrentBlock => TransactionError::EstimationTooEarly,
+            ContractError::OldestForcedInclusionDue
+            | ContractError::UnprocessedForcedInclusionIsDue => {
+                TransactionError::OldestForcedInclusionDue
+            }
+            ContractError::NotTheOperator
+            | ContractError::NotPreconfer
+            | ContractError::NotPreconferOrFallback
+            | ContractError::InvalidProposer
+            | ContractError::Unknown(_) => TransactionError::ContractRevert(self),
+        }
+    }
+
+    /// The proposal was rejected because the sender is no longer the operator, which is expected
+    /// when a transaction lands after the handover and does not indicate a node failure.
+    pub fn is_not_the_operator(&self) -> bool {
+        matches!(
+            self,
+            ContractError::NotTheOperator
+                | ContractError::NotPreconfer
+                | ContractError::NotPreconferOrFallback
+                | ContractError::InvalidProposer
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_revert_data() {
+        // NotPreconferOrFallback() followed by no arguments
+        let error = TransactionError::from_revert_data(&[0xc0, 0xec, 0x4b, 0x50]);
+        assert!(matches!(
+            error,
+            TransactionError::ContractRevert(ContractError::NotPreconferOrFallback)
+        ));
+        assert_eq!(
+            error.selector(),
+            Some(Selector::from([0xc0, 0xec, 0x4b, 0x50]))
+        );
+        assert_eq!(error.to_string(), "ContractRevert(NotPreconferOrFallback)");
+
+        let error = TransactionError::from_revert_data(&[0xde, 0xad, 0xbe, 0xef, 0x01]);
+        assert!(matches!(
+            error,
+            TransactionError::ContractRevert(ContractError::Unknown(_))
+        ));
+        assert_eq!(
+            error.selector(),
+            Some(Selector::from([0xde, 0xad, 0xbe, 0xef]))
+        );
+
+        let error = TransactionError::from_revert_data(&[0xc0, 0xec]);
+        assert!(matches!(error, TransactionError::TransactionReverted));
+        assert_eq!(error.selector(), None);
+    }
+
+    #[test]
+    fn test_known_contract_error_selectors_round_trip() {
+        for error in ContractError::KNOWN {
+            let selector = error.selector();
+            assert_ne!(selector, Selector::ZERO);
+            assert_eq!(ContractError::from_selector(selector), error);
+        }
+    }
+
+    #[test]
+    fn test_from_revert_data_derives_existing_variants() {
+        // TimestampTooSmall()
+        let error = TransactionError::from_revert_data(&[0x19, 0x99, 0xae, 0xd2]);
+        assert!(matches!(error, TransactionError::ReanchorRequired));
+
+        // UnprocessedForcedInclusionIsDue()
+        let error = TransactionError::from_revert_data(&[0x02, 0x96, 0x7f, 0xb6]);
+        assert!(matches!(error, TransactionError::OldestForcedInclusionDue));
+
+        // InvalidProposer()
+        let error = TransactionError::from_revert_data(&[0x41, 0x00, 0xac, 0x03]);
+        assert!(matches!(
+            error,
+            TransactionError::ContractRevert(contract_error) if contract_error.is_not_the_operator()
+        ));
+    }
+}
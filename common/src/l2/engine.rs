@@ -1,18 +1,73 @@
 use crate::{
     config::Config,
+    metrics::Metrics,
     shared::l2_tx_lists::{self, PreBuiltTxList},
     utils::rpc_client::JSONRPCClient,
 };
 use alloy::primitives::Address;
 use anyhow::Error;
+use jsonrpsee::core::client::Error as JsonRpcError;
 use serde_json::Value;
 use std::cmp::{max, min};
-use std::time::Duration;
-use tracing::debug;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::{debug, info, warn};
+
+const RECONNECT_BASE_DELAY: Duration = Duration::from_secs(1);
+const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(30);
 
 pub struct L2Engine {
     auth_rpc: JSONRPCClient,
     config: L2EngineConfig,
+    connection: Mutex<AuthRpcConnection>,
+    metrics: Arc<Metrics>,
+}
+
+/// Tracks the auth RPC connection state and when the client may be recreated next.
+///
+/// The first reconnect is attempted right after a failure, subsequent ones back off
+/// exponentially up to `RECONNECT_MAX_DELAY`.
+#[derive(Debug, Default)]
+struct AuthRpcConnection {
+    consecutive_failures: u32,
+    next_reconnect_at: Option<Instant>,
+}
+
+impl AuthRpcConnection {
+    fn is_connected(&self) -> bool {
+        self.consecutive_failures == 0
+    }
+
+    fn on_failure(&mut self, now: Instant) {
+        self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+        if self.next_reconnect_at.is_none() {
+            self.next_reconnect_at = Some(now);
+        }
+    }
+
+    fn on_success(&mut self) {
+        self.consecutive_failures = 0;
+        self.next_reconnect_at = None;
+    }
+
+    /// Returns true when the client should be recreated now and schedules the next attempt.
+    fn should_reconnect(&mut self, now: Instant) -> bool {
+        match self.next_reconnect_at {
+            Some(at) if now >= at => {
+                self.next_reconnect_at = Some(now + self.backoff_delay());
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn backoff_delay(&self) -> Duration {
+        let exponent = self.consecutive_failures.saturating_sub(1).min(16);
+        min(
+            RECONNECT_BASE_DELAY * 2u32.pow(exponent),
+            RECONNECT_MAX_DELAY,
+        )
+    }
 }
 
 pub struct L2EngineConfig {
@@ -43,7 +98,7 @@ impl L2EngineConfig {
 }
 
 impl L2Engine {
-    pub fn new(config: L2EngineConfig) -> Result<Self, Error> {
+    pub fn new(config: L2EngineConfig, metrics: Arc<Metrics>) -> Result<Self, Error> {
         let auth_rpc = JSONRPCClient::new_with_timeout_and_jwt(
             &config.auth_url,
             config.rpc_timeout,
@@ -53,7 +108,56 @@ impl L2Engine {
             anyhow::anyhow!("Failed to create JSONRPCClient for taiko geth auth: {}", e)
         })?;
 
-        Ok(Self { auth_rpc, config })
+        metrics.set_l2_engine_connected(true);
+
+        Ok(Self {
+            auth_rpc,
+            config,
+            connection: Mutex::new(AuthRpcConnection::default()),
+            metrics,
+        })
+    }
+
+    /// Calls the auth RPC, recreating the client with a fresh JWT after a connection failure.
+    async fn call_auth_rpc(&self, method: &str, params: Vec<Value>) -> Result<Value, Error> {
+        if self.lock_connection().should_reconnect(Instant::now()) {
+            warn!("L2 engine auth RPC is disconnected, recreating client");
+            self.metrics.inc_l2_engine_reconnects();
+            if let Err(e) = self.auth_rpc.recreate_client().await {
+                warn!("Failed to recreate L2 engine auth RPC client: {}", e);
+            }
+        }
+
+        let result = self.auth_rpc.call_method(method, params).await;
+        match &result {
+            Err(err) if is_connection_error(err) => {
+                let mut connection = self.lock_connection();
+                if connection.is_connected() {
+                    warn!("L2 engine auth RPC connection lost: {}", err);
+                    self.metrics.set_l2_engine_connected(false);
+                }
+                connection.on_failure(Instant::now());
+            }
+            _ => {
+                let mut connection = self.lock_connection();
+                if !connection.is_connected() {
+                    info!("L2 engine auth RPC connection restored");
+                    self.metrics.set_l2_engine_connected(true);
+                }
+                connection.on_success();
+            }
+        }
+        result
+    }
+
+    fn lock_connection(&self) -> std::sync::MutexGuard<'_, AuthRpcConnection> {
+        self.connection
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.lock_connection().is_connected()
     }
 
     pub async fn get_last_certain_block_id_by_batch_id(
@@ -63,8 +167,7 @@ impl L2Engine {
         let hex_batch_id = format!("0x{:x}", batch_id);
         let params = vec![Value::String(hex_batch_id)];
         let result = self
-            .auth_rpc
-            .call_method("taikoAuth_lastCertainBlockIDByBatchID", params)
+            .call_auth_rpc("taikoAuth_lastCertainBlockIDByBatchID", params)
             .await
             .map_err(|e| {
                 anyhow::anyhow!(
@@ -107,8 +210,7 @@ impl L2Engine {
         ];
 
         let result = self
-            .auth_rpc
-            .call_method("taikoAuth_txPoolContentWithMinTip", params)
+            .call_auth_rpc("taikoAuth_txPoolContentWithMinTip", params)
             .await
             .map_err(|e| anyhow::anyhow!("Failed to get L2 tx lists: {}", e))?;
         if result != Value::Null {
@@ -127,6 +229,14 @@ impl L2Engine {
     }
 }
 
+/// RPC call errors are returned by a reachable server, anything else means the connection failed.
+fn is_connection_error(err: &Error) -> bool {
+    !matches!(
+        err.downcast_ref::<JsonRpcError>(),
+        Some(JsonRpcError::Call(_))
+    )
+}
+
 /// Calculate the max bytes per tx list based on the number of batches ready to send.
 /// The max bytes per tx list is reduced exponentially by given factor.
 fn calculate_max_bytes_per_tx_list(
@@ -192,4 +302,85 @@ mod test {
             min_value
         );
     }
+
+    #[test]
+    fn test_auth_rpc_connection_backoff() {
+        let now = Instant::now();
+        let mut connection = AuthRpcConnection::default();
+        assert!(connection.is_connected());
+        assert!(!connection.should_reconnect(now));
+
+        // First reconnect is attempted right away
+        connection.on_failure(now);
+        assert!(!connection.is_connected());
+        assert!(connection.should_reconnect(now));
+        assert!(!connection.should_reconnect(now));
+
+        // Next one waits for the backoff delay
+        connection.on_failure(now);
+        assert!(!connection.should_reconnect(now + Duration::from_millis(500)));
+        assert!(connection.should_reconnect(now + Duration::from_secs(1)));
+
+        for _ in 0..20 {
+            connection.on_failure(now);
+        }
+        assert_eq!(connection.backoff_delay(), RECONNECT_MAX_DELAY);
+
+        connection.on_success();
+        assert!(connection.is_connected());
+        assert!(!connection.should_reconnect(now + Duration::from_secs(60)));
+    }
+
+    #[tokio::test]
+    async fn test_auth_rpc_drop_and_recovery() {
+        let mut server = mockito::Server::new_async().await;
+        let engine = L2Engine::new(
+            L2EngineConfig {
+                auth_url: server.url(),
+                rpc_timeout: Duration::from_secs(1),
+                jwt_secret_bytes: [1u8; 32],
+                max_bytes_per_tx_list: 1000,
+                throttling_factor: 10,
+                min_bytes_per_tx_list: 100,
+                coinbase: Address::ZERO,
+            },
+            Arc::new(Metrics::new()),
+        )
+        .unwrap();
+
+        let dropped = server
+            .mock("POST", "/")
+            .with_status(503)
+            .create_async()
+            .await;
+        assert!(
+            engine
+                .get_last_certain_block_id_by_batch_id(1)
+                .await
+                .is_err()
+        );
+        assert!(!engine.is_connected());
+        dropped.remove_async().await;
+
+        let recovered = server
+            .mock("POST", "/")
+            .with_body_from_request(|request| {
+                let body: Value = serde_json::from_slice(request.body().unwrap()).unwrap();
+                serde_json::json!({"jsonrpc": "2.0", "id": body["id"], "result": "0x10"})
+                    .to_string()
+                    .into()
+            })
+            .expect(1)
+            .create_async()
+            .await;
+        assert_eq!(
+            engine
+                .get_last_certain_block_id_by_batch_id(1)
+                .await
+                .unwrap(),
+            Some(16)
+        );
+        assert!(engine.is_connected());
+        recovered.assert_async().await;
+    }
 }
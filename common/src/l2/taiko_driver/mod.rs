@@ -67,6 +67,7 @@ impl TaikoDriver {
         if let Some(preconfirmed_block) =
             BuildPreconfBlockResponse::new_from_value(response, request_body.is_forced_inclusion)
         {
+            preconfirmed_block.check_block_number(request_body.executable_data.block_number)?;
             self.metrics.inc_blocks_preconfirmed();
             Ok(preconfirmed_block)
         } else {
@@ -1,4 +1,5 @@
 use alloy::primitives::B256;
+use anyhow::Error;
 use hex::FromHex;
 use serde::{Deserialize, Deserializer, Serialize};
 
@@ -40,6 +41,19 @@ impl BuildPreconfBlockResponse {
         })
     }
 
+    /// Verifies that the driver built the block at the requested height.
+    pub fn check_block_number(&self, requested_block_number: u64) -> Result<(), Error> {
+        if self.number != requested_block_number {
+            return Err(anyhow::anyhow!(
+                "Driver returned block {} (hash {}) but block {} was requested",
+                self.number,
+                self.hash,
+                requested_block_number
+            ));
+        }
+        Ok(())
+    }
+
     fn to_b256(s: &str) -> Option<B256> {
         let bytes = <[u8; 32]>::from_hex(s.trim_start_matches("0x")).ok()?;
         Some(B256::from(bytes))
@@ -102,3 +116,31 @@ where
     array.copy_from_slice(&bytes);
     Ok(B256::from(array))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response_value(number: &str) -> serde_json::Value {
+        serde_json::json!({
+            "blockHeader": {
+                "number": number,
+                "hash": format!("0x{}", "11".repeat(32)),
+                "parentHash": format!("0x{}", "22".repeat(32)),
+            }
+        })
+    }
+
+    #[test]
+    fn test_check_block_number() {
+        let response = BuildPreconfBlockResponse::new_from_value(response_value("0x64"), false)
+            .expect("valid response");
+        assert_eq!(response.number, 100);
+        assert!(response.check_block_number(100).is_ok());
+
+        let err = response
+            .check_block_number(101)
+            .expect_err("mismatched block number must be rejected");
+        assert!(err.to_string().contains("block 101 was requested"));
+    }
+}
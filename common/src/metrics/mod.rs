@@ -4,12 +4,15 @@ use axum::http::header;
 use axum::response::IntoResponse;
 use axum::routing::get;
 use prometheus::{
-    Counter, CounterVec, Encoder, Gauge, Histogram, HistogramOpts, HistogramVec, Opts, Registry,
-    TextEncoder,
+    Counter, CounterVec, Encoder, Gauge, GaugeVec, Histogram, HistogramOpts, HistogramVec, Opts,
+    Registry, TextEncoder,
 };
 use std::sync::Arc;
 use tracing::error;
 
+use crate::l1::transaction_error::{ContractError, TransactionError};
+use crate::utils::health::OverallHealth;
+
 async fn metrics_handler(State(metrics): State<Arc<Metrics>>) -> impl IntoResponse {
     let output = metrics.gather();
     (
@@ -45,6 +48,54 @@ pub struct Metrics {
     reorg_depth: Gauge,
     operator_whitelisted: Gauge,
     is_geth_and_driver_synced: Gauge,
+    forced_inclusion_head_regressions: Counter,
+    batch_age_forced_submissions: Counter,
+    tx_list_compression_ratio: Gauge,
+    l2_engine_connected: Gauge,
+    l2_engine_reconnects: Counter,
+    preconfer_eth_burn_alert: Gauge,
+    preconfer_but_unsynced_slots_total: Counter,
+    produced_block_hash_mismatches: Counter,
+    preconf_commitment_signer_mismatches: Counter,
+    proposal_id_source: CounterVec,
+    kzg_setup_ms: Gauge,
+    bridge_failures_critical: Gauge,
+    conflicting_proposals: Counter,
+    anchor_decode_failures: Counter,
+    last_reanchor_size: Gauge,
+    safe_mode: Gauge,
+    warmup_duration_seconds: Gauge,
+    warmup_phase_duration_seconds: GaugeVec,
+    operator_transition_slots: CounterVec,
+    l1_basefee_estimation_error: Histogram,
+    fork_transition_skipped_slots: Counter,
+    block_timestamp_skew_seconds: Gauge,
+    forced_inclusion_empty_blob_hashes: Counter,
+    forced_inclusion_cap_reached: Counter,
+    duplicate_slot_blocks_prevented: Counter,
+    transaction_errors: CounterVec,
+    l1_clock_skew_seconds: Gauge,
+    chain_monitor_catchup_remaining_blocks: GaugeVec,
+    healthy_rpc_endpoints: GaugeVec,
+    configured_rpc_endpoints: GaugeVec,
+    operator_status_transitions: CounterVec,
+    forced_inclusion_reset_discrepancies: Counter,
+    l2_height_regression: Counter,
+    batch_gas_utilization: Gauge,
+    nonce_gap_self_heals: Counter,
+    batch_boundaries: CounterVec,
+    l1_rpc_height_divergence: Gauge,
+    pending_nonce_gap_shutdowns: Counter,
+    submitter_grace_submissions: Counter,
+    overall_health: Gauge,
+    blob_verification_failures: Counter,
+    operator_status_age_seconds: Gauge,
+    verification_results: CounterVec,
+    operator_stall_epochs: Gauge,
+    tx_max_fee_per_gas: Gauge,
+    forced_inclusion_manifest_block_count: Histogram,
+    forced_inclusion_manifest_tx_count: Histogram,
+    l1_rpc_failovers: CounterVec,
     registry: Registry,
 }
 
@@ -281,6 +332,645 @@ impl Metrics {
             );
         }
 
+        let forced_inclusion_head_regressions = Counter::new(
+            "catalyst_forced_inclusion_head_regressions_total",
+            "Number of times the forced inclusion head read from the inbox moved backward",
+        )
+        .expect("Failed to create forced_inclusion_head_regressions counter");
+
+        if let Err(err) = registry.register(Box::new(forced_inclusion_head_regressions.clone())) {
+            error!(
+                "Error: Failed to register forced_inclusion_head_regressions: {}",
+                err
+            );
+        }
+
+        let batch_age_forced_submissions = Counter::new(
+            "catalyst_batch_age_forced_submissions_total",
+            "Number of partially filled proposals submitted because they reached the max batch age",
+        )
+        .expect("Failed to create batch_age_forced_submissions counter");
+
+        if let Err(err) = registry.register(Box::new(batch_age_forced_submissions.clone())) {
+            error!(
+                "Error: Failed to register batch_age_forced_submissions: {}",
+                err
+            );
+        }
+
+        let tx_list_compression_ratio = Gauge::new(
+            "catalyst_tx_list_compression_ratio",
+            "Ratio of RLP encoded to compressed tx list bytes of the last proposed batch",
+        )
+        .expect("Failed to create tx_list_compression_ratio gauge");
+
+        if let Err(err) = registry.register(Box::new(tx_list_compression_ratio.clone())) {
+            error!(
+                "Error: Failed to register tx_list_compression_ratio: {}",
+                err
+            );
+        }
+
+        let l2_engine_connected = Gauge::new(
+            "catalyst_l2_engine_connected",
+            "Whether the L2 engine auth RPC is reachable (1) or not (0)",
+        )
+        .expect("Failed to create l2_engine_connected gauge");
+
+        if let Err(err) = registry.register(Box::new(l2_engine_connected.clone())) {
+            error!("Error: Failed to register l2_engine_connected: {}", err);
+        }
+
+        let l2_engine_reconnects = Counter::new(
+            "catalyst_l2_engine_reconnects",
+            "Number of L2 engine auth RPC client reconnections",
+        )
+        .expect("Failed to create l2_engine_reconnects counter");
+
+        if let Err(err) = registry.register(Box::new(l2_engine_reconnects.clone())) {
+            error!("Error: Failed to register l2_engine_reconnects: {}", err);
+        }
+
+        let preconfer_eth_burn_alert = Gauge::new(
+            "catalyst_preconfer_eth_burn_alert",
+            "Whether the preconfer ETH balance is projected to reach the threshold within the alert horizon",
+        )
+        .expect("Failed to create preconfer_eth_burn_alert gauge");
+
+        if let Err(err) = registry.register(Box::new(preconfer_eth_burn_alert.clone())) {
+            error!(
+                "Error: Failed to register preconfer_eth_burn_alert: {}",
+                err
+            );
+        }
+
+        let preconfer_but_unsynced_slots_total = Counter::new(
+            "catalyst_preconfer_but_unsynced_slots_total",
+            "Number of slots where the node was preconfer but the driver was not synced",
+        )
+        .expect("Failed to create preconfer_but_unsynced_slots_total counter");
+
+        if let Err(err) = registry.register(Box::new(preconfer_but_unsynced_slots_total.clone())) {
+            error!(
+                "Error: Failed to register preconfer_but_unsynced_slots_total: {}",
+                err
+            );
+        }
+
+        let produced_block_hash_mismatches = Counter::new(
+            "catalyst_produced_block_hash_mismatches_total",
+            "Number of preconfirmed blocks whose hash differs from the block stored in Geth",
+        )
+        .expect("Failed to create produced_block_hash_mismatches counter");
+
+        if let Err(err) = registry.register(Box::new(produced_block_hash_mismatches.clone())) {
+            error!(
+                "Error: Failed to register produced_block_hash_mismatches: {}",
+                err
+            );
+        }
+
+        let preconf_commitment_signer_mismatches = Counter::new(
+            "catalyst_preconf_commitment_signer_mismatches",
+            "Number of preconfirmation commitments whose recovered signer did not match the preconfer address",
+        )
+        .expect("Failed to create preconf_commitment_signer_mismatches counter");
+
+        if let Err(err) = registry.register(Box::new(preconf_commitment_signer_mismatches.clone()))
+        {
+            error!(
+                "Error: Failed to register preconf_commitment_signer_mismatches: {}",
+                err
+            );
+        }
+
+        let proposal_id_source = match CounterVec::new(
+            Opts::new(
+                "catalyst_proposal_id_source_total",
+                "Number of next proposal ids resolved by source",
+            ),
+            &["source"],
+        ) {
+            Ok(counter) => counter,
+            Err(err) => panic!("Failed to create proposal_id_source counter: {err}"),
+        };
+
+        if let Err(err) = registry.register(Box::new(proposal_id_source.clone())) {
+            error!("Error: Failed to register proposal_id_source: {}", err);
+        }
+
+        let kzg_setup_ms = Gauge::new(
+            "catalyst_kzg_setup_ms",
+            "Time spent building the default KZG settings at startup in milliseconds",
+        )
+        .expect("Failed to create kzg_setup_ms gauge");
+
+        if let Err(err) = registry.register(Box::new(kzg_setup_ms.clone())) {
+            error!("Error: Failed to register kzg_setup_ms: {}", err);
+        }
+
+        let bridge_failures_critical = Gauge::new(
+            "catalyst_bridge_failures_critical",
+            "Whether consecutive L2 to L1 bridging failures reached the configured limit",
+        )
+        .expect("Failed to create bridge_failures_critical gauge");
+
+        if let Err(err) = registry.register(Box::new(bridge_failures_critical.clone())) {
+            error!(
+                "Error: Failed to register bridge_failures_critical: {}",
+                err
+            );
+        }
+
+        let conflicting_proposals = Counter::new(
+            "catalyst_conflicting_proposals_total",
+            "Number of third party proposals observed during our sequencing window",
+        )
+        .expect("Failed to create conflicting_proposals counter");
+
+        if let Err(err) = registry.register(Box::new(conflicting_proposals.clone())) {
+            error!("Error: Failed to register conflicting_proposals: {}", err);
+        }
+
+        let anchor_decode_failures = Counter::new(
+            "catalyst_anchor_decode_failures_total",
+            "Number of anchor transactions whose L1 anchor block id could not be decoded",
+        )
+        .expect("Failed to create anchor_decode_failures counter");
+
+        if let Err(err) = registry.register(Box::new(anchor_decode_failures.clone())) {
+            error!("Error: Failed to register anchor_decode_failures: {}", err);
+        }
+
+        let last_reanchor_size = Gauge::new(
+            "catalyst_last_reanchor_size",
+            "Number of blocks reanchored in the most recent reanchor",
+        )
+        .expect("Failed to create last_reanchor_size gauge");
+
+        if let Err(err) = registry.register(Box::new(last_reanchor_size.clone())) {
+            error!("Error: Failed to register last_reanchor_size: {}", err);
+        }
+
+        let safe_mode = Gauge::new(
+            "catalyst_safe_mode",
+            "Whether the node is in safe mode after repeated unexpected L2 head errors",
+        )
+        .expect("Failed to create safe_mode gauge");
+
+        if let Err(err) = registry.register(Box::new(safe_mode.clone())) {
+            error!("Error: Failed to register safe_mode: {}", err);
+        }
+
+        let warmup_duration_seconds = Gauge::new(
+            "catalyst_warmup_duration_seconds",
+            "Time spent in node warmup in seconds",
+        )
+        .expect("Failed to create warmup_duration_seconds gauge");
+
+        if let Err(err) = registry.register(Box::new(warmup_duration_seconds.clone())) {
+            error!("Error: Failed to register warmup_duration_seconds: {}", err);
+        }
+
+        let warmup_phase_duration_seconds = match GaugeVec::new(
+            Opts::new(
+                "catalyst_warmup_phase_duration_seconds",
+                "Time spent in each node warmup phase in seconds",
+            ),
+            &["phase"],
+        ) {
+            Ok(gauge) => gauge,
+            Err(err) => panic!("Failed to create warmup_phase_duration_seconds gauge: {err}"),
+        };
+
+        if let Err(err) = registry.register(Box::new(warmup_phase_duration_seconds.clone())) {
+            error!(
+                "Error: Failed to register warmup_phase_duration_seconds: {}",
+                err
+            );
+        }
+
+        let operator_transition_slots = match CounterVec::new(
+            Opts::new(
+                "catalyst_operator_transition_slots_total",
+                "Operator checks resolved from the cached next operator flag during epoch transition slots",
+            ),
+            &["outcome"],
+        ) {
+            Ok(counter) => counter,
+            Err(err) => panic!("Failed to create operator_transition_slots counter: {err}"),
+        };
+
+        if let Err(err) = registry.register(Box::new(operator_transition_slots.clone())) {
+            error!(
+                "Error: Failed to register operator_transition_slots: {}",
+                err
+            );
+        }
+
+        let opts = HistogramOpts::new(
+            "catalyst_l1_basefee_estimation_error", "Difference in percent between the L1 base fee of the inclusion block and the base fee at build time")
+            .buckets(vec![-50.0, -25.0, -12.5, -5.0, 0.0, 5.0, 12.5, 25.0, 50.0, 100.0]);
+        let l1_basefee_estimation_error = match Histogram::with_opts(opts) {
+            Ok(histogram) => histogram,
+            Err(err) => panic!("Failed to create l1_basefee_estimation_error histogram: {err}"),
+        };
+
+        if let Err(err) = registry.register(Box::new(l1_basefee_estimation_error.clone())) {
+            error!(
+                "Error: Failed to register l1_basefee_estimation_error: {}",
+                err
+            );
+        }
+
+        let fork_transition_skipped_slots = Counter::new(
+            "catalyst_fork_transition_skipped_slots_total",
+            "Number of L2 slots not preconfirmed because of the fork switch transition period",
+        )
+        .expect("Failed to create fork_transition_skipped_slots counter");
+
+        if let Err(err) = registry.register(Box::new(fork_transition_skipped_slots.clone())) {
+            error!(
+                "Error: Failed to register fork_transition_skipped_slots: {}",
+                err
+            );
+        }
+
+        let block_timestamp_skew_seconds = Gauge::new(
+            "catalyst_block_timestamp_skew_seconds",
+            "Difference between the produced L2 block timestamp and the wall clock, positive when the block is ahead",
+        )
+        .expect("Failed to create block_timestamp_skew_seconds gauge");
+
+        if let Err(err) = registry.register(Box::new(block_timestamp_skew_seconds.clone())) {
+            error!(
+                "Error: Failed to register block_timestamp_skew_seconds: {}",
+                err
+            );
+        }
+
+        let forced_inclusion_empty_blob_hashes = Counter::new(
+            "catalyst_forced_inclusion_empty_blob_hashes_total",
+            "Number of forced inclusions skipped because they reference no blobs",
+        )
+        .expect("Failed to create forced_inclusion_empty_blob_hashes counter");
+
+        if let Err(err) = registry.register(Box::new(forced_inclusion_empty_blob_hashes.clone())) {
+            error!(
+                "Error: Failed to register forced_inclusion_empty_blob_hashes: {}",
+                err
+            );
+        }
+
+        let forced_inclusion_cap_reached = Counter::new(
+            "catalyst_forced_inclusion_cap_reached_total",
+            "Number of proposals where the forced inclusion cap stopped pending forced inclusions from being added",
+        )
+        .expect("Failed to create forced_inclusion_cap_reached counter");
+
+        if let Err(err) = registry.register(Box::new(forced_inclusion_cap_reached.clone())) {
+            error!(
+                "Error: Failed to register forced_inclusion_cap_reached: {}",
+                err
+            );
+        }
+
+        let duplicate_slot_blocks_prevented = Counter::new(
+            "catalyst_duplicate_slot_blocks_prevented_total",
+            "Number of blocks refused because a block was already produced for the same or a later L2 slot",
+        )
+        .expect("Failed to create duplicate_slot_blocks_prevented counter");
+
+        if let Err(err) = registry.register(Box::new(duplicate_slot_blocks_prevented.clone())) {
+            error!(
+                "Error: Failed to register duplicate_slot_blocks_prevented: {}",
+                err
+            );
+        }
+
+        let transaction_errors = match CounterVec::new(
+            Opts::new(
+                "catalyst_transaction_errors_total",
+                "Number of transaction errors handled by the node, by error",
+            ),
+            &["error"],
+        ) {
+            Ok(counter) => counter,
+            Err(err) => panic!("Failed to create transaction_errors counter: {err}"),
+        };
+
+        if let Err(err) = registry.register(Box::new(transaction_errors.clone())) {
+            error!("Error: Failed to register transaction_errors: {}", err);
+        }
+
+        let l1_clock_skew_seconds = Gauge::new(
+            "catalyst_l1_clock_skew_seconds",
+            "Difference between the local clock and the latest L1 block timestamp measured at startup",
+        )
+        .expect("Failed to create l1_clock_skew_seconds gauge");
+
+        if let Err(err) = registry.register(Box::new(l1_clock_skew_seconds.clone())) {
+            error!("Error: Failed to register l1_clock_skew_seconds: {}", err);
+        }
+
+        let chain_monitor_catchup_remaining_blocks = match GaugeVec::new(
+            Opts::new(
+                "catalyst_chain_monitor_catchup_remaining_blocks",
+                "Number of L1 blocks the chain monitor still has to scan for events",
+            ),
+            &["event"],
+        ) {
+            Ok(gauge) => gauge,
+            Err(err) => {
+                panic!("Failed to create chain_monitor_catchup_remaining_blocks gauge: {err}")
+            }
+        };
+
+        if let Err(err) =
+            registry.register(Box::new(chain_monitor_catchup_remaining_blocks.clone()))
+        {
+            error!(
+                "Error: Failed to register chain_monitor_catchup_remaining_blocks: {}",
+                err
+            );
+        }
+
+        let healthy_rpc_endpoints = match GaugeVec::new(
+            Opts::new(
+                "catalyst_healthy_rpc_endpoints",
+                "Number of configured RPC endpoints that passed the last health probe",
+            ),
+            &["layer"],
+        ) {
+            Ok(gauge) => gauge,
+            Err(err) => panic!("Failed to create healthy_rpc_endpoints gauge: {err}"),
+        };
+
+        if let Err(err) = registry.register(Box::new(healthy_rpc_endpoints.clone())) {
+            error!("Error: Failed to register healthy_rpc_endpoints: {}", err);
+        }
+
+        let configured_rpc_endpoints = match GaugeVec::new(
+            Opts::new(
+                "catalyst_configured_rpc_endpoints",
+                "Number of configured RPC endpoints",
+            ),
+            &["layer"],
+        ) {
+            Ok(gauge) => gauge,
+            Err(err) => panic!("Failed to create configured_rpc_endpoints gauge: {err}"),
+        };
+
+        if let Err(err) = registry.register(Box::new(configured_rpc_endpoints.clone())) {
+            error!(
+                "Error: Failed to register configured_rpc_endpoints: {}",
+                err
+            );
+        }
+
+        let operator_status_transitions = match CounterVec::new(
+            Opts::new(
+                "catalyst_operator_status_transitions_total",
+                "Number of operator status transitions",
+            ),
+            &["transition"],
+        ) {
+            Ok(counter) => counter,
+            Err(err) => panic!("Failed to create operator_status_transitions counter: {err}"),
+        };
+
+        if let Err(err) = registry.register(Box::new(operator_status_transitions.clone())) {
+            error!(
+                "Error: Failed to register operator_status_transitions: {}",
+                err
+            );
+        }
+
+        let forced_inclusion_reset_discrepancies = Counter::new(
+            "catalyst_forced_inclusion_reset_discrepancies_total",
+            "Number of builder resets where the local forced inclusion index did not match the inbox head",
+        )
+        .expect("Failed to create forced_inclusion_reset_discrepancies counter");
+
+        if let Err(err) = registry.register(Box::new(forced_inclusion_reset_discrepancies.clone()))
+        {
+            error!(
+                "Error: Failed to register forced_inclusion_reset_discrepancies: {}",
+                err
+            );
+        }
+
+        let l2_height_regression = Counter::new(
+            "catalyst_l2_height_regression_total",
+            "Number of times the L2 height reported by Geth was lower than a previously seen one",
+        )
+        .expect("Failed to create l2_height_regression counter");
+
+        if let Err(err) = registry.register(Box::new(l2_height_regression.clone())) {
+            error!("Error: Failed to register l2_height_regression: {}", err);
+        }
+
+        let batch_gas_utilization = Gauge::new(
+            "catalyst_batch_gas_utilization",
+            "Ratio of estimated gas used to the gas limit of the blocks in the last finalized batch",
+        )
+        .expect("Failed to create batch_gas_utilization gauge");
+
+        if let Err(err) = registry.register(Box::new(batch_gas_utilization.clone())) {
+            error!("Error: Failed to register batch_gas_utilization: {}", err);
+        }
+
+        let nonce_gap_self_heals = Counter::new(
+            "catalyst_nonce_gap_self_heals_total",
+            "Number of stuck transactions replaced at startup to close a preconfer nonce gap",
+        )
+        .expect("Failed to create nonce_gap_self_heals counter");
+
+        if let Err(err) = registry.register(Box::new(nonce_gap_self_heals.clone())) {
+            error!("Error: Failed to register nonce_gap_self_heals: {}", err);
+        }
+
+        let batch_boundaries = match CounterVec::new(
+            Opts::new(
+                "catalyst_batch_boundaries_total",
+                "Number of finalized proposals by the reason the proposal was closed",
+            ),
+            &["reason"],
+        ) {
+            Ok(counter) => counter,
+            Err(err) => panic!("Failed to create batch_boundaries counter: {err}"),
+        };
+
+        if let Err(err) = registry.register(Box::new(batch_boundaries.clone())) {
+            error!("Error: Failed to register batch_boundaries: {}", err);
+        }
+
+        let l1_rpc_height_divergence = Gauge::new(
+            "catalyst_l1_rpc_height_divergence",
+            "Spread in blocks between the highest and lowest height reported by the L1 RPC endpoints",
+        )
+        .expect("Failed to create l1_rpc_height_divergence gauge");
+
+        if let Err(err) = registry.register(Box::new(l1_rpc_height_divergence.clone())) {
+            error!(
+                "Error: Failed to register l1_rpc_height_divergence: {}",
+                err
+            );
+        }
+
+        let pending_nonce_gap_shutdowns = Counter::new(
+            "catalyst_pending_nonce_gap_shutdowns_total",
+            "Number of shutdowns because the pending preconfer nonce did not settle to the latest nonce",
+        )
+        .expect("Failed to create pending_nonce_gap_shutdowns counter");
+
+        if let Err(err) = registry.register(Box::new(pending_nonce_gap_shutdowns.clone())) {
+            error!(
+                "Error: Failed to register pending_nonce_gap_shutdowns: {}",
+                err
+            );
+        }
+
+        let submitter_grace_submissions = Counter::new(
+            "catalyst_submitter_grace_submissions_total",
+            "Number of proposal submissions made in the grace window after the submitter role was lost",
+        )
+        .expect("Failed to create submitter_grace_submissions counter");
+
+        if let Err(err) = registry.register(Box::new(submitter_grace_submissions.clone())) {
+            error!(
+                "Error: Failed to register submitter_grace_submissions: {}",
+                err
+            );
+        }
+
+        let overall_health = Gauge::new(
+            "catalyst_overall_health",
+            "Overall node health derived from the component health signals: 0 unhealthy, 1 degraded, 2 healthy",
+        )
+        .expect("Failed to create overall_health gauge");
+
+        if let Err(err) = registry.register(Box::new(overall_health.clone())) {
+            error!("Error: Failed to register overall_health: {}", err);
+        }
+
+        let blob_verification_failures = Counter::new(
+            "catalyst_blob_verification_failures_total",
+            "Number of proposal submissions aborted because the blob KZG verification failed",
+        )
+        .expect("Failed to create blob_verification_failures counter");
+
+        if let Err(err) = registry.register(Box::new(blob_verification_failures.clone())) {
+            error!(
+                "Error: Failed to register blob_verification_failures: {}",
+                err
+            );
+        }
+
+        let operator_status_age_seconds = Gauge::new(
+            "catalyst_operator_status_age_seconds",
+            "Seconds since the operators used for the operator status were read from L1",
+        )
+        .expect("Failed to create operator_status_age_seconds gauge");
+
+        if let Err(err) = registry.register(Box::new(operator_status_age_seconds.clone())) {
+            error!(
+                "Error: Failed to register operator_status_age_seconds: {}",
+                err
+            );
+        }
+
+        let verification_results = match CounterVec::new(
+            Opts::new(
+                "catalyst_verification_results_total",
+                "Number of verifier outcomes by result",
+            ),
+            &["result"],
+        ) {
+            Ok(counter) => counter,
+            Err(err) => panic!("Failed to create verification_results counter: {err}"),
+        };
+
+        if let Err(err) = registry.register(Box::new(verification_results.clone())) {
+            error!("Error: Failed to register verification_results: {}", err);
+        }
+
+        let operator_stall_epochs = Gauge::new(
+            "catalyst_operator_stall_epochs",
+            "Consecutive epochs as preconfer with pending transactions but without a produced block",
+        )
+        .expect("Failed to create operator_stall_epochs gauge");
+
+        if let Err(err) = registry.register(Box::new(operator_stall_epochs.clone())) {
+            error!("Error: Failed to register operator_stall_epochs: {}", err);
+        }
+
+        let tx_max_fee_per_gas = Gauge::new(
+            "catalyst_tx_max_fee_per_gas_wei",
+            "Max fee per gas of the last sent L1 transaction in wei",
+        )
+        .expect("Failed to create tx_max_fee_per_gas gauge");
+
+        if let Err(err) = registry.register(Box::new(tx_max_fee_per_gas.clone())) {
+            error!("Error: Failed to register tx_max_fee_per_gas: {}", err);
+        }
+
+        let opts = HistogramOpts::new(
+            "catalyst_forced_inclusion_manifest_block_count",
+            "Number of blocks in a decoded forced inclusion manifest",
+        )
+        .buckets(vec![1.0, 2.0, 3.0, 5.0, 10.0, 20.0, 50.0]);
+        let forced_inclusion_manifest_block_count = match Histogram::with_opts(opts) {
+            Ok(histogram) => histogram,
+            Err(err) => {
+                panic!("Failed to create forced_inclusion_manifest_block_count histogram: {err}")
+            }
+        };
+
+        if let Err(err) = registry.register(Box::new(forced_inclusion_manifest_block_count.clone()))
+        {
+            error!(
+                "Error: Failed to register forced_inclusion_manifest_block_count: {}",
+                err
+            );
+        }
+
+        let opts = HistogramOpts::new(
+            "catalyst_forced_inclusion_manifest_tx_count",
+            "Number of transactions in a decoded forced inclusion manifest",
+        )
+        .buckets(vec![
+            1.0, 5.0, 10.0, 25.0, 50.0, 100.0, 250.0, 500.0, 1000.0, 2500.0,
+        ]);
+        let forced_inclusion_manifest_tx_count = match Histogram::with_opts(opts) {
+            Ok(histogram) => histogram,
+            Err(err) => {
+                panic!("Failed to create forced_inclusion_manifest_tx_count histogram: {err}")
+            }
+        };
+
+        if let Err(err) = registry.register(Box::new(forced_inclusion_manifest_tx_count.clone())) {
+            error!(
+                "Error: Failed to register forced_inclusion_manifest_tx_count: {}",
+                err
+            );
+        }
+
+        let l1_rpc_failovers = match CounterVec::new(
+            Opts::new(
+                "catalyst_l1_rpc_failovers_total",
+                "Number of times a request failed over from an L1 RPC endpoint",
+            ),
+            &["endpoint"],
+        ) {
+            Ok(counter) => counter,
+            Err(err) => panic!("Failed to create l1_rpc_failovers counter: {err}"),
+        };
+
+        if let Err(err) = registry.register(Box::new(l1_rpc_failovers.clone())) {
+            error!("Error: Failed to register l1_rpc_failovers: {}", err);
+        }
+
         Self {
             preconfer_eth_balance,
             preconfer_l2_eth_balance,
@@ -302,6 +992,54 @@ impl Metrics {
             reorg_depth,
             operator_whitelisted,
             is_geth_and_driver_synced,
+            forced_inclusion_head_regressions,
+            batch_age_forced_submissions,
+            tx_list_compression_ratio,
+            l2_engine_connected,
+            l2_engine_reconnects,
+            preconfer_eth_burn_alert,
+            preconfer_but_unsynced_slots_total,
+            produced_block_hash_mismatches,
+            preconf_commitment_signer_mismatches,
+            proposal_id_source,
+            kzg_setup_ms,
+            bridge_failures_critical,
+            conflicting_proposals,
+            anchor_decode_failures,
+            last_reanchor_size,
+            safe_mode,
+            warmup_duration_seconds,
+            warmup_phase_duration_seconds,
+            operator_transition_slots,
+            l1_basefee_estimation_error,
+            fork_transition_skipped_slots,
+            block_timestamp_skew_seconds,
+            forced_inclusion_empty_blob_hashes,
+            forced_inclusion_cap_reached,
+            duplicate_slot_blocks_prevented,
+            transaction_errors,
+            l1_clock_skew_seconds,
+            chain_monitor_catchup_remaining_blocks,
+            healthy_rpc_endpoints,
+            configured_rpc_endpoints,
+            operator_status_transitions,
+            forced_inclusion_reset_discrepancies,
+            l2_height_regression,
+            batch_gas_utilization,
+            nonce_gap_self_heals,
+            batch_boundaries,
+            l1_rpc_height_divergence,
+            pending_nonce_gap_shutdowns,
+            submitter_grace_submissions,
+            overall_health,
+            blob_verification_failures,
+            operator_status_age_seconds,
+            verification_results,
+            operator_stall_epochs,
+            tx_max_fee_per_gas,
+            forced_inclusion_manifest_block_count,
+            forced_inclusion_manifest_tx_count,
+            l1_rpc_failovers,
             registry,
         }
     }
@@ -418,6 +1156,306 @@ impl Metrics {
             .set(if synced { 1.0 } else { 0.0 });
     }
 
+    pub fn inc_forced_inclusion_head_regressions(&self) {
+        self.forced_inclusion_head_regressions.inc();
+    }
+
+    pub fn inc_batch_age_forced_submissions(&self) {
+        self.batch_age_forced_submissions.inc();
+    }
+
+    pub fn set_tx_list_compression_ratio(&self, ratio: f64) {
+        self.tx_list_compression_ratio.set(ratio);
+    }
+
+    pub fn set_l2_engine_connected(&self, connected: bool) {
+        self.l2_engine_connected
+            .set(if connected { 1.0 } else { 0.0 });
+    }
+
+    pub fn inc_l2_engine_reconnects(&self) {
+        self.l2_engine_reconnects.inc();
+    }
+
+    pub fn set_preconfer_eth_burn_alert(&self, alert: bool) {
+        self.preconfer_eth_burn_alert
+            .set(if alert { 1.0 } else { 0.0 });
+    }
+
+    pub fn inc_preconfer_but_unsynced_slots(&self) {
+        self.preconfer_but_unsynced_slots_total.inc();
+    }
+
+    pub fn inc_produced_block_hash_mismatches(&self) {
+        self.produced_block_hash_mismatches.inc();
+    }
+
+    pub fn inc_preconf_commitment_signer_mismatches(&self) {
+        self.preconf_commitment_signer_mismatches.inc();
+    }
+
+    pub fn inc_proposal_id_source(&self, source: &str) {
+        if let Ok(metric) = self
+            .proposal_id_source
+            .get_metric_with_label_values(&[source])
+        {
+            metric.inc();
+        } else {
+            error!(
+                "Failed to increment proposal id source counter for source: {}",
+                source
+            );
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    pub fn set_kzg_setup_ms(&self, duration: std::time::Duration) {
+        self.kzg_setup_ms.set(duration.as_millis() as f64);
+    }
+
+    pub fn set_bridge_failures_critical(&self, critical: bool) {
+        self.bridge_failures_critical
+            .set(if critical { 1.0 } else { 0.0 });
+    }
+
+    pub fn inc_conflicting_proposals(&self) {
+        self.conflicting_proposals.inc();
+    }
+
+    pub fn inc_anchor_decode_failures(&self) {
+        self.anchor_decode_failures.inc();
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    pub fn set_last_reanchor_size(&self, blocks_reanchored: u64) {
+        self.last_reanchor_size.set(blocks_reanchored as f64);
+    }
+
+    pub fn set_safe_mode(&self, active: bool) {
+        self.safe_mode.set(if active { 1.0 } else { 0.0 });
+    }
+
+    pub fn set_warmup_duration(&self, duration: std::time::Duration) {
+        self.warmup_duration_seconds.set(duration.as_secs_f64());
+    }
+
+    pub fn set_warmup_phase_duration(&self, phase: &str, duration: std::time::Duration) {
+        if let Ok(metric) = self
+            .warmup_phase_duration_seconds
+            .get_metric_with_label_values(&[phase])
+        {
+            metric.set(duration.as_secs_f64());
+        } else {
+            error!("Failed to set warmup phase duration for phase: {}", phase);
+        }
+    }
+
+    pub fn inc_operator_transition_slot(&self, role_flipped: bool) {
+        let outcome = if role_flipped { "flipped" } else { "unchanged" };
+        if let Ok(metric) = self
+            .operator_transition_slots
+            .get_metric_with_label_values(&[outcome])
+        {
+            metric.inc();
+        } else {
+            error!(
+                "Failed to increment operator transition slots counter for outcome: {}",
+                outcome
+            );
+        }
+    }
+
+    pub fn observe_l1_basefee_estimation_error(&self, error_percent: f64) {
+        self.l1_basefee_estimation_error.observe(error_percent);
+    }
+
+    pub fn inc_fork_transition_skipped_slots(&self) {
+        self.fork_transition_skipped_slots.inc();
+    }
+
+    pub fn set_block_timestamp_skew(&self, skew_seconds: f64) {
+        self.block_timestamp_skew_seconds.set(skew_seconds);
+    }
+
+    pub fn inc_forced_inclusion_empty_blob_hashes(&self) {
+        self.forced_inclusion_empty_blob_hashes.inc();
+    }
+
+    pub fn inc_forced_inclusion_cap_reached(&self) {
+        self.forced_inclusion_cap_reached.inc();
+    }
+
+    pub fn inc_duplicate_slot_blocks_prevented(&self) {
+        self.duplicate_slot_blocks_prevented.inc();
+    }
+
+    pub fn inc_transaction_error(&self, error: &TransactionError) {
+        // Unknown selectors share one label to keep the label cardinality bounded
+        let error = match error {
+            TransactionError::ContractRevert(ContractError::Unknown(_)) => "unknown".to_string(),
+            error => error.to_string(),
+        };
+        if let Ok(metric) = self
+            .transaction_errors
+            .get_metric_with_label_values(&[error.as_str()])
+        {
+            metric.inc();
+        } else {
+            error!(
+                "Failed to increment transaction errors counter for error: {}",
+                error
+            );
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    pub fn set_l1_clock_skew(&self, skew_sec: i64) {
+        self.l1_clock_skew_seconds.set(skew_sec as f64);
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    pub fn set_chain_monitor_catchup_remaining_blocks(&self, event_name: &str, blocks: u64) {
+        if let Ok(metric) = self
+            .chain_monitor_catchup_remaining_blocks
+            .get_metric_with_label_values(&[event_name])
+        {
+            metric.set(blocks as f64);
+        } else {
+            error!(
+                "Failed to set chain monitor catch-up gauge for event: {}",
+                event_name
+            );
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    pub fn set_rpc_endpoints_health(&self, layer: &str, healthy: usize, configured: usize) {
+        match (
+            self.healthy_rpc_endpoints
+                .get_metric_with_label_values(&[layer]),
+            self.configured_rpc_endpoints
+                .get_metric_with_label_values(&[layer]),
+        ) {
+            (Ok(healthy_metric), Ok(configured_metric)) => {
+                healthy_metric.set(healthy as f64);
+                configured_metric.set(configured as f64);
+            }
+            _ => error!(
+                "Failed to set RPC endpoints health gauges for layer: {}",
+                layer
+            ),
+        }
+    }
+
+    pub fn inc_operator_status_transition(&self, transition: &str) {
+        if let Ok(metric) = self
+            .operator_status_transitions
+            .get_metric_with_label_values(&[transition])
+        {
+            metric.inc();
+        } else {
+            error!(
+                "Failed to increment operator status transition counter: {}",
+                transition
+            );
+        }
+    }
+
+    pub fn inc_forced_inclusion_reset_discrepancies(&self) {
+        self.forced_inclusion_reset_discrepancies.inc();
+    }
+
+    pub fn inc_l2_height_regression(&self) {
+        self.l2_height_regression.inc();
+    }
+
+    pub fn set_batch_gas_utilization(&self, utilization: f64) {
+        self.batch_gas_utilization.set(utilization);
+    }
+
+    pub fn inc_nonce_gap_self_heals(&self) {
+        self.nonce_gap_self_heals.inc();
+    }
+
+    pub fn inc_batch_boundary(&self, reason: &str) {
+        if let Ok(metric) = self
+            .batch_boundaries
+            .get_metric_with_label_values(&[reason])
+        {
+            metric.inc();
+        } else {
+            error!("Failed to increment batch boundary counter: {}", reason);
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    pub fn set_l1_rpc_height_divergence(&self, divergence: u64) {
+        self.l1_rpc_height_divergence.set(divergence as f64);
+    }
+
+    pub fn inc_pending_nonce_gap_shutdowns(&self) {
+        self.pending_nonce_gap_shutdowns.inc();
+    }
+
+    pub fn inc_submitter_grace_submissions(&self) {
+        self.submitter_grace_submissions.inc();
+    }
+
+    pub fn set_overall_health(&self, health: OverallHealth) {
+        self.overall_health.set(f64::from(health as u8));
+    }
+
+    pub fn inc_blob_verification_failures(&self) {
+        self.blob_verification_failures.inc();
+    }
+
+    pub fn set_operator_status_age(&self, age: std::time::Duration) {
+        self.operator_status_age_seconds.set(age.as_secs_f64());
+    }
+
+    pub fn inc_verification_result(&self, result: &str) {
+        if let Ok(metric) = self
+            .verification_results
+            .get_metric_with_label_values(&[result])
+        {
+            metric.inc();
+        } else {
+            error!(
+                "Failed to increment verification results counter: {}",
+                result
+            );
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    pub fn set_operator_stall_epochs(&self, epochs: u64) {
+        self.operator_stall_epochs.set(epochs as f64);
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    pub fn set_tx_max_fee_per_gas(&self, max_fee_per_gas: u128) {
+        self.tx_max_fee_per_gas.set(max_fee_per_gas as f64);
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    pub fn observe_forced_inclusion_manifest(&self, block_count: usize, tx_count: usize) {
+        self.forced_inclusion_manifest_block_count
+            .observe(block_count as f64);
+        self.forced_inclusion_manifest_tx_count
+            .observe(tx_count as f64);
+    }
+
+    pub fn inc_l1_rpc_failovers(&self, endpoint: &str) {
+        if let Ok(metric) = self
+            .l1_rpc_failovers
+            .get_metric_with_label_values(&[endpoint])
+        {
+            metric.inc();
+        } else {
+            error!("Failed to increment L1 RPC failovers counter: {}", endpoint);
+        }
+    }
+
     fn u256_to_f64(balance: alloy::primitives::U256) -> f64 {
         let balance_str = balance.to_string();
         let len = balance_str.len();
@@ -545,4 +1583,31 @@ mod tests {
         let large = alloy::primitives::U256::from(123456789012345678901234567890u128);
         assert_eq!(Metrics::u256_to_f64(large), 123_456_789_012.345_67);
     }
+
+    #[test]
+    fn test_transaction_errors_by_variant() {
+        let metrics = Metrics::new();
+        metrics.inc_transaction_error(&TransactionError::EstimationTooEarly);
+        metrics.inc_transaction_error(&TransactionError::EstimationTooEarly);
+        metrics.inc_transaction_error(&TransactionError::NotTheOperatorInCurrentEpoch);
+        metrics.inc_transaction_error(&TransactionError::TransactionReverted);
+        for selector in [[0x01, 0x02, 0x03, 0x04], [0x05, 0x06, 0x07, 0x08]] {
+            metrics.inc_transaction_error(&TransactionError::ContractRevert(
+                ContractError::Unknown(selector.into()),
+            ));
+        }
+
+        let output = metrics.gather();
+        assert!(
+            output.contains("catalyst_transaction_errors_total{error=\"EstimationTooEarly\"} 2")
+        );
+        assert!(output.contains(
+            "catalyst_transaction_errors_total{error=\"NotTheOperatorInCurrentEpoch\"} 1"
+        ));
+        assert!(
+            output.contains("catalyst_transaction_errors_total{error=\"TransactionReverted\"} 1")
+        );
+        assert!(output.contains("catalyst_transaction_errors_total{error=\"unknown\"} 2"));
+        assert!(!output.contains("Unknown("));
+    }
 }
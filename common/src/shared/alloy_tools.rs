@@ -1,14 +1,17 @@
-use crate::signer::Signer;
+use crate::{metrics::Metrics, shared::failover_transport::FailoverTransport, signer::Signer};
 use alloy::{
     network::{Ethereum, EthereumWallet},
     primitives::B256,
     providers::{DynProvider, Provider, ProviderBuilder, WsConnect, ext::DebugApi},
-    rpc::types::{Transaction, TransactionRequest, trace::geth::GethDebugTracingOptions},
+    rpc::{
+        client::RpcClient,
+        types::{Transaction, TransactionRequest, trace::geth::GethDebugTracingOptions},
+    },
     signers::local::PrivateKeySigner,
 };
 use anyhow::Error;
-use std::str::FromStr;
-use tracing::debug;
+use std::{str::FromStr, sync::Arc, time::Duration};
+use tracing::{debug, warn};
 
 pub async fn check_for_revert_reason<P: Provider<Ethereum>>(
     provider: &P,
@@ -78,29 +81,70 @@ pub async fn construct_alloy_provider(
     execution_ws_rpc_url: &str,
 ) -> Result<DynProvider, Error> {
     match signer {
-        Signer::PrivateKey(private_key, _) => {
+        Signer::PrivateKey(_, _) => debug!(
+            "Creating alloy provider with URL: {} and private key signer.",
+            execution_ws_rpc_url
+        ),
+        Signer::Web3signer(_, _) => debug!(
+            "Creating alloy provider with URL: {} and web3signer signer.",
+            execution_ws_rpc_url
+        ),
+    }
+    create_alloy_provider_with_wallet(wallet_from_signer(signer)?, execution_ws_rpc_url).await
+}
+
+/// Creates a provider failing over between the given URLs on transport errors, a failing URL
+/// being skipped for `cooldown`. A single URL gets a plain provider.
+pub async fn construct_failover_alloy_provider(
+    signer: &Signer,
+    execution_rpc_urls: &[String],
+    cooldown: Duration,
+    metrics: Arc<Metrics>,
+) -> Result<DynProvider, Error> {
+    match execution_rpc_urls {
+        [] => Err(anyhow::anyhow!("L1 RPC URL is required")),
+        [url] => construct_alloy_provider(signer, url).await,
+        urls => {
             debug!(
-                "Creating alloy provider with URL: {} and private key signer.",
-                execution_ws_rpc_url
+                "Creating alloy provider failing over between URLs: {:?}",
+                urls
             );
-            let signer = PrivateKeySigner::from_str(private_key.as_str())?;
+            // An unreachable endpoint must not block the startup, it is left out
+            let mut endpoints = Vec::with_capacity(urls.len());
+            for url in urls {
+                match create_alloy_provider_without_wallet(url).await {
+                    Ok(provider) => {
+                        endpoints.push((url.clone(), provider.client().transport().clone()))
+                    }
+                    Err(err) => warn!("Skipping L1 RPC endpoint {}: {}", url, err),
+                }
+            }
+            if endpoints.is_empty() {
+                return Err(anyhow::anyhow!(
+                    "Failed to connect to any of the L1 RPC URLs: {:?}",
+                    urls
+                ));
+            }
+            let client =
+                RpcClient::new(FailoverTransport::new(endpoints, cooldown, metrics), false);
+            Ok(ProviderBuilder::new()
+                .wallet(wallet_from_signer(signer)?)
+                .connect_client(client)
+                .erased())
+        }
+    }
+}
 
-            Ok(create_alloy_provider_with_wallet(signer.into(), execution_ws_rpc_url).await?)
+fn wallet_from_signer(signer: &Signer) -> Result<EthereumWallet, Error> {
+    match signer {
+        Signer::PrivateKey(private_key, _) => {
+            let signer = PrivateKeySigner::from_str(private_key.as_str())?;
+            Ok(signer.into())
         }
         Signer::Web3signer(web3signer, address) => {
-            debug!(
-                "Creating alloy provider with URL: {} and web3signer signer.",
-                execution_ws_rpc_url
-            );
-            let preconfer_address = *address;
-
-            let tx_signer = crate::signer::web3signer::Web3TxSigner::new(
-                web3signer.clone(),
-                preconfer_address,
-            )?;
-            let wallet = EthereumWallet::new(tx_signer);
-
-            Ok(create_alloy_provider_with_wallet(wallet, execution_ws_rpc_url).await?)
+            let tx_signer =
+                crate::signer::web3signer::Web3TxSigner::new(web3signer.clone(), *address)?;
+            Ok(EthereumWallet::new(tx_signer))
         }
     }
 }
@@ -15,12 +15,14 @@ impl AnchorBlockInfo {
         l1_height_lag: u64,
         last_anchor_id: u64,
         min_anchor_offset: u64,
+        anchor_confirmation_depth: u64,
     ) -> Result<Self, Error> {
         let id = Self::calculate_anchor_block_id(
             execution_layer,
             l1_height_lag,
             last_anchor_id,
             min_anchor_offset,
+            anchor_confirmation_depth,
         )
         .await?;
         Self::from_block_number(execution_layer, id).await
@@ -59,24 +61,78 @@ impl AnchorBlockInfo {
         l1_height_lag: u64,
         last_anchor_id: u64,
         min_anchor_offset: u64,
+        anchor_confirmation_depth: u64,
     ) -> Result<u64, Error> {
         let l1_height = execution_layer.get_latest_block_id().await?;
-        let l1_height_with_lag = l1_height - l1_height_lag;
+        Self::anchor_block_id_from_l1_height(
+            l1_height,
+            l1_height_lag,
+            last_anchor_id,
+            min_anchor_offset,
+            anchor_confirmation_depth,
+        )
+    }
+
+    /// Picks the anchor block ID for the given L1 height, keeping at least
+    /// `min_anchor_offset` blocks between the anchor and the L1 head. The anchor
+    /// is also kept at least `anchor_confirmation_depth` blocks deep, independently
+    /// of `l1_height_lag`.
+    pub fn anchor_block_id_from_l1_height(
+        l1_height: u64,
+        l1_height_lag: u64,
+        last_anchor_id: u64,
+        min_anchor_offset: u64,
+        anchor_confirmation_depth: u64,
+    ) -> Result<u64, Error> {
+        let l1_height_with_lag =
+            l1_height.saturating_sub(l1_height_lag.max(anchor_confirmation_depth));
 
         let anchor_id = l1_height_with_lag.max(last_anchor_id + 1);
 
-        if l1_height < anchor_id + min_anchor_offset {
+        let required_offset = min_anchor_offset.max(anchor_confirmation_depth);
+        if l1_height < anchor_id + required_offset {
             return Err(anyhow::anyhow!(
-                "Calculated anchor block ID {} exceeds latest L1 height {} - min_anchor_offset {}",
+                "Calculated anchor block ID {} exceeds latest L1 height {} - min_anchor_offset {} (anchor_confirmation_depth {})",
                 anchor_id,
                 l1_height,
-                min_anchor_offset
+                min_anchor_offset,
+                anchor_confirmation_depth
             ));
         }
 
         Ok(anchor_id)
     }
 
+    /// Ensures the minimum anchor offset leaves room below the maximum anchor height offset.
+    pub fn validate_min_anchor_offset(
+        min_anchor_offset: u64,
+        max_anchor_height_offset: u64,
+    ) -> Result<(), Error> {
+        if min_anchor_offset >= max_anchor_height_offset {
+            return Err(anyhow::anyhow!(
+                "MIN_ANCHOR_OFFSET ({}) must be less than the max anchor height offset ({})",
+                min_anchor_offset,
+                max_anchor_height_offset
+            ));
+        }
+        Ok(())
+    }
+
+    /// Ensures the anchor confirmation depth leaves room below the maximum anchor height offset.
+    pub fn validate_anchor_confirmation_depth(
+        anchor_confirmation_depth: u64,
+        max_anchor_height_offset: u64,
+    ) -> Result<(), Error> {
+        if anchor_confirmation_depth >= max_anchor_height_offset {
+            return Err(anyhow::anyhow!(
+                "ANCHOR_CONFIRMATION_DEPTH ({}) must be less than the max anchor height offset ({})",
+                anchor_confirmation_depth,
+                max_anchor_height_offset
+            ));
+        }
+        Ok(())
+    }
+
     pub fn new(id: u64, timestamp_sec: u64, hash: B256, state_root: B256) -> Self {
         Self {
             id,
@@ -99,3 +155,62 @@ impl AnchorBlockInfo {
         self.state_root
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_anchor_block_id_with_default_min_offset() {
+        assert_eq!(
+            AnchorBlockInfo::anchor_block_id_from_l1_height(100, 4, 50, 2, 0).ok(),
+            Some(96)
+        );
+        // Last anchor is too close to the L1 head
+        assert!(AnchorBlockInfo::anchor_block_id_from_l1_height(100, 4, 98, 2, 0).is_err());
+    }
+
+    #[test]
+    fn test_anchor_block_id_with_custom_min_offset() {
+        assert_eq!(
+            AnchorBlockInfo::anchor_block_id_from_l1_height(100, 4, 50, 4, 0).ok(),
+            Some(96)
+        );
+        assert!(AnchorBlockInfo::anchor_block_id_from_l1_height(100, 4, 50, 5, 0).is_err());
+        assert!(AnchorBlockInfo::anchor_block_id_from_l1_height(100, 4, 96, 4, 0).is_err());
+    }
+
+    #[test]
+    fn test_validate_min_anchor_offset() {
+        assert!(AnchorBlockInfo::validate_min_anchor_offset(2, 64).is_ok());
+        assert!(AnchorBlockInfo::validate_min_anchor_offset(64, 64).is_err());
+    }
+
+    #[test]
+    fn test_anchor_block_id_respects_confirmation_depth() {
+        // Depth deeper than the lag pushes the anchor further from the head
+        assert_eq!(
+            AnchorBlockInfo::anchor_block_id_from_l1_height(100, 4, 50, 2, 10).ok(),
+            Some(90)
+        );
+        // Last anchor already inside the confirmation depth
+        assert!(AnchorBlockInfo::anchor_block_id_from_l1_height(100, 4, 92, 2, 10).is_err());
+
+        for l1_height in 20..200 {
+            for depth in 0..16 {
+                if let Ok(anchor_id) =
+                    AnchorBlockInfo::anchor_block_id_from_l1_height(l1_height, 4, 10, 2, depth)
+                {
+                    assert!(anchor_id <= l1_height - depth);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_anchor_confirmation_depth() {
+        assert!(AnchorBlockInfo::validate_anchor_confirmation_depth(0, 64).is_ok());
+        assert!(AnchorBlockInfo::validate_anchor_confirmation_depth(63, 64).is_ok());
+        assert!(AnchorBlockInfo::validate_anchor_confirmation_depth(64, 64).is_err());
+    }
+}
@@ -1,16 +1,27 @@
+use crate::{
+    l1::{config::EthereumL1Config, height_cross_check::L1HeightCrossCheck},
+    metrics::Metrics,
+    shared::{alloy_tools, transaction_monitor::bump_tx_fees},
+};
 use alloy::{
+    consensus::{SidecarBuilder, Transaction as _},
     eips::BlockNumberOrTag,
-    primitives::{Address, B256},
+    network::{TransactionBuilder, TransactionBuilder7594},
+    primitives::{Address, B256, U256},
     providers::{DynProvider, Provider},
-    rpc::types::{Block as RpcBlock, Filter, Log},
+    rpc::types::{Block as RpcBlock, Filter, Log, Transaction, TransactionRequest},
 };
 use anyhow::Error;
-use tracing::debug;
+use serde_json::Value;
+use std::{sync::Arc, time::Duration};
+use taiko_protocol::shasta::BlobCoder;
+use tracing::{debug, warn};
 
 pub struct ExecutionLayer {
     provider: DynProvider,
     chain_id: u64,
     preconfer_address: Address,
+    height_cross_check: Option<L1HeightCrossCheck>,
 }
 
 pub struct BlockInfo {
@@ -42,9 +53,30 @@ impl ExecutionLayer {
             provider,
             chain_id,
             preconfer_address,
+            height_cross_check: None,
         })
     }
 
+    /// Creates the execution layer on a provider failing over between the configured L1 RPC URLs.
+    pub async fn from_config(
+        config: &EthereumL1Config,
+        metrics: Arc<Metrics>,
+    ) -> Result<Self, Error> {
+        let provider = alloy_tools::construct_failover_alloy_provider(
+            &config.signer,
+            &config.execution_rpc_urls,
+            Duration::from_secs(config.l1_rpc_failover_cooldown_sec),
+            metrics,
+        )
+        .await?;
+        Self::new(provider, config.signer.get_address()).await
+    }
+
+    /// Reads the latest block number from all given endpoints instead of the main provider only.
+    pub fn set_height_cross_check(&mut self, height_cross_check: L1HeightCrossCheck) {
+        self.height_cross_check = Some(height_cross_check);
+    }
+
     pub fn preconfer_address(&self) -> Address {
         self.preconfer_address
     }
@@ -184,6 +216,11 @@ impl ExecutionLayer {
     }
 
     pub async fn get_latest_block_id(&self) -> Result<u64, Error> {
+        if let Some(height_cross_check) = &self.height_cross_check {
+            return height_cross_check.get_latest_block_id().await.map_err(|e| {
+                self.chain_error("Failed to get latest block number", Some(&e.to_string()))
+            });
+        }
         self.provider.get_block_number().await.map_err(|e| {
             self.chain_error("Failed to get latest block number", Some(&e.to_string()))
         })
@@ -226,6 +263,80 @@ impl ExecutionLayer {
             .ok_or_else(|| self.chain_error("Failed to get transaction: value is None", None))
     }
 
+    /// Resubmits the pending transaction with the given nonce with its fees bumped by
+    /// `fee_bump_basis_points`, paying at least the current fee estimate. A stuck blob transaction
+    /// can't be resent without its blobs, so it is replaced by a blob transaction to the preconfer
+    /// address. When the stuck transaction can't be read, an empty transfer replaces it.
+    pub async fn resubmit_pending_transaction(
+        &self,
+        nonce: u64,
+        fee_bump_basis_points: u128,
+    ) -> Result<B256, Error> {
+        let fees = self.provider.estimate_eip1559_fees().await.map_err(|e| {
+            self.chain_error("Failed to estimate EIP-1559 fees", Some(&e.to_string()))
+        })?;
+        let stuck_tx = match self.get_pending_transaction(nonce).await {
+            Ok(Some(stuck_tx)) => Some(stuck_tx),
+            Ok(None) => {
+                warn!("Stuck transaction with nonce {nonce} not found in the transaction pool");
+                None
+            }
+            Err(err) => {
+                warn!("Failed to read the stuck transaction with nonce {nonce}: {err}");
+                None
+            }
+        };
+        let blob_base_fee = match &stuck_tx {
+            Some(stuck_tx) if stuck_tx.max_fee_per_blob_gas().is_some() => {
+                Some(self.provider.get_blob_base_fee().await.map_err(|e| {
+                    self.chain_error("Failed to get blob base fee", Some(&e.to_string()))
+                })?)
+            }
+            _ => None,
+        };
+
+        let mut tx = replacement_transaction(
+            stuck_tx,
+            (
+                fees.max_fee_per_gas,
+                fees.max_priority_fee_per_gas,
+                blob_base_fee,
+            ),
+            fee_bump_basis_points,
+            self.preconfer_address,
+        )
+        .with_nonce(nonce);
+        if tx.max_fee_per_blob_gas.is_some() {
+            let sidecar = SidecarBuilder::<BlobCoder>::from_slice(&[0])
+                .build_7594()
+                .map_err(|e| anyhow::anyhow!("Failed to build replacement blob sidecar: {e}"))?;
+            tx = tx.with_blob_sidecar(sidecar);
+        }
+        let pending_tx = self.provider.send_transaction(tx).await.map_err(|e| {
+            self.chain_error(
+                &format!("Failed to send replacement transaction for nonce {nonce}"),
+                Some(&e.to_string()),
+            )
+        })?;
+        Ok(*pending_tx.tx_hash())
+    }
+
+    /// The preconfer transaction with the given nonce in the transaction pool.
+    async fn get_pending_transaction(&self, nonce: u64) -> Result<Option<Transaction>, Error> {
+        let content: Value = self
+            .provider
+            .client()
+            .request("txpool_contentFrom", (self.preconfer_address,))
+            .await
+            .map_err(|e| {
+                self.chain_error(
+                    "Failed to get transaction pool content",
+                    Some(&e.to_string()),
+                )
+            })?;
+        pool_transaction(&content, nonce)
+    }
+
     pub async fn get_latest_block_number_and_timestamp(&self) -> Result<(u64, u64), Error> {
         let block = self
             .get_block_header(BlockNumberOrTag::Latest)
@@ -236,3 +347,246 @@ impl ExecutionLayer {
         Ok((block.header.number, block.header.timestamp))
     }
 }
+
+/// Max fee per gas, max priority fee per gas and max fee per blob gas of a transaction.
+type TxFees = (u128, u128, Option<u128>);
+
+/// Reads the transaction with the given nonce from a `txpool_contentFrom` response.
+fn pool_transaction(content: &Value, nonce: u64) -> Result<Option<Transaction>, Error> {
+    let nonce = nonce.to_string();
+    let Some(tx) = ["pending", "queued"]
+        .iter()
+        .find_map(|pool| content.get(pool)?.get(&nonce))
+    else {
+        return Ok(None);
+    };
+    serde_json::from_value(tx.clone())
+        .map(Some)
+        .map_err(|e| anyhow::anyhow!("Failed to decode pool transaction with nonce {nonce}: {e}"))
+}
+
+/// Fees of the transaction, the gas price is used for both gas fees of a legacy transaction.
+fn transaction_fees(tx: &Transaction) -> TxFees {
+    let max_fee_per_gas = tx.max_fee_per_gas();
+    (
+        max_fee_per_gas,
+        tx.max_priority_fee_per_gas().unwrap_or(max_fee_per_gas),
+        tx.max_fee_per_blob_gas(),
+    )
+}
+
+/// Replacement of the stuck transaction, without nonce and blob sidecar. A regular transaction is
+/// sent again, while a blob transaction becomes a blob transaction to `preconfer_address`, as its
+/// blobs are not in the pool. Without the stuck transaction, an empty transfer is sent.
+fn replacement_transaction(
+    stuck_tx: Option<Transaction>,
+    estimated_fees: TxFees,
+    fee_bump_basis_points: u128,
+    preconfer_address: Address,
+) -> TransactionRequest {
+    let (max_fee_per_gas, max_priority_fee_per_gas, max_fee_per_blob_gas) = replacement_fees(
+        stuck_tx.as_ref().map(transaction_fees),
+        estimated_fees,
+        fee_bump_basis_points,
+    );
+    let tx = match stuck_tx {
+        Some(stuck_tx) if max_fee_per_blob_gas.is_none() => {
+            let mut tx = stuck_tx.into_request();
+            tx.gas_price = None;
+            tx
+        }
+        _ => TransactionRequest::default()
+            .with_from(preconfer_address)
+            .with_to(preconfer_address)
+            .with_value(U256::ZERO)
+            .with_gas_limit(21_000),
+    };
+    let mut tx = tx
+        .with_max_fee_per_gas(max_fee_per_gas)
+        .with_max_priority_fee_per_gas(max_priority_fee_per_gas);
+    tx.max_fee_per_blob_gas = max_fee_per_blob_gas;
+    tx
+}
+
+/// Fees of the replacement transaction: every fee of the stuck transaction bumped, and at least
+/// the current estimate. Without the stuck transaction fees, twice the current estimate is paid.
+fn replacement_fees(
+    stuck_fees: Option<TxFees>,
+    (estimated_max_fee, estimated_max_priority_fee, estimated_blob_base_fee): TxFees,
+    fee_bump_basis_points: u128,
+) -> TxFees {
+    match stuck_fees {
+        Some((max_fee, max_priority_fee, max_fee_per_blob_gas)) => {
+            let (max_fee, max_priority_fee, max_fee_per_blob_gas) = bump_tx_fees(
+                max_fee,
+                max_priority_fee,
+                max_fee_per_blob_gas,
+                fee_bump_basis_points,
+            );
+            (
+                max_fee.max(estimated_max_fee),
+                max_priority_fee.max(estimated_max_priority_fee),
+                max_fee_per_blob_gas
+                    .map(|blob_fee| blob_fee.max(estimated_blob_base_fee.unwrap_or_default())),
+            )
+        }
+        None => (estimated_max_fee * 2, estimated_max_priority_fee * 2, None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::{
+        consensus::{
+            Signed, TxEip1559, TxEip4844, TxEip4844Variant, TxEnvelope, transaction::Recovered,
+        },
+        primitives::{Bytes, Signature, TxKind},
+    };
+
+    fn pool_transaction_json(tx: TxEnvelope) -> Value {
+        let tx = Transaction {
+            inner: Recovered::new_unchecked(tx, Address::repeat_byte(7)),
+            block_hash: None,
+            block_number: None,
+            transaction_index: None,
+            effective_gas_price: None,
+        };
+        serde_json::to_value(tx).expect("failed to encode transaction")
+    }
+
+    fn eip1559_tx(nonce: u64) -> TxEnvelope {
+        TxEnvelope::Eip1559(Signed::new_unchecked(
+            TxEip1559 {
+                chain_id: 1,
+                nonce,
+                gas_limit: 500_000,
+                max_fee_per_gas: 10_000_000_000,
+                max_priority_fee_per_gas: 1_000_000_000,
+                to: TxKind::Call(Address::repeat_byte(1)),
+                input: Bytes::from_static(&[0xde, 0xad]),
+                ..Default::default()
+            },
+            Signature::test_signature(),
+            B256::repeat_byte(2),
+        ))
+    }
+
+    fn blob_tx(nonce: u64) -> TxEnvelope {
+        TxEnvelope::Eip4844(Signed::new_unchecked(
+            TxEip4844Variant::TxEip4844(TxEip4844 {
+                chain_id: 1,
+                nonce,
+                gas_limit: 500_000,
+                max_fee_per_gas: 10_000_000_000,
+                max_priority_fee_per_gas: 1_000_000_000,
+                max_fee_per_blob_gas: 3_000_000_000,
+                to: Address::repeat_byte(1),
+                blob_versioned_hashes: vec![B256::repeat_byte(3)],
+                input: Bytes::from_static(&[0xbe, 0xef]),
+                ..Default::default()
+            }),
+            Signature::test_signature(),
+            B256::repeat_byte(4),
+        ))
+    }
+
+    #[test]
+    fn test_pool_transaction() {
+        let content = serde_json::json!({
+            "pending": {"7": pool_transaction_json(eip1559_tx(7))},
+            "queued": {"9": pool_transaction_json(blob_tx(9))},
+        });
+
+        let pending = pool_transaction(&content, 7)
+            .expect("decoding failed")
+            .expect("pending transaction");
+        assert_eq!(
+            transaction_fees(&pending),
+            (10_000_000_000, 1_000_000_000, None)
+        );
+        let queued = pool_transaction(&content, 9)
+            .expect("decoding failed")
+            .expect("queued transaction");
+        assert_eq!(
+            transaction_fees(&queued),
+            (10_000_000_000, 1_000_000_000, Some(3_000_000_000))
+        );
+        assert!(
+            pool_transaction(&content, 8)
+                .expect("decoding failed")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_stuck_transaction_is_resubmitted_with_bumped_fees() {
+        let content = serde_json::json!({"pending": {"7": pool_transaction_json(eip1559_tx(7))}});
+        let stuck_tx = pool_transaction(&content, 7).expect("decoding failed");
+
+        let tx = replacement_transaction(
+            stuck_tx,
+            (2_000_000_000, 100_000_000, None),
+            1_250,
+            Address::repeat_byte(7),
+        );
+
+        // The original call is sent again
+        assert_eq!(tx.to, Some(TxKind::Call(Address::repeat_byte(1))));
+        assert_eq!(tx.input.input(), Some(&Bytes::from_static(&[0xde, 0xad])));
+        assert_eq!(tx.gas, Some(500_000));
+        assert_eq!(tx.gas_price, None);
+        assert_eq!(tx.max_fee_per_gas, Some(11_250_000_000));
+        assert_eq!(tx.max_priority_fee_per_gas, Some(1_125_000_000));
+        assert_eq!(tx.max_fee_per_blob_gas, None);
+    }
+
+    #[test]
+    fn test_stuck_blob_transaction_is_replaced_by_blob_transaction() {
+        let content = serde_json::json!({"pending": {"9": pool_transaction_json(blob_tx(9))}});
+        let stuck_tx = pool_transaction(&content, 9).expect("decoding failed");
+
+        let tx = replacement_transaction(
+            stuck_tx,
+            (2_000_000_000, 100_000_000, Some(1_000_000_000)),
+            1_250,
+            Address::repeat_byte(7),
+        );
+
+        // The blob pool requires every fee to be doubled
+        assert_eq!(tx.to, Some(TxKind::Call(Address::repeat_byte(7))));
+        assert_eq!(tx.max_fee_per_gas, Some(20_000_000_000));
+        assert_eq!(tx.max_priority_fee_per_gas, Some(2_000_000_000));
+        assert_eq!(tx.max_fee_per_blob_gas, Some(6_000_000_000));
+    }
+
+    #[test]
+    fn test_replacement_fees_bump_stuck_transaction_after_fee_drop() {
+        // Fees dropped since the stuck transaction was sent
+        let stuck_fees = Some((10_000_000_000, 1_000_000_000, None));
+        let estimate = (2_000_000_000, 100_000_000, None);
+
+        assert_eq!(
+            replacement_fees(stuck_fees, estimate, 1_250),
+            (11_250_000_000, 1_125_000_000, None)
+        );
+        // Fees rose above the bumped ones
+        assert_eq!(
+            replacement_fees(stuck_fees, (20_000_000_000, 2_000_000_000, None), 1_250),
+            (20_000_000_000, 2_000_000_000, None)
+        );
+        assert_eq!(
+            replacement_fees(None, estimate, 1_250),
+            (4_000_000_000, 200_000_000, None)
+        );
+        // Blob base fee rose above the doubled blob fee
+        assert_eq!(
+            replacement_fees(
+                Some((10_000_000_000, 1_000_000_000, Some(1_000_000_000))),
+                (2_000_000_000, 100_000_000, Some(5_000_000_000)),
+                1_250
+            ),
+            (20_000_000_000, 2_000_000_000, Some(5_000_000_000))
+        );
+    }
+}
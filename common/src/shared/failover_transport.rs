@@ -0,0 +1,262 @@
+use crate::metrics::Metrics;
+use alloy::transports::{BoxTransport, RpcError, TransportError, TransportErrorKind, TransportFut};
+use alloy_json_rpc::{RequestPacket, ResponsePacket};
+use std::{
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+use tower::Service;
+use tracing::warn;
+
+struct Endpoint {
+    label: String,
+    transport: BoxTransport,
+    unhealthy_until: Mutex<Option<Instant>>,
+}
+
+impl Endpoint {
+    fn unhealthy_until(&self) -> Option<Instant> {
+        *self
+            .unhealthy_until
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn set_unhealthy_until(&self, until: Option<Instant>) {
+        *self
+            .unhealthy_until
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = until;
+    }
+}
+
+/// Transport sending each request to the first healthy RPC endpoint in the configured order.
+///
+/// A transport error, like a refused connection, marks the endpoint unhealthy for the cooldown
+/// and the request is retried on the next endpoint. JSON-RPC error responses are returned as
+/// they are, since any other endpoint would answer the same. Requests sending a transaction are
+/// not retried, as the failing endpoint may have accepted the transaction before the error.
+#[derive(Clone)]
+pub struct FailoverTransport {
+    endpoints: Arc<Vec<Endpoint>>,
+    cooldown: Duration,
+    metrics: Arc<Metrics>,
+}
+
+impl FailoverTransport {
+    /// Takes the endpoints as URL and transport pairs, in the order of preference.
+    pub fn new(
+        endpoints: Vec<(String, BoxTransport)>,
+        cooldown: Duration,
+        metrics: Arc<Metrics>,
+    ) -> Self {
+        let endpoints = endpoints
+            .into_iter()
+            .map(|(url, transport)| Endpoint {
+                label: endpoint_label(&url),
+                transport,
+                unhealthy_until: Mutex::new(None),
+            })
+            .collect();
+        Self {
+            endpoints: Arc::new(endpoints),
+            cooldown,
+            metrics,
+        }
+    }
+
+    async fn send(self, request: RequestPacket) -> Result<ResponsePacket, TransportError> {
+        let unhealthy_until: Vec<Option<Instant>> = self
+            .endpoints
+            .iter()
+            .map(Endpoint::unhealthy_until)
+            .collect();
+
+        let fail_over = !sends_transaction(&request);
+        let mut last_error = None;
+        for index in endpoint_order(&unhealthy_until, Instant::now()) {
+            let endpoint = &self.endpoints[index];
+            let mut transport = endpoint.transport.clone();
+            match transport.call(request.clone()).await {
+                Ok(response) => {
+                    endpoint.set_unhealthy_until(None);
+                    return Ok(response);
+                }
+                Err(RpcError::Transport(err)) => {
+                    endpoint.set_unhealthy_until(Some(Instant::now() + self.cooldown));
+                    if !fail_over {
+                        warn!(
+                            "RPC endpoint {} failed to send a transaction: {}. Marking it unhealthy for {:?}",
+                            endpoint.label, err, self.cooldown
+                        );
+                        return Err(RpcError::Transport(err));
+                    }
+                    warn!(
+                        "RPC endpoint {} failed: {}. Marking it unhealthy for {:?} and failing over",
+                        endpoint.label, err, self.cooldown
+                    );
+                    self.metrics.inc_l1_rpc_failovers(&endpoint.label);
+                    last_error = Some(RpcError::Transport(err));
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        Err(last_error
+            .unwrap_or_else(|| TransportErrorKind::custom_str("No RPC endpoint configured")))
+    }
+}
+
+impl Service<RequestPacket> for FailoverTransport {
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: RequestPacket) -> Self::Future {
+        Box::pin(self.clone().send(request))
+    }
+}
+
+fn sends_transaction(request: &RequestPacket) -> bool {
+    request
+        .method_names()
+        .any(|method| matches!(method, "eth_sendRawTransaction" | "eth_sendTransaction"))
+}
+
+/// Healthy endpoints in the configured order, followed by the unhealthy ones by the end of their
+/// cooldown, so a request is still attempted when every endpoint is failing.
+fn endpoint_order(unhealthy_until: &[Option<Instant>], now: Instant) -> Vec<usize> {
+    let (mut order, mut unhealthy): (Vec<usize>, Vec<usize>) = (0..unhealthy_until.len())
+        .partition(|&index| unhealthy_until[index].is_none_or(|until| until <= now));
+    unhealthy.sort_by_key(|&index| unhealthy_until[index]);
+    order.extend(unhealthy);
+    order
+}
+
+/// Host and port of the URL, used as metric label so API keys in the path are not exposed.
+fn endpoint_label(url: &str) -> String {
+    match reqwest::Url::parse(url) {
+        Ok(parsed) => match (parsed.host_str(), parsed.port()) {
+            (Some(host), Some(port)) => format!("{host}:{port}"),
+            (Some(host), None) => host.to_string(),
+            (None, _) => "unknown".to_string(),
+        },
+        Err(_) => "unknown".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::alloy_tools;
+    use alloy::{
+        providers::{Provider, ProviderBuilder},
+        rpc::client::RpcClient,
+    };
+    use serde_json::Value;
+
+    async fn transport(url: &str) -> BoxTransport {
+        alloy_tools::create_alloy_provider_without_wallet(url)
+            .await
+            .expect("failed to create provider")
+            .client()
+            .transport()
+            .clone()
+    }
+
+    #[tokio::test]
+    async fn test_failover_to_secondary_on_connection_errors() {
+        let mut primary = mockito::Server::new_async().await;
+        let primary_mock = primary
+            .mock("POST", "/")
+            .with_status(503)
+            .expect(1)
+            .create_async()
+            .await;
+        let mut secondary = mockito::Server::new_async().await;
+        secondary
+            .mock("POST", "/")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body_from_request(|request| {
+                let body: Value = serde_json::from_slice(request.body().expect("request body"))
+                    .expect("valid JSON-RPC request");
+                serde_json::json!({"jsonrpc": "2.0", "id": body["id"], "result": "0x10"})
+                    .to_string()
+                    .into()
+            })
+            .create_async()
+            .await;
+
+        let metrics = Arc::new(Metrics::new());
+        let failover = FailoverTransport::new(
+            vec![
+                (primary.url(), transport(&primary.url()).await),
+                (secondary.url(), transport(&secondary.url()).await),
+            ],
+            Duration::from_secs(60),
+            metrics.clone(),
+        );
+        let provider = ProviderBuilder::new().connect_client(RpcClient::new(failover, false));
+
+        assert_eq!(provider.get_block_number().await.unwrap(), 16);
+        // The primary is in its cooldown, so it is not queried again
+        assert_eq!(provider.get_block_number().await.unwrap(), 16);
+        primary_mock.assert_async().await;
+
+        assert!(metrics.gather().contains(&format!(
+            "catalyst_l1_rpc_failovers_total{{endpoint=\"{}\"}} 1",
+            primary.host_with_port()
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_transaction_is_not_sent_to_secondary() {
+        let mut primary = mockito::Server::new_async().await;
+        primary
+            .mock("POST", "/")
+            .with_status(503)
+            .expect(1)
+            .create_async()
+            .await;
+        let mut secondary = mockito::Server::new_async().await;
+        let secondary_mock = secondary.mock("POST", "/").expect(0).create_async().await;
+
+        let failover = FailoverTransport::new(
+            vec![
+                (primary.url(), transport(&primary.url()).await),
+                (secondary.url(), transport(&secondary.url()).await),
+            ],
+            Duration::from_secs(60),
+            Arc::new(Metrics::new()),
+        );
+        let provider = ProviderBuilder::new().connect_client(RpcClient::new(failover, false));
+
+        // The primary may have accepted the transaction before failing
+        assert!(provider.send_raw_transaction(&[0x02, 0x01]).await.is_err());
+        secondary_mock.assert_async().await;
+    }
+
+    #[test]
+    fn test_endpoint_order() {
+        let now = Instant::now();
+        let later = now + Duration::from_secs(10);
+        let latest = now + Duration::from_secs(20);
+
+        assert_eq!(endpoint_order(&[None, None, None], now), vec![0, 1, 2]);
+        // Unhealthy endpoints go last, and an expired cooldown makes the endpoint healthy again
+        assert_eq!(
+            endpoint_order(&[Some(later), None, Some(now)], now),
+            vec![1, 2, 0]
+        );
+        // All unhealthy, the one recovering first is tried first
+        assert_eq!(
+            endpoint_order(&[Some(latest), Some(later)], now),
+            vec![1, 0]
+        );
+    }
+}
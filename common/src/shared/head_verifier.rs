@@ -1,4 +1,9 @@
-use alloy::primitives::B256;
+use crate::{
+    l2::taiko_driver::models::BuildPreconfBlockResponse, metrics::Metrics,
+    shared::execution_layer::ExecutionLayer,
+};
+use alloy::{eips::BlockNumberOrTag, primitives::B256, providers::Provider};
+use anyhow::Error;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
@@ -58,3 +63,121 @@ impl HeadVerifier {
         );
     }
 }
+
+/// Checks that Geth stores the block returned by the driver at its height. A missing or
+/// different block is logged and counted as a hash mismatch, and false is returned.
+pub async fn verify_produced_block_stored_in_geth(
+    l2_execution_layer: &ExecutionLayer,
+    block: &BuildPreconfBlockResponse,
+    metrics: &Metrics,
+) -> Result<bool, Error> {
+    let stored_hash = l2_execution_layer
+        .provider()
+        .get_block_by_number(BlockNumberOrTag::Number(block.number))
+        .await
+        .map_err(|e| {
+            l2_execution_layer.chain_error("Failed to get produced block", Some(&e.to_string()))
+        })?
+        .map(|stored| stored.header.hash);
+    if stored_hash == Some(block.hash) {
+        return Ok(true);
+    }
+
+    metrics.inc_produced_block_hash_mismatches();
+    match stored_hash {
+        Some(stored_hash) => tracing::error!(
+            "Produced block {} hash {} differs from Geth block hash {}",
+            block.number,
+            block.hash,
+            stored_hash
+        ),
+        None => tracing::error!(
+            "Produced block {} hash {} not found in Geth",
+            block.number,
+            block.hash
+        ),
+    }
+    Ok(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::alloy_tools;
+    use alloy::{primitives::Address, rpc::types::Block};
+    use serde_json::Value;
+
+    fn make_block(hash: B256) -> BuildPreconfBlockResponse {
+        BuildPreconfBlockResponse {
+            number: 10,
+            hash,
+            state_root: B256::ZERO,
+            parent_hash: B256::repeat_byte(9),
+            is_forced_inclusion: false,
+        }
+    }
+
+    /// Execution layer of a Geth storing the given block, if any, at every height.
+    async fn geth_storing(
+        server: &mut mockito::ServerGuard,
+        stored_hash: Option<B256>,
+    ) -> ExecutionLayer {
+        let stored_block = stored_hash.map(|hash| {
+            let mut block = Block::default();
+            block.header.hash = hash;
+            block
+        });
+        server
+            .mock("POST", "/")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body_from_request(move |request| {
+                let body: Value = serde_json::from_slice(request.body().expect("request body"))
+                    .expect("valid JSON-RPC request");
+                let result = match body["method"].as_str() {
+                    Some("eth_chainId") => Value::from("0x1"),
+                    _ => serde_json::to_value(&stored_block).expect("serializable block"),
+                };
+                serde_json::json!({"jsonrpc": "2.0", "id": body["id"], "result": result})
+                    .to_string()
+                    .into()
+            })
+            .create_async()
+            .await;
+        let provider = alloy_tools::create_alloy_provider_without_wallet(&server.url())
+            .await
+            .expect("failed to create provider");
+        ExecutionLayer::new(provider, Address::ZERO)
+            .await
+            .expect("failed to create execution layer")
+    }
+
+    #[tokio::test]
+    async fn test_verify_produced_block_stored_in_geth() {
+        let block = make_block(B256::repeat_byte(1));
+        let cases = [
+            (Some(B256::repeat_byte(1)), true),
+            (None, false),
+            (Some(B256::repeat_byte(2)), false),
+        ];
+
+        for (stored_hash, expected) in cases {
+            let mut server = mockito::Server::new_async().await;
+            let geth = geth_storing(&mut server, stored_hash).await;
+            let metrics = Metrics::new();
+
+            let stored = verify_produced_block_stored_in_geth(&geth, &block, &metrics)
+                .await
+                .expect("Geth query failed");
+
+            assert_eq!(stored, expected, "stored hash {stored_hash:?}");
+            assert_eq!(
+                metrics
+                    .gather()
+                    .contains("catalyst_produced_block_hash_mismatches_total 1"),
+                !expected,
+                "stored hash {stored_hash:?}"
+            );
+        }
+    }
+}
@@ -1,8 +1,12 @@
 use crate::utils::cancellation_token::CancellationToken;
+use anyhow::Error;
 use axum::Router;
 use std::net::SocketAddr;
+use std::time::Duration;
 use tokio::net::TcpListener;
-use tracing::{error, info};
+use tracing::{error, info, warn};
+
+const BIND_RETRY_DELAY: Duration = Duration::from_secs(5);
 
 /// Spawns an internal HTTP server that merges the provided routes and listens on the given IP and
 /// port. The server shuts down gracefully when the `cancel_token` is cancelled.
@@ -10,26 +14,97 @@ use tracing::{error, info};
 /// Known routes (registered by callers):
 /// - `GET /metrics` — Prometheus metrics (all protocol variants)
 /// - `GET /status`  — Node status (Shasta only)
-pub fn serve(cancel_token: CancellationToken, routes: Vec<Router>, ip: [u8; 4], port: u16) {
+/// - `GET /role`    — Current operator role (Shasta only)
+/// - `GET /admin/current-batch` — In-progress proposals, token-guarded (Shasta only)
+///
+/// Binding is retried `bind_max_retries` times, e.g. while a previous process still holds the
+/// port. If it keeps failing, the node shuts down when `required` is set, otherwise it keeps
+/// running without the internal server.
+pub fn serve(
+    cancel_token: CancellationToken,
+    routes: Vec<Router>,
+    ip: [u8; 4],
+    port: u16,
+    bind_max_retries: u64,
+    required: bool,
+) {
     let addr = SocketAddr::from((ip, port));
     tokio::spawn(async move {
-        let app = build_app(routes);
+        bind_and_serve(
+            cancel_token,
+            routes,
+            addr,
+            bind_max_retries,
+            BIND_RETRY_DELAY,
+            required,
+        )
+        .await;
+    });
+}
 
-        info!("Internal server listening on {}", addr);
+async fn bind_and_serve(
+    cancel_token: CancellationToken,
+    routes: Vec<Router>,
+    addr: SocketAddr,
+    bind_max_retries: u64,
+    bind_retry_delay: Duration,
+    required: bool,
+) {
+    let app = build_app(routes);
 
-        let listener = match TcpListener::bind(addr).await {
-            Ok(listener) => listener,
-            Err(err) => {
+    let listener = match bind_with_retries(addr, bind_max_retries, bind_retry_delay).await {
+        Ok(listener) => listener,
+        Err(err) => {
+            if required {
+                error!(
+                    "Failed to bind internal server listener on {}: {}. Shutting down the node",
+                    addr, err
+                );
+                cancel_token.cancel_on_critical_error();
+            } else {
                 error!(
-                    "Failed to bind internal server listener on {}: {}",
+                    "Failed to bind internal server listener on {}: {}. Node continues without metrics and internal endpoints",
                     addr, err
                 );
-                return;
             }
-        };
+            return;
+        }
+    };
 
-        run_server(listener, app, cancel_token).await;
-    });
+    info!("Internal server listening on {}", addr);
+    run_server(listener, app, cancel_token).await;
+}
+
+async fn bind_with_retries(
+    addr: SocketAddr,
+    max_retries: u64,
+    retry_delay: Duration,
+) -> Result<TcpListener, Error> {
+    let mut attempt = 0;
+    loop {
+        match TcpListener::bind(addr).await {
+            Ok(listener) => return Ok(listener),
+            Err(err) if attempt < max_retries => {
+                attempt += 1;
+                warn!(
+                    "Failed to bind internal server listener on {}: {}. Retrying in {}s ({}/{})",
+                    addr,
+                    err,
+                    retry_delay.as_secs(),
+                    attempt,
+                    max_retries
+                );
+                tokio::time::sleep(retry_delay).await;
+            }
+            Err(err) => {
+                return Err(anyhow::anyhow!(
+                    "{} after {} attempts",
+                    err,
+                    max_retries + 1
+                ));
+            }
+        }
+    }
 }
 
 fn build_app(routes: Vec<Router>) -> Router {
@@ -51,3 +126,41 @@ async fn run_server(listener: TcpListener, app: Router, shutdown_token: Cancella
         error!("Internal server terminated with error: {}", err);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metrics::Metrics;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_node_continues_when_port_is_in_use() {
+        let taken = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("failed to bind listener");
+        let addr = taken.local_addr().expect("failed to get local addr");
+
+        let cancel_token = CancellationToken::new(Arc::new(Metrics::new()));
+        bind_and_serve(
+            cancel_token.clone(),
+            Vec::new(),
+            addr,
+            2,
+            Duration::from_millis(10),
+            false,
+        )
+        .await;
+        assert!(!cancel_token.is_cancelled());
+
+        bind_and_serve(
+            cancel_token.clone(),
+            Vec::new(),
+            addr,
+            0,
+            Duration::from_millis(10),
+            true,
+        )
+        .await;
+        assert!(cancel_token.is_cancelled());
+    }
+}
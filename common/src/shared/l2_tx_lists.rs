@@ -125,6 +125,15 @@ impl PreBuiltTxList {
             bytes_length,
         }
     }
+
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn empty_with_estimated_gas_used(estimated_gas_used: u64) -> Self {
+        PreBuiltTxList {
+            tx_list: Vec::new(),
+            estimated_gas_used,
+            bytes_length: 0,
+        }
+    }
 }
 
 pub fn uncompress_and_decode(data: &[u8]) -> Result<Vec<Transaction>, Error> {
@@ -1,6 +1,7 @@
 pub mod alloy_tools;
 pub mod anchor_block_info;
 pub mod execution_layer;
+pub mod failover_transport;
 pub mod head_verifier;
 pub mod internal_server;
 pub mod l2_block;
@@ -8,4 +9,5 @@ pub mod l2_block_v2;
 pub mod l2_slot_info;
 pub mod l2_slot_info_v2;
 pub mod l2_tx_lists;
+pub mod safe_mode;
 pub mod transaction_monitor;
@@ -0,0 +1,131 @@
+use anyhow::Error;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Circuit breaker for repeated unexpected L2 head errors.
+///
+/// Error timestamps are persisted to a file so the breaker survives the restarts
+/// triggered by those errors. Once `max_errors` errors are recorded within `window_sec`,
+/// the node should stop preconfirming until the errors age out of the window.
+pub struct SafeModeBreaker {
+    state_path: PathBuf,
+    max_errors: Option<u64>,
+    window_sec: u64,
+    error_timestamps: Vec<u64>,
+}
+
+impl SafeModeBreaker {
+    /// Loads previously recorded errors, a missing or unreadable state file starts empty.
+    pub fn load(state_path: PathBuf, max_errors: Option<u64>, window_sec: u64) -> Self {
+        let error_timestamps = std::fs::read_to_string(&state_path)
+            .map(|content| {
+                content
+                    .lines()
+                    .filter_map(|line| line.trim().parse::<u64>().ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self {
+            state_path,
+            max_errors,
+            window_sec,
+            error_timestamps,
+        }
+    }
+
+    /// Records an unexpected head error and returns true when the breaker is tripped.
+    pub fn record_error(&mut self, now_sec: u64) -> Result<bool, Error> {
+        if self.max_errors.is_none() {
+            return Ok(false);
+        }
+        self.prune(now_sec);
+        self.error_timestamps.push(now_sec);
+        self.persist()?;
+        Ok(self.is_tripped(now_sec))
+    }
+
+    pub fn is_tripped(&self, now_sec: u64) -> bool {
+        let Some(max_errors) = self.max_errors else {
+            return false;
+        };
+        let recent_errors = self
+            .error_timestamps
+            .iter()
+            .filter(|&&timestamp| now_sec.saturating_sub(timestamp) < self.window_sec)
+            .count();
+        u64::try_from(recent_errors).unwrap_or(u64::MAX) >= max_errors
+    }
+
+    fn prune(&mut self, now_sec: u64) {
+        self.error_timestamps
+            .retain(|&timestamp| now_sec.saturating_sub(timestamp) < self.window_sec);
+    }
+
+    fn persist(&self) -> Result<(), Error> {
+        let content = self
+            .error_timestamps
+            .iter()
+            .map(u64::to_string)
+            .collect::<Vec<_>>()
+            .join("\n");
+        std::fs::write(&self.state_path, content).map_err(|e| {
+            anyhow::anyhow!(
+                "Failed to write safe mode state to {}: {}",
+                self.state_path.display(),
+                e
+            )
+        })
+    }
+}
+
+pub fn unix_timestamp_sec() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state_path(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "catalyst_safe_mode_{}_{}",
+            name,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn test_breaker_trips_across_restarts() {
+        let path = state_path("trip");
+
+        let mut breaker = SafeModeBreaker::load(path.clone(), Some(3), 600);
+        assert!(!breaker.record_error(1_000).expect("record error"));
+        assert!(!breaker.record_error(1_100).expect("record error"));
+
+        // Simulate a restart, previous errors are loaded from the state file
+        let mut breaker = SafeModeBreaker::load(path.clone(), Some(3), 600);
+        assert!(!breaker.is_tripped(1_200));
+        assert!(breaker.record_error(1_200).expect("record error"));
+        assert!(SafeModeBreaker::load(path.clone(), Some(3), 600).is_tripped(1_250));
+
+        // Errors age out of the window
+        assert!(!breaker.is_tripped(1_700));
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_breaker_disabled() {
+        let path = state_path("disabled");
+
+        let mut breaker = SafeModeBreaker::load(path.clone(), None, 600);
+        for now in 0..10 {
+            assert!(!breaker.record_error(now).expect("record error"));
+        }
+        assert!(!path.exists());
+    }
+}
@@ -1,7 +1,11 @@
-use crate::l1::{config::EthereumL1Config, tools, transaction_error::TransactionError};
+use crate::l1::{
+    config::EthereumL1Config, fee_history_cache::FeeHistoryCache, tools,
+    transaction_error::TransactionError,
+};
 use crate::{metrics::Metrics, shared::alloy_tools, signer::Signer};
 use alloy::{
     consensus::TxType,
+    eips::BlockNumberOrTag,
     network::{Network, ReceiptResponse, TransactionBuilder, TransactionBuilder4844},
     primitives::{B256, FixedBytes},
     providers::{
@@ -42,6 +46,28 @@ pub struct TxMonitorHandles {
     pub tx_result_receiver: tokio::sync::oneshot::Receiver<bool>,
 }
 
+/// Waits for the hash of the first sent transaction and logs it at INFO,
+/// so the submission can be looked up on an explorer before it is mined.
+/// Returns the logged hash, or None if the transaction was never sent.
+pub async fn log_submitted_tx_hash(
+    tx_hash_receiver: tokio::sync::oneshot::Receiver<B256>,
+    description: &str,
+) -> Option<B256> {
+    match tx_hash_receiver.await {
+        Ok(tx_hash) => {
+            info!("📤 {} submitted, tx hash: {}", description, tx_hash);
+            Some(tx_hash)
+        }
+        Err(_) => {
+            debug!(
+                "{} transaction was not sent, no tx hash to log",
+                description
+            );
+            None
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TransactionMonitorConfig {
     min_priority_fee_per_gas_wei: u128,
@@ -49,6 +75,8 @@ pub struct TransactionMonitorConfig {
     max_attempts_to_send_tx: u64,
     max_attempts_to_wait_tx: u64,
     delay_between_tx_attempts: Duration,
+    resubmit_after_slots: u64,
+    fee_bump_basis_points: u128,
     execution_rpc_urls: Vec<String>,
     signer: Arc<Signer>,
 }
@@ -63,6 +91,9 @@ pub struct TransactionMonitorThread {
     sent_tx_hashes: Vec<FixedBytes<32>>,
     tx_hash_notifier: Option<tokio::sync::oneshot::Sender<B256>>,
     tx_result_notifier: tokio::sync::oneshot::Sender<bool>,
+    fee_history_cache: FeeHistoryCache,
+    /// L1 base fee of the fee history the transaction was built with.
+    build_base_fee: Option<u64>,
 }
 
 //#[derive(Debug)]
@@ -73,6 +104,14 @@ pub struct TransactionMonitor {
     error_notification_channel: Sender<TransactionError>,
     metrics: Arc<Metrics>,
     chain_id: u64,
+    fee_history_cache: FeeHistoryCache,
+    fee_history_refresh: JoinHandle<()>,
+}
+
+impl Drop for TransactionMonitor {
+    fn drop(&mut self) {
+        self.fee_history_refresh.abort();
+    }
 }
 
 impl TransactionMonitor {
@@ -84,6 +123,16 @@ impl TransactionMonitor {
         metrics: Arc<Metrics>,
         chain_id: u64,
     ) -> Result<Self, Error> {
+        // Keep the fee history warm, so the first submission after a restart does not wait for it.
+        // The blob fee is taken from the cache without headroom, so it must not be older than a slot.
+        let slot_duration = Duration::from_secs(config.slot_duration_sec);
+        let fee_history_cache = FeeHistoryCache::new(
+            provider.clone(),
+            config.priority_fee_strategy,
+            slot_duration,
+        );
+        let fee_history_refresh = fee_history_cache.spawn_refresh_task(slot_duration);
+
         Ok(Self {
             provider,
             config: TransactionMonitorConfig {
@@ -94,6 +143,8 @@ impl TransactionMonitor {
                 delay_between_tx_attempts: Duration::from_secs(
                     config.delay_between_tx_attempts_sec,
                 ),
+                resubmit_after_slots: config.tx_resubmit_after_slots,
+                fee_bump_basis_points: u128::from(config.tx_fee_bump_basis_points),
                 execution_rpc_urls: config.execution_rpc_urls.clone(),
                 signer: config.signer.clone(),
             },
@@ -101,8 +152,15 @@ impl TransactionMonitor {
             error_notification_channel,
             metrics,
             chain_id,
+            fee_history_cache,
+            fee_history_refresh,
         })
     }
+
+    /// Fees per gas refreshed in the background, to be used when building transactions.
+    pub fn fee_history_cache(&self) -> FeeHistoryCache {
+        self.fee_history_cache.clone()
+    }
 }
 
 impl TransactionMonitor {
@@ -139,6 +197,7 @@ impl TransactionMonitor {
             self.chain_id,
             tx_hash_sender,
             tx_result_sender,
+            self.fee_history_cache.clone(),
         );
         let join_handle = monitor_thread.spawn_monitoring_task(tx);
         *guard = Some(join_handle);
@@ -178,6 +237,7 @@ impl TransactionMonitor {
             self.chain_id,
             tx_hash_sender,
             tx_result_sender,
+            self.fee_history_cache.clone(),
         );
         let join_handle = monitor_thread.spawn_monitoring_task_with_builder(tx_builder);
         *guard = Some(join_handle);
@@ -204,6 +264,7 @@ impl TransactionMonitorThread {
         chain_id: u64,
         tx_hash_notifier: tokio::sync::oneshot::Sender<B256>,
         tx_result_notifier: tokio::sync::oneshot::Sender<bool>,
+        fee_history_cache: FeeHistoryCache,
     ) -> Self {
         Self {
             provider,
@@ -215,6 +276,8 @@ impl TransactionMonitorThread {
             sent_tx_hashes: Vec::new(),
             tx_hash_notifier: Some(tx_hash_notifier),
             tx_result_notifier,
+            fee_history_cache,
+            build_base_fee: None,
         }
     }
     pub fn spawn_monitoring_task(self, tx: TransactionRequest) -> JoinHandle<()> {
@@ -256,6 +319,12 @@ impl TransactionMonitorThread {
             return;
         }
         tx.set_chain_id(self.chain_id);
+        // The builder just read the cached fees, so no block is fetched for the build base fee
+        self.build_base_fee = self
+            .fee_history_cache
+            .get_cached()
+            .await
+            .and_then(|fees| u64::try_from(fees.base_fee_per_gas()).ok());
 
         debug!(
             "Monitoring tx with nonce: {}  max_fee_per_gas: {:?}, max_priority_fee_per_gas: {:?}, max_fee_per_blob_gas: {:?}",
@@ -351,6 +420,7 @@ impl TransactionMonitorThread {
                 max_priority_fee_per_gas,
                 max_fee_per_blob_gas
             );
+            self.metrics.set_tx_max_fee_per_gas(max_fee_per_gas);
 
             if let Some(confirmed) = self
                 .is_transaction_handled_by_builder(
@@ -366,12 +436,16 @@ impl TransactionMonitorThread {
             }
 
             // increase fees for next attempt
-            // replacement requires 100% more for penalty
-            max_fee_per_gas += max_fee_per_gas;
-            max_priority_fee_per_gas += max_priority_fee_per_gas;
-            if let Some(max_fee_per_blob_gas) = &mut max_fee_per_blob_gas {
-                *max_fee_per_blob_gas += *max_fee_per_blob_gas;
-            }
+            (
+                max_fee_per_gas,
+                max_priority_fee_per_gas,
+                max_fee_per_blob_gas,
+            ) = bump_tx_fees(
+                max_fee_per_gas,
+                max_priority_fee_per_gas,
+                max_fee_per_blob_gas,
+                self.config.fee_bump_basis_points,
+            );
         }
 
         //Wait for transaction result
@@ -461,7 +535,9 @@ impl TransactionMonitorThread {
                     return Some(false);
                 }
             };
-            if current_l1_height != l1_block_at_send {
+            if current_l1_height
+                >= l1_block_at_send.saturating_add(self.config.resubmit_after_slots)
+            {
                 break;
             }
             debug!(
@@ -528,6 +604,11 @@ impl TransactionMonitorThread {
                         .await;
                 }
                 return;
+            } else if let Some(revert_data) = err.as_revert_data() {
+                let error = TransactionError::from_revert_data(&revert_data);
+                error!("Failed to send transaction: {}. {}", error, e);
+                self.send_error_signal(error).await;
+                return;
             } else if let Some(error) = tools::convert_error_payload(&err.message) {
                 error!("Failed to send transaction: {}", error);
                 self.send_error_signal(error).await;
@@ -559,6 +640,7 @@ impl TransactionMonitorThread {
                 );
                 self.metrics.observe_batch_propose_tries(sending_attempt);
                 self.metrics.inc_batch_confirmed();
+                self.observe_basefee_estimation_error(block_number).await;
                 return true;
             }
         }
@@ -598,6 +680,7 @@ impl TransactionMonitorThread {
                     );
                     self.metrics.observe_batch_propose_tries(sending_attempt);
                     self.metrics.inc_batch_confirmed();
+                    self.observe_basefee_estimation_error(block_number).await;
                     TxStatus::Confirmed
                 } else if let Some(block_number) = receipt.block_number() {
                     let revert_reason = crate::shared::alloy_tools::check_for_revert_reason(
@@ -632,6 +715,42 @@ impl TransactionMonitorThread {
         }
     }
 
+    async fn get_block_base_fee(&self, block: BlockNumberOrTag) -> Option<u64> {
+        match self.provider.get_block_by_number(block).await {
+            Ok(Some(block)) => block.header.base_fee_per_gas,
+            Ok(None) => {
+                warn!("Block {} not found when reading base fee", block);
+                None
+            }
+            Err(e) => {
+                warn!("Failed to get block {} to read base fee: {}", block, e);
+                None
+            }
+        }
+    }
+
+    async fn observe_basefee_estimation_error(&self, inclusion_block: u64) {
+        let Some(build_base_fee) = self.build_base_fee else {
+            return;
+        };
+        let Some(inclusion_base_fee) = self
+            .get_block_base_fee(BlockNumberOrTag::Number(inclusion_block))
+            .await
+        else {
+            return;
+        };
+        if let Some(error_percent) =
+            basefee_estimation_error_percent(build_base_fee, inclusion_base_fee)
+        {
+            debug!(
+                "L1 base fee at build: {}, at inclusion: {}, error: {:.2}%",
+                build_base_fee, inclusion_base_fee, error_percent
+            );
+            self.metrics
+                .observe_l1_basefee_estimation_error(error_percent);
+        }
+    }
+
     fn set_tx_parameters(
         &self,
         tx: &mut TransactionRequest,
@@ -656,3 +775,101 @@ impl TransactionMonitorThread {
         );
     }
 }
+
+/// Fees of a replacement transaction, each raised by `bump_basis_points`.
+/// The blob pool only accepts a replacement blob transaction paying at least double the fees,
+/// so blob transactions are bumped by at least 100%.
+pub(crate) fn bump_tx_fees(
+    max_fee_per_gas: u128,
+    max_priority_fee_per_gas: u128,
+    max_fee_per_blob_gas: Option<u128>,
+    bump_basis_points: u128,
+) -> (u128, u128, Option<u128>) {
+    let bump_basis_points = if max_fee_per_blob_gas.is_some() {
+        bump_basis_points.max(10_000)
+    } else {
+        bump_basis_points
+    };
+    let bump = |fee: u128| fee + (fee * bump_basis_points).div_ceil(10_000);
+    (
+        bump(max_fee_per_gas),
+        bump(max_priority_fee_per_gas),
+        max_fee_per_blob_gas.map(bump),
+    )
+}
+
+/// Relative difference in percent between the base fee of the inclusion block
+/// and the base fee assumed when the transaction was built.
+#[allow(clippy::cast_precision_loss)]
+fn basefee_estimation_error_percent(build_base_fee: u64, inclusion_base_fee: u64) -> Option<f64> {
+    if build_base_fee == 0 {
+        return None;
+    }
+    Some((inclusion_base_fee as f64 - build_base_fee as f64) / build_base_fee as f64 * 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_basefee_estimation_error_percent() {
+        // Base fee rose by the maximum 12.5% per block
+        assert_eq!(
+            basefee_estimation_error_percent(1_000_000_000, 1_125_000_000),
+            Some(12.5)
+        );
+        // Base fee dropped
+        assert_eq!(
+            basefee_estimation_error_percent(2_000_000_000, 1_500_000_000),
+            Some(-25.0)
+        );
+        assert_eq!(
+            basefee_estimation_error_percent(1_000_000_000, 1_000_000_000),
+            Some(0.0)
+        );
+        assert_eq!(basefee_estimation_error_percent(0, 1_000_000_000), None);
+    }
+
+    #[test]
+    fn test_fee_bump_sequence_for_never_mined_tx() {
+        // Every attempt of a pending transaction is replaced with 12.5% higher fees
+        let mut fees = (1_000_000_000, 100_000_000, None);
+        let mut sequence = vec![fees];
+        for _ in 1..4 {
+            fees = bump_tx_fees(fees.0, fees.1, fees.2, 1_250);
+            sequence.push(fees);
+        }
+        assert_eq!(
+            sequence,
+            vec![
+                (1_000_000_000, 100_000_000, None),
+                (1_125_000_000, 112_500_000, None),
+                (1_265_625_000, 126_562_500, None),
+                (1_423_828_125, 142_382_813, None),
+            ]
+        );
+
+        // Blob transactions double their fees
+        assert_eq!(
+            bump_tx_fees(1_000, 100, Some(10), 1_250),
+            (2_000, 200, Some(20))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_log_submitted_tx_hash() {
+        let (sender, receiver) = tokio::sync::oneshot::channel();
+        let tx_hash = B256::repeat_byte(0xab);
+        sender.send(tx_hash).unwrap();
+        assert_eq!(
+            log_submitted_tx_hash(receiver, "Proposal").await,
+            Some(tx_hash)
+        );
+
+        // Transaction never sent
+        let (sender, receiver) = tokio::sync::oneshot::channel::<B256>();
+        drop(sender);
+        assert_eq!(log_submitted_tx_hash(receiver, "Proposal").await, None);
+    }
+}
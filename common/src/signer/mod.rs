@@ -43,4 +43,12 @@ impl Signer {
             Signer::PrivateKey(_, address) => *address,
         }
     }
+
+    /// A local private key is always available, Web3Signer is probed.
+    pub async fn is_reachable(&self) -> bool {
+        match self {
+            Signer::Web3signer(web3signer, _) => web3signer.is_reachable().await,
+            Signer::PrivateKey(_, _) => true,
+        }
+    }
 }
@@ -56,6 +56,14 @@ impl Web3Signer {
             .any(|account| account == signer_address.to_lowercase()))
     }
 
+    /// Returns true if Web3Signer answers a request, without retrying.
+    pub async fn is_reachable(&self) -> bool {
+        self.client
+            .call_method("eth_accounts", vec![])
+            .await
+            .is_ok()
+    }
+
     pub async fn sign_transaction(
         &self,
         tx: &dyn Transaction,
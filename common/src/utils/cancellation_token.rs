@@ -35,4 +35,14 @@ impl CancellationToken {
     pub fn cancelled(&self) -> tokio_util::sync::WaitForCancellationFuture<'_> {
         self.cancel_token.cancelled()
     }
+
+    /// Runs the future to completion unless the token is cancelled first, in which case
+    /// the future is dropped and None is returned.
+    pub async fn run_until_cancelled<F: Future>(&self, fut: F) -> Option<F::Output> {
+        tokio::select! {
+            biased;
+            _ = self.cancel_token.cancelled() => None,
+            output = fut => Some(output),
+        }
+    }
 }
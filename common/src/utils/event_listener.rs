@@ -1,3 +1,4 @@
+use crate::metrics::Metrics;
 use crate::shared::alloy_tools;
 use crate::utils::cancellation_token::CancellationToken;
 use alloy::{
@@ -8,6 +9,7 @@ use alloy::{
 };
 use anyhow::Error;
 use futures_util::StreamExt;
+use std::sync::Arc;
 use tokio::{
     select,
     sync::mpsc::Sender,
@@ -15,8 +17,6 @@ use tokio::{
 };
 use tracing::{debug, error, info, warn};
 
-const MAX_BLOCKS_PER_POLL: u64 = 10;
-
 pub struct EventListenerConfig {
     pub rpc_url: String,
     pub contract_address: Address,
@@ -24,6 +24,9 @@ pub struct EventListenerConfig {
     pub signature_hash: B256,
     pub reconnect_timeout: Duration,
     pub poll_interval: Duration,
+    /// Maximum block range of a single `get_logs` request while polling
+    pub log_chunk_blocks: u64,
+    pub metrics: Arc<Metrics>,
 }
 
 pub async fn listen_for_event<T>(
@@ -41,6 +44,8 @@ pub async fn listen_for_event<T>(
         signature_hash,
         reconnect_timeout,
         poll_interval,
+        log_chunk_blocks,
+        metrics,
     } = config;
 
     loop {
@@ -74,11 +79,15 @@ pub async fn listen_for_event<T>(
                 run_polling_loop(
                     &provider,
                     filter,
-                    event_name,
+                    PollingConfig {
+                        event_name,
+                        poll_interval,
+                        log_chunk_blocks,
+                        metrics: &metrics,
+                    },
                     to_event,
                     &sender_tx,
                     &cancel_token,
-                    poll_interval,
                 )
                 .await
             }
@@ -126,20 +135,42 @@ where
     }
 }
 
+struct PollingConfig<'a> {
+    event_name: &'static str,
+    poll_interval: Duration,
+    log_chunk_blocks: u64,
+    metrics: &'a Metrics,
+}
+
+/// Returns the block range of the next `get_logs` request, at most `chunk_blocks` long.
+fn next_log_range(next_block: u64, latest_block: u64, chunk_blocks: u64) -> (u64, u64) {
+    let to_block = next_block
+        .saturating_add(chunk_blocks.max(1) - 1)
+        .min(latest_block);
+    (next_block, to_block)
+}
+
 /// Polls for new logs over HTTP until an error occurs or is cancelled.
+/// A gap larger than one chunk is scanned in consecutive chunks, so no events are skipped.
 /// Returns `true` to trigger a reconnect, `false` on cancellation.
 async fn run_polling_loop<T>(
     provider: &DynProvider,
     filter: Filter,
-    event_name: &'static str,
+    config: PollingConfig<'_>,
     to_event: fn(Log) -> Result<T, Error>,
     sender_tx: &Sender<T>,
     cancel_token: &CancellationToken,
-    poll_interval: Duration,
 ) -> bool
 where
     T: Send + SolEvent,
 {
+    let PollingConfig {
+        event_name,
+        poll_interval,
+        log_chunk_blocks,
+        metrics,
+    } = config;
+
     let mut next_block = match provider.get_block_number().await {
         Ok(n) => n.saturating_add(1),
         Err(e) => {
@@ -170,34 +201,42 @@ where
             continue;
         }
 
-        if latest_block - next_block > MAX_BLOCKS_PER_POLL {
+        if latest_block - next_block >= log_chunk_blocks {
             debug!(
-                "{event_name}: gap too large: latest={latest_block}, next={next_block}, fetching last {MAX_BLOCKS_PER_POLL}"
+                "{event_name}: catching up from block {next_block} to {latest_block} in chunks of {log_chunk_blocks} blocks"
             );
-            next_block = latest_block - MAX_BLOCKS_PER_POLL;
         }
 
-        let logs = match provider
-            .get_logs(&filter.clone().from_block(next_block).to_block(latest_block))
-            .await
-        {
-            Ok(logs) => logs,
-            Err(e) => {
-                error!("{event_name}: failed to fetch logs: {e:?}");
-                return true;
+        while next_block <= latest_block {
+            if cancel_token.is_cancelled() {
+                info!("{event_name}: cancellation received, stopping polling");
+                return false;
             }
-        };
 
-        for log in logs {
-            if dispatch_log(log, event_name, to_event, sender_tx)
+            let (from_block, to_block) = next_log_range(next_block, latest_block, log_chunk_blocks);
+            let logs = match provider
+                .get_logs(&filter.clone().from_block(from_block).to_block(to_block))
                 .await
-                .is_err()
             {
-                return true;
+                Ok(logs) => logs,
+                Err(e) => {
+                    error!("{event_name}: failed to fetch logs: {e:?}");
+                    return true;
+                }
+            };
+
+            for log in logs {
+                if dispatch_log(log, event_name, to_event, sender_tx)
+                    .await
+                    .is_err()
+                {
+                    return true;
+                }
             }
-        }
 
-        next_block = latest_block.saturating_add(1);
+            next_block = to_block.saturating_add(1);
+            metrics.set_chain_monitor_catchup_remaining_blocks(event_name, latest_block - to_block);
+        }
     }
 }
 
@@ -220,3 +259,26 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_ranges_cover_gap_larger_than_chunk() {
+        let mut ranges = vec![];
+        let mut next_block = 100;
+        while next_block <= 349 {
+            let (from_block, to_block) = next_log_range(next_block, 349, 100);
+            ranges.push((from_block, to_block));
+            next_block = to_block + 1;
+        }
+        assert_eq!(ranges, vec![(100, 199), (200, 299), (300, 349)]);
+    }
+
+    #[test]
+    fn test_log_range_within_chunk() {
+        assert_eq!(next_log_range(100, 105, 100), (100, 105));
+        assert_eq!(next_log_range(100, 100, 100), (100, 100));
+    }
+}
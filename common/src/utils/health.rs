@@ -0,0 +1,88 @@
+/// Health of the individual components the node depends on, as observed in one heartbeat.
+#[derive(Debug, Clone, Copy)]
+pub struct ComponentHealth {
+    pub driver_synced: bool,
+    pub geth_reachable: bool,
+    pub l1_rpc_reachable: bool,
+    pub signer_reachable: bool,
+    pub funds_above_threshold: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverallHealth {
+    Unhealthy = 0,
+    Degraded = 1,
+    Healthy = 2,
+}
+
+impl ComponentHealth {
+    /// Without Geth, L1 or the signer the node can neither preconfirm nor submit, so any of them
+    /// being unreachable is unhealthy. An unsynced driver or low funds only degrade the node.
+    pub fn overall(&self) -> OverallHealth {
+        if !self.geth_reachable || !self.l1_rpc_reachable || !self.signer_reachable {
+            OverallHealth::Unhealthy
+        } else if !self.driver_synced || !self.funds_above_threshold {
+            OverallHealth::Degraded
+        } else {
+            OverallHealth::Healthy
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overall_health_for_each_unhealthy_component() {
+        let healthy = ComponentHealth {
+            driver_synced: true,
+            geth_reachable: true,
+            l1_rpc_reachable: true,
+            signer_reachable: true,
+            funds_above_threshold: true,
+        };
+        assert_eq!(healthy.overall(), OverallHealth::Healthy);
+
+        let cases = [
+            (
+                ComponentHealth {
+                    driver_synced: false,
+                    ..healthy
+                },
+                OverallHealth::Degraded,
+            ),
+            (
+                ComponentHealth {
+                    geth_reachable: false,
+                    ..healthy
+                },
+                OverallHealth::Unhealthy,
+            ),
+            (
+                ComponentHealth {
+                    l1_rpc_reachable: false,
+                    ..healthy
+                },
+                OverallHealth::Unhealthy,
+            ),
+            (
+                ComponentHealth {
+                    signer_reachable: false,
+                    ..healthy
+                },
+                OverallHealth::Unhealthy,
+            ),
+            (
+                ComponentHealth {
+                    funds_above_threshold: false,
+                    ..healthy
+                },
+                OverallHealth::Degraded,
+            ),
+        ];
+        for (components, expected) in cases {
+            assert_eq!(components.overall(), expected, "{components:?}");
+        }
+    }
+}
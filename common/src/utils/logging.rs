@@ -1,3 +1,8 @@
+use crate::l1::slot_clock::{Clock, SlotClock};
+use crate::utils::cancellation_token::CancellationToken;
+use std::io::Write;
+use std::time::Duration;
+use tracing::{error, info};
 use tracing_subscriber::{EnvFilter, filter::FilterFn, fmt, prelude::*};
 
 pub fn init_logging() {
@@ -97,3 +102,117 @@ pub fn init_logging() {
 
     subscriber.init();
 }
+
+/// Flushes the log output, which is written to stdout.
+pub fn flush_logs() {
+    if let Err(err) = std::io::stdout().flush() {
+        eprintln!("Failed to flush log output: {err}");
+    }
+}
+
+/// Installs a panic hook which cancels the node once the panic is logged and flushed.
+pub fn set_panic_hook(cancel_token: CancellationToken, flush_delay: Duration) {
+    std::panic::set_hook(Box::new(move |panic_info| {
+        handle_panic(panic_info, &cancel_token, flush_delay, flush_logs);
+    }));
+}
+
+/// Logs the panic, flushes the log output and waits `flush_delay` before cancelling,
+/// so the panic context is not truncated by the shutdown.
+pub fn handle_panic(
+    panic_info: &dyn std::fmt::Debug,
+    cancel_token: &CancellationToken,
+    flush_delay: Duration,
+    flush: impl FnOnce(),
+) {
+    error!("Panic occurred: {:?}", panic_info);
+    flush();
+    if !flush_delay.is_zero() {
+        std::thread::sleep(flush_delay);
+    }
+    cancel_token.cancel_on_critical_error();
+    info!("Cancellation token triggered, initiating shutdown...");
+}
+
+/// Creates a span covering one heartbeat, so every log emitted while handling it
+/// (block creation, submission, verification) carries the same slot identifiers.
+pub fn heartbeat_span<T: Clock>(slot_clock: &SlotClock<T>) -> tracing::Span {
+    tracing::info_span!(
+        "heartbeat",
+        epoch = slot_clock.get_current_epoch().ok(),
+        l1_slot = slot_clock.get_current_slot_of_epoch().ok(),
+        l2_slot = slot_clock.get_current_l2_slot_within_l1_slot().ok(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::l1::slot_clock::mock::MockClock;
+    use std::sync::{Arc, Mutex};
+    use tracing::{
+        Subscriber,
+        field::{Field, Visit},
+        span::{Attributes, Id},
+    };
+    use tracing_subscriber::layer::{Context, Layer};
+
+    #[derive(Clone, Default)]
+    struct SpanFieldsLayer {
+        fields: Arc<Mutex<Vec<(String, String)>>>,
+    }
+
+    impl Visit for SpanFieldsLayer {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            self.fields
+                .lock()
+                .expect("fields lock poisoned")
+                .push((field.name().to_string(), format!("{value:?}")));
+        }
+    }
+
+    impl<S: Subscriber> Layer<S> for SpanFieldsLayer {
+        fn on_new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: Context<'_, S>) {
+            attrs.record(&mut self.clone());
+        }
+    }
+
+    #[test]
+    fn test_heartbeat_span_has_slot_fields() {
+        let mut slot_clock = SlotClock::<MockClock>::new(0, 0, 12, 32, 2000);
+        // epoch 1, 6th L1 slot of the epoch, 3rd L2 slot
+        slot_clock.clock.timestamp = (32 + 5) * 12 + 4;
+
+        let layer = SpanFieldsLayer::default();
+        let subscriber = tracing_subscriber::registry().with(layer.clone());
+        tracing::subscriber::with_default(subscriber, || {
+            let span = heartbeat_span(&slot_clock);
+            let _guard = span.enter();
+            tracing::info!("inside heartbeat");
+        });
+
+        let fields = layer.fields.lock().expect("fields lock poisoned").clone();
+        assert!(fields.contains(&("epoch".to_string(), "1".to_string())));
+        assert!(fields.contains(&("l1_slot".to_string(), "5".to_string())));
+        assert!(fields.contains(&("l2_slot".to_string(), "2".to_string())));
+    }
+
+    #[test]
+    fn test_panic_hook_cancels_after_flush() {
+        let cancel_token = CancellationToken::new(Arc::new(crate::metrics::Metrics::new()));
+        let flushed = std::cell::Cell::new(false);
+
+        handle_panic(
+            &"test panic",
+            &cancel_token,
+            Duration::from_millis(1),
+            || {
+                assert!(!cancel_token.is_cancelled(), "cancelled before the flush");
+                flushed.set(true);
+            },
+        );
+
+        assert!(flushed.get());
+        assert!(cancel_token.is_cancelled());
+    }
+}
@@ -1,10 +1,14 @@
 pub mod cancellation_token;
 pub mod event_listener;
 pub mod file_operations;
+pub mod health;
 pub mod logging;
 mod retry;
 pub mod rpc_client;
+pub mod rpc_health;
 pub mod rpc_server;
 pub mod synchronization;
+pub mod transient_error;
 pub mod types;
+pub mod warmup_error;
 pub mod watchdog;
@@ -1,4 +1,5 @@
 use super::retry::backoff_retry_with_timeout;
+use super::transient_error::{TransientHttpError, is_transient_error};
 use anyhow::Error;
 use http::{HeaderMap, HeaderValue};
 use jsonrpsee::{
@@ -8,6 +9,7 @@ use jsonrpsee::{
 use jsonwebtoken::{Algorithm, EncodingKey, Header, encode};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 use tokio::sync::RwLock;
 
@@ -152,7 +154,8 @@ impl JSONRPCClient {
         })
     }
 
-    async fn recreate_client(&self) -> Result<(), Error> {
+    /// Recreates the underlying HTTP client, refreshing the JWT token if one is used.
+    pub async fn recreate_client(&self) -> Result<(), Error> {
         let new_client = (if let Some(jwt_secret) = self.jwt_secret {
             Self::create_client_with_jwt(&self.url, self.timeout, &jwt_secret)
         } else {
@@ -232,11 +235,13 @@ impl HttpRPCClient {
     where
         T: serde::Serialize,
     {
+        let last_error_transient = AtomicBool::new(false);
         let result = backoff_retry_with_timeout(
             || async {
                 let response = self.request_json(method.clone(), endpoint, payload).await;
 
                 if let Err(ref e) = response {
+                    last_error_transient.store(is_transient_error(e), Ordering::Relaxed);
                     tracing::error!(
                         "Failed to call driver RPC for API '{}': {}. Retrying...",
                         endpoint,
@@ -254,7 +259,12 @@ impl HttpRPCClient {
         .await;
 
         result.map_err(|err| {
-            anyhow::anyhow!("Failed to call driver RPC for API '{}': {}", endpoint, err)
+            let msg = format!("Failed to call driver RPC for API '{endpoint}': {err}");
+            if last_error_transient.load(Ordering::Relaxed) {
+                Error::new(TransientHttpError(msg))
+            } else {
+                anyhow::anyhow!(msg)
+            }
         })
     }
 
@@ -281,9 +291,13 @@ impl HttpRPCClient {
             .await
             .map_err(|e| {
                 if e.is_timeout() {
-                    anyhow::anyhow!("HttpRPCClient: request timed out: {e}")
+                    Error::new(TransientHttpError(format!(
+                        "HttpRPCClient: request timed out: {e}"
+                    )))
                 } else {
-                    anyhow::anyhow!("HttpRPCClient: failed to send HTTP request: {e}")
+                    Error::new(TransientHttpError(format!(
+                        "HttpRPCClient: failed to send HTTP request: {e}"
+                    )))
                 }
             })?;
 
@@ -302,11 +316,16 @@ impl HttpRPCClient {
         }
 
         if !response.status().is_success() {
-            return Err(anyhow::anyhow!(
+            let status = response.status();
+            let msg = format!(
                 "HTTP request failed with status: {}, body: {}",
-                response.status(),
+                status,
                 response.text().await.unwrap_or_default()
-            ));
+            );
+            if status.is_server_error() {
+                return Err(Error::new(TransientHttpError(msg)));
+            }
+            return Err(anyhow::anyhow!(msg));
         }
 
         response
@@ -0,0 +1,147 @@
+use crate::{metrics::Metrics, shared::alloy_tools, utils::cancellation_token::CancellationToken};
+use alloy::providers::Provider;
+use anyhow::Error;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::{sleep, timeout};
+use tracing::{debug, info, warn};
+
+const RPC_HEALTH_PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Periodically probes every configured L1 and L2 RPC endpoint and reports how many of them
+/// are healthy, so a node running degraded on a subset of its endpoints can be alerted on
+/// before all of them fail.
+pub struct RpcHealthMonitor {
+    l1_rpc_urls: Vec<String>,
+    l2_rpc_urls: Vec<String>,
+    cancel_token: CancellationToken,
+    metrics: Arc<Metrics>,
+    monitor_interval: Duration,
+}
+
+impl RpcHealthMonitor {
+    pub fn new(
+        l1_rpc_urls: Vec<String>,
+        l2_rpc_urls: Vec<String>,
+        cancel_token: CancellationToken,
+        metrics: Arc<Metrics>,
+        monitor_interval_sec: u64,
+    ) -> Self {
+        Self {
+            l1_rpc_urls,
+            l2_rpc_urls,
+            cancel_token,
+            metrics,
+            monitor_interval: Duration::from_secs(monitor_interval_sec),
+        }
+    }
+
+    pub fn run(self) {
+        tokio::spawn(async move {
+            self.monitor_rpc_health().await;
+        });
+    }
+
+    async fn monitor_rpc_health(self) {
+        loop {
+            self.check_layer("l1", &self.l1_rpc_urls).await;
+            self.check_layer("l2", &self.l2_rpc_urls).await;
+            tokio::select! {
+                _ = sleep(self.monitor_interval) => {},
+                _ = self.cancel_token.cancelled() => {
+                    info!("Shutdown signal received, exiting RPC health monitor loop...");
+                    return;
+                }
+            }
+        }
+    }
+
+    async fn check_layer(&self, layer: &str, urls: &[String]) {
+        let healthy = count_healthy_endpoints(urls).await;
+        self.metrics
+            .set_rpc_endpoints_health(layer, healthy, urls.len());
+        if healthy < urls.len() {
+            warn!(
+                "Running degraded: {}/{} {} RPC endpoints healthy",
+                healthy,
+                urls.len(),
+                layer
+            );
+        }
+    }
+}
+
+/// Returns the number of endpoints that answered the health probe.
+pub async fn count_healthy_endpoints(urls: &[String]) -> usize {
+    let mut healthy = 0;
+    for url in urls {
+        match probe_endpoint(url).await {
+            Ok(block_number) => {
+                debug!("RPC endpoint {} healthy at block {}", url, block_number);
+                healthy += 1;
+            }
+            Err(e) => warn!("RPC endpoint {} failed health probe: {}", url, e),
+        }
+    }
+    healthy
+}
+
+async fn probe_endpoint(url: &str) -> Result<u64, Error> {
+    let block_number = timeout(RPC_HEALTH_PROBE_TIMEOUT, async {
+        let provider = alloy_tools::create_alloy_provider_without_wallet(url).await?;
+        provider
+            .get_block_number()
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to get block number: {e}"))
+    })
+    .await
+    .map_err(|_| anyhow::anyhow!("Timed out after {:?}", RPC_HEALTH_PROBE_TIMEOUT))??;
+    Ok(block_number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    async fn healthy_server() -> mockito::ServerGuard {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", "/")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body_from_request(|request| {
+                let body: Value = serde_json::from_slice(request.body().expect("request body"))
+                    .expect("valid JSON-RPC request");
+                serde_json::json!({"jsonrpc": "2.0", "id": body["id"], "result": "0x10"})
+                    .to_string()
+                    .into()
+            })
+            .create_async()
+            .await;
+        server
+    }
+
+    async fn failing_server() -> mockito::ServerGuard {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", "/")
+            .with_status(503)
+            .create_async()
+            .await;
+        server
+    }
+
+    #[tokio::test]
+    async fn test_count_healthy_endpoints_with_failing_endpoints() {
+        let healthy_a = healthy_server().await;
+        let healthy_b = healthy_server().await;
+        let failing = failing_server().await;
+
+        let urls = vec![healthy_a.url(), failing.url(), healthy_b.url()];
+        assert_eq!(count_healthy_endpoints(&urls).await, 2);
+
+        let urls = vec![failing.url()];
+        assert_eq!(count_healthy_endpoints(&urls).await, 0);
+    }
+}
@@ -0,0 +1,76 @@
+use alloy::transports::{RpcError, TransportErrorKind};
+use anyhow::Error;
+use jsonrpsee::core::client::Error as JsonRpcError;
+
+/// HTTP request failure which did not reach the server or was rejected with a 5xx status.
+#[derive(Debug)]
+pub struct TransientHttpError(pub String);
+
+impl std::fmt::Display for TransientHttpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for TransientHttpError {}
+
+/// Returns true if the error was caused by an RPC connectivity problem or a timeout,
+/// i.e. a condition which is likely to go away when the same operation is retried.
+/// Anything else, including errors without a recognizable source, is considered fatal.
+pub fn is_transient_error(err: &Error) -> bool {
+    err.chain().any(|cause| {
+        if let Some(err) = cause.downcast_ref::<RpcError<TransportErrorKind>>() {
+            return matches!(err, RpcError::Transport(_) | RpcError::NullResp);
+        }
+        if let Some(err) = cause.downcast_ref::<JsonRpcError>() {
+            return matches!(
+                err,
+                JsonRpcError::Transport(_)
+                    | JsonRpcError::RequestTimeout
+                    | JsonRpcError::RestartNeeded(_)
+            );
+        }
+        cause.is::<tokio::time::error::Elapsed>() || cause.is::<TransientHttpError>()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rpc_errors_are_transient() {
+        assert!(is_transient_error(&Error::from(
+            TransportErrorKind::backend_gone()
+        )));
+        assert!(is_transient_error(&Error::from(
+            JsonRpcError::RequestTimeout
+        )));
+        assert!(is_transient_error(
+            &Error::from(JsonRpcError::RequestTimeout).context("Failed to get L2 slot info")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_timeout_is_transient() {
+        let elapsed = tokio::time::timeout(std::time::Duration::ZERO, std::future::pending::<()>())
+            .await
+            .expect_err("pending future should time out");
+        assert!(is_transient_error(&Error::from(elapsed)));
+    }
+
+    #[test]
+    fn test_http_transient_error_is_transient() {
+        assert!(is_transient_error(
+            &Error::new(TransientHttpError("status 503".to_string()))
+                .context("Failed to advance head")
+        ));
+    }
+
+    #[test]
+    fn test_head_mismatch_is_fatal() {
+        assert!(!is_transient_error(&anyhow::anyhow!(
+            "Unexpected L2 head detected. Restarting node..."
+        )));
+    }
+}
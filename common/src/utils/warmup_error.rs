@@ -0,0 +1,49 @@
+use anyhow::Error;
+use std::time::Duration;
+
+/// Context attached to errors raised while warming up the node. Dependencies such as
+/// Taiko Geth usually need more time to come up, so the node is recreated after `retry_delay`
+/// instead of the regular runtime failure delay.
+#[derive(Debug, Clone, Copy)]
+pub struct WarmupError {
+    pub retry_delay: Duration,
+}
+
+impl std::fmt::Display for WarmupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Node warmup failed")
+    }
+}
+
+/// Returns how long to wait before recreating the node after it failed with `err`.
+pub fn recreate_node_delay(err: &Error, runtime_failure_delay: Duration) -> Duration {
+    err.downcast_ref::<WarmupError>()
+        .map_or(runtime_failure_delay, |warmup_error| {
+            warmup_error.retry_delay
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recreate_node_delay_by_failure_phase() {
+        let runtime_delay = Duration::from_secs(5);
+        let warmup_error = anyhow::anyhow!("Taiko Geth not ready")
+            .context(WarmupError {
+                retry_delay: Duration::from_secs(30),
+            })
+            .context("Failed to start Node");
+        assert_eq!(
+            recreate_node_delay(&warmup_error, runtime_delay),
+            Duration::from_secs(30)
+        );
+
+        let runtime_error = anyhow::anyhow!("Failed to create Node");
+        assert_eq!(
+            recreate_node_delay(&runtime_error, runtime_delay),
+            runtime_delay
+        );
+    }
+}
@@ -4,7 +4,7 @@ use common::{
     fork_info::{Fork, ForkInfo},
     metrics::{Metrics, metrics_route},
     shared::internal_server,
-    utils::cancellation_token::CancellationToken,
+    utils::{cancellation_token::CancellationToken, warmup_error::recreate_node_delay},
 };
 use std::sync::Arc;
 use tokio::signal::unix::{SignalKind, signal};
@@ -46,15 +46,17 @@ async fn main() -> Result<(), Error> {
                 continue;
             }
             Err(e) => {
-                error!("Failed to run node: {}", e);
+                error!("Failed to run node: {:#}", e);
                 metrics.inc_critical_errors();
+                let delay = recreate_node_delay(
+                    &e,
+                    tokio::time::Duration::from_secs(WAIT_BEFORE_RECREATING_NODE_SECS),
+                );
                 info!(
-                    "Waiting {WAIT_BEFORE_RECREATING_NODE_SECS} second before recreating node..."
+                    "Waiting {} second before recreating node...",
+                    delay.as_secs()
                 );
-                tokio::time::sleep(tokio::time::Duration::from_secs(
-                    WAIT_BEFORE_RECREATING_NODE_SECS,
-                ))
-                .await;
+                tokio::time::sleep(delay).await;
                 continue;
             }
         }
@@ -75,12 +77,10 @@ async fn run_node(iteration: u64, metrics: Arc<Metrics>) -> Result<ExecutionStop
     let cancel_token = CancellationToken::new(metrics.clone());
 
     // Set up panic hook to cancel token on panic
-    let panic_cancel_token = cancel_token.clone();
-    std::panic::set_hook(Box::new(move |panic_info| {
-        error!("Panic occurred: {:?}", panic_info);
-        panic_cancel_token.cancel_on_critical_error();
-        info!("Cancellation token triggered, initiating shutdown...");
-    }));
+    common::utils::logging::set_panic_hook(
+        cancel_token.clone(),
+        std::time::Duration::from_millis(config.panic_flush_delay_ms),
+    );
 
     let mut extra_routes: Vec<Router> = match fork_info.fork {
         Fork::Shasta => {
@@ -123,6 +123,8 @@ async fn run_node(iteration: u64, metrics: Arc<Metrics>) -> Result<ExecutionStop
         extra_routes,
         config.internal_server_ip,
         config.internal_server_port,
+        config.internal_server_bind_max_retries,
+        config.internal_server_required,
     );
 
     Ok(wait_for_the_termination(cancel_token, config.l1_slot_duration_sec).await)
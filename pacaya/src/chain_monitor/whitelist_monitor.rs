@@ -35,18 +35,9 @@ impl<T: WhitelistProvider + 'static> WhitelistMonitor<T> {
     }
 
     async fn monitor_whitelist(self) {
+        let mut was_whitelisted = None;
         loop {
-            match self.execution_layer.is_operator_whitelisted().await {
-                Ok(is_whitelisted) => {
-                    self.metrics.set_operator_whitelisted(is_whitelisted);
-                    if !is_whitelisted {
-                        warn!("Operator ejected from the whitelist");
-                    }
-                }
-                Err(e) => {
-                    error!("Failed to check if operator is whitelisted: {}", e);
-                }
-            }
+            was_whitelisted = self.check_whitelist(was_whitelisted).await;
             tokio::select! {
                 _ = sleep(self.monitor_interval) => {},
                 _ = self.cancel_token.cancelled() => {
@@ -56,4 +47,71 @@ impl<T: WhitelistProvider + 'static> WhitelistMonitor<T> {
             }
         }
     }
+
+    /// Checks whether our preconfer address is still whitelisted and reports changes
+    /// relative to the previous check. Returns the last known whitelist state.
+    async fn check_whitelist(&self, was_whitelisted: Option<bool>) -> Option<bool> {
+        match self.execution_layer.is_operator_whitelisted().await {
+            Ok(is_whitelisted) => {
+                self.metrics.set_operator_whitelisted(is_whitelisted);
+                match (was_whitelisted, is_whitelisted) {
+                    (Some(true), false) => error!("Operator has been removed from the whitelist"),
+                    (Some(false), true) => info!("Operator has been added back to the whitelist"),
+                    (_, false) => warn!("Operator ejected from the whitelist"),
+                    _ => {}
+                }
+                Some(is_whitelisted)
+            }
+            Err(e) => {
+                error!("Failed to check if operator is whitelisted: {}", e);
+                was_whitelisted
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Error;
+    use std::sync::Mutex;
+
+    struct MockWhitelist {
+        responses: Mutex<Vec<bool>>,
+    }
+
+    impl WhitelistProvider for MockWhitelist {
+        async fn is_operator_whitelisted(&self) -> Result<bool, Error> {
+            self.responses
+                .lock()
+                .expect("mock whitelist lock poisoned")
+                .pop()
+                .ok_or_else(|| anyhow::anyhow!("no more responses"))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_detects_removal_from_whitelist() {
+        let metrics = Arc::new(Metrics::new());
+        let monitor = WhitelistMonitor::new(
+            Arc::new(MockWhitelist {
+                // popped from the back: whitelisted first, then removed
+                responses: Mutex::new(vec![false, true]),
+            }),
+            CancellationToken::new(metrics.clone()),
+            metrics.clone(),
+            1,
+        );
+
+        let state = monitor.check_whitelist(None).await;
+        assert_eq!(state, Some(true));
+        assert!(metrics.gather().contains("operator_whitelisted 1"));
+
+        let state = monitor.check_whitelist(state).await;
+        assert_eq!(state, Some(false));
+        assert!(metrics.gather().contains("operator_whitelisted 0"));
+
+        // RPC failure keeps the last known state
+        assert_eq!(monitor.check_whitelist(state).await, Some(false));
+    }
 }
@@ -5,6 +5,7 @@ use alloy::{
 };
 use anyhow::Error;
 use std::sync::RwLock;
+use std::time::Duration;
 
 mod error;
 mod state;
@@ -18,18 +19,22 @@ const MAX_BLOCK_AGE_SECS: u64 = 60;
 /// Cached result of get_operators_for_current_and_next_epoch.
 /// Operators only change once per L1 slot (12s), so we avoid repeating the RPC call every L2 slot (2s).
 /// Key is current_slot_timestamp.
+/// Entries older than `max_age` are never served, so the operators are read again even when the
+/// slot clock stalls and the key does not change.
 pub struct OperatorsCache {
     cache: RwLock<Option<OperatorsCacheState>>,
     provider: DynProvider,
     whitelist_address: Address,
+    max_age: Duration,
 }
 
 impl OperatorsCache {
-    pub fn new(provider: DynProvider, whitelist_address: Address) -> Self {
+    pub fn new(provider: DynProvider, whitelist_address: Address, max_age: Duration) -> Self {
         Self {
             cache: RwLock::new(None),
             provider,
             whitelist_address,
+            max_age,
         }
     }
 
@@ -38,7 +43,13 @@ impl OperatorsCache {
         current_slot_timestamp: u64,
     ) -> Result<OperatorsCacheState, Error> {
         if let Some(cached) = self.read_cached_state() {
-            if cached.timestamp() == current_slot_timestamp {
+            if cached.age() > self.max_age {
+                tracing::warn!(
+                    "OperatorsCache: cached operators read {:?} ago exceed the max age of {:?}, forcing a fresh read",
+                    cached.age(),
+                    self.max_age
+                );
+            } else if cached.timestamp() == current_slot_timestamp {
                 return Ok(cached);
             } else if cached.timestamp().saturating_add(MAX_BLOCK_AGE_SECS) < current_slot_timestamp
             {
@@ -100,12 +111,20 @@ impl OperatorsCache {
         &self,
         current_slot_timestamp: u64,
     ) -> Result<OperatorsCacheState, Error> {
-        self.read_cached_state().ok_or_else(|| {
+        let cached = self.read_cached_state().ok_or_else(|| {
             anyhow::anyhow!(
                 "OperatorsCache: cache is empty, slot timestamp {}",
                 current_slot_timestamp
             )
-        })
+        })?;
+        if cached.age() > self.max_age {
+            return Err(anyhow::anyhow!(
+                "OperatorsCache: cached operators are stale ({:?} old), slot timestamp {}",
+                cached.age(),
+                current_slot_timestamp
+            ));
+        }
+        Ok(cached)
     }
 
     async fn get_operators_for_current_and_next_epoch_internal(
@@ -158,3 +177,46 @@ impl OperatorsCache {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::providers::ProviderBuilder;
+
+    #[tokio::test]
+    async fn test_stale_operators_are_read_again_when_the_epoch_clock_stalls() {
+        let mut server = mockito::Server::new_async().await;
+        let l1_read = server
+            .mock("POST", "/")
+            .with_status(503)
+            .expect_at_least(1)
+            .create_async()
+            .await;
+        let provider = ProviderBuilder::new()
+            .connect_http(server.url().parse().expect("valid mock server URL"))
+            .erased();
+        let cache = OperatorsCache::new(provider, Address::ZERO, Duration::from_millis(50));
+        cache.update_cache(OperatorsCacheState::new(
+            1000,
+            Address::repeat_byte(1),
+            Address::repeat_byte(2),
+        ));
+
+        // Slot clock stalled at the same slot timestamp, the fresh entry is served from the cache
+        let cached = cache
+            .get_operators_for_current_and_next_epoch(1000)
+            .await
+            .expect("cached operators");
+        assert_eq!(cached.current_operator(), Address::repeat_byte(1));
+
+        // Once stale, L1 is read again and the stale entry is not used as a fallback
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(
+            cache
+                .get_operators_for_current_and_next_epoch(1000)
+                .await
+                .is_err()
+        );
+        l1_read.assert_async().await;
+    }
+}
@@ -1,4 +1,6 @@
 use alloy::primitives::Address;
+use std::time::Duration;
+use tokio::time::Instant;
 
 #[derive(Clone, Debug)]
 pub struct Operators {
@@ -10,6 +12,7 @@ pub struct Operators {
 pub struct OperatorsCacheState {
     timestamp: u64,
     operators: Operators,
+    fetched_at: Instant,
 }
 
 impl OperatorsCacheState {
@@ -17,9 +20,15 @@ impl OperatorsCacheState {
         Self {
             timestamp,
             operators: Operators { current, next },
+            fetched_at: Instant::now(),
         }
     }
 
+    /// Time since the operators were read from L1.
+    pub fn age(&self) -> Duration {
+        self.fetched_at.elapsed()
+    }
+
     pub fn timestamp(&self) -> u64 {
         self.timestamp
     }
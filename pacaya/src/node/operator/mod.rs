@@ -8,6 +8,7 @@ use common::{
     fork_info::ForkInfo,
     l1::slot_clock::{Clock, SlotClock},
     l2::taiko_driver::{StatusProvider, models::TaikoStatus},
+    metrics::Metrics,
     shared::l2_slot_info::SlotData,
     utils::{cancellation_token::CancellationToken, types::*},
 };
@@ -31,6 +32,9 @@ pub struct Operator<T: PreconfOperator, U: Clock, V: StatusProvider> {
     current_operator_address: Address,
     last_ejection_timestamp: Option<u64>,
     ejection_grace_period_sec: u64,
+    driver_sync_tolerance_blocks: u64,
+    in_fork_transition: bool,
+    metrics: Arc<Metrics>,
 }
 
 impl<T: PreconfOperator, U: Clock, V: StatusProvider> Operator<T, U, V> {
@@ -45,7 +49,12 @@ impl<T: PreconfOperator, U: Clock, V: StatusProvider> Operator<T, U, V> {
         cancel_token: CancellationToken,
         fork_info: ForkInfo,
         ejection_grace_period_sec: u64,
+        driver_sync_tolerance_blocks: u64,
+        metrics: Arc<Metrics>,
     ) -> Result<Self, Error> {
+        let handover_window_slots = floor_handover_window_slots(handover_window_slots);
+        validate_handover_window_slots(handover_window_slots, slot_clock.get_slots_per_epoch())?;
+
         Ok(Self {
             execution_layer,
             slot_clock,
@@ -62,6 +71,9 @@ impl<T: PreconfOperator, U: Clock, V: StatusProvider> Operator<T, U, V> {
             current_operator_address: Address::ZERO,
             last_ejection_timestamp: None,
             ejection_grace_period_sec,
+            driver_sync_tolerance_blocks,
+            in_fork_transition: false,
+            metrics,
         })
     }
 
@@ -107,6 +119,9 @@ impl<T: PreconfOperator, U: Clock, V: StatusProvider> Operator<T, U, V> {
         if !preconfer {
             self.was_synced_preconfer = false;
         }
+        if preconfer && !is_driver_synced {
+            self.metrics.inc_preconfer_but_unsynced_slots();
+        }
 
         let submitter = self.is_submitter(current_operator, handover_window);
         #[cfg(feature = "get_status_duration")]
@@ -197,9 +212,18 @@ impl<T: PreconfOperator, U: Clock, V: StatusProvider> Operator<T, U, V> {
             .execution_layer
             .get_operators_for_current_and_next_epoch(current_slot_timestamp)
             .await?;
+        self.metrics.set_operator_status_age(op_cache.age());
 
         if op_cache.timestamp() < epoch_timestamp {
-            debug!("Operator check too early, using cached next operator");
+            // The operator set is not updated on L1 yet, so the previous epoch's
+            // next operator flag decides our role for the transition slots.
+            let was_current_operator = self.current_operator_address == my_address;
+            let role_flipped = self.next_operator != was_current_operator;
+            debug!(
+                "Operator check too early, using cached next operator (role flipped: {})",
+                role_flipped
+            );
+            self.metrics.inc_operator_transition_slot(role_flipped);
             return Ok(self.next_operator);
         };
 
@@ -241,7 +265,17 @@ impl<T: PreconfOperator, U: Clock, V: StatusProvider> Operator<T, U, V> {
     }
 
     fn is_l2_slot_before_handover_window(&self, l1_slot: Slot) -> Result<bool, Error> {
-        let end_l1_slot = self.slot_clock.get_slots_per_epoch() - self.handover_window_slots - 1;
+        let end_l1_slot = self
+            .slot_clock
+            .get_slots_per_epoch()
+            .checked_sub(self.handover_window_slots + 1)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Handover window slots ({}) must be less than slots per epoch ({})",
+                    self.handover_window_slots,
+                    self.slot_clock.get_slots_per_epoch()
+                )
+            })?;
         if l1_slot == end_l1_slot {
             let l2_slot = self.slot_clock.get_current_l2_slot_within_l1_slot()?;
             Ok(l2_slot + 1 == self.slot_clock.get_number_of_l2_slots_per_l1())
@@ -284,12 +318,21 @@ impl<T: PreconfOperator, U: Clock, V: StatusProvider> Operator<T, U, V> {
         l2_slot_info: &S,
         driver_status: &TaikoStatus,
     ) -> Result<bool, Error> {
-        if self
-            .fork_info
-            .is_fork_switch_transition_period(std::time::Duration::from_secs(
-                l2_slot_info.slot_timestamp(),
-            ))
-        {
+        let in_fork_transition =
+            self.fork_info
+                .is_fork_switch_transition_period(std::time::Duration::from_secs(
+                    l2_slot_info.slot_timestamp(),
+                ));
+        if in_fork_transition != self.in_fork_transition {
+            if in_fork_transition {
+                info!("Entering fork switch transition period, preconfirmation is suspended");
+            } else {
+                info!("Fork switch transition period is over, preconfirmation is resumed");
+            }
+            self.in_fork_transition = in_fork_transition;
+        }
+        if in_fork_transition {
+            self.metrics.inc_fork_transition_skipped_slots();
             return Ok(false);
         }
 
@@ -386,7 +429,11 @@ impl<T: PreconfOperator, U: Clock, V: StatusProvider> Operator<T, U, V> {
             );
         }
 
-        Ok(taiko_geth_height == status.highest_unsafe_l2_payload_block_id)
+        Ok(is_driver_height_within_tolerance(
+            taiko_geth_height,
+            status.highest_unsafe_l2_payload_block_id,
+            self.driver_sync_tolerance_blocks,
+        ))
     }
 
     async fn is_taiko_geth_synced_with_l1<S: SlotData>(
@@ -401,3 +448,43 @@ impl<T: PreconfOperator, U: Clock, V: StatusProvider> Operator<T, U, V> {
         Ok(l2_slot_info.parent_id() >= taiko_inbox_height)
     }
 }
+
+/// Checks that the driver's highest unsafe payload is at most `tolerance_blocks` away from Taiko Geth.
+pub fn is_driver_height_within_tolerance(
+    taiko_geth_height: u64,
+    driver_height: u64,
+    tolerance_blocks: u64,
+) -> bool {
+    taiko_geth_height.abs_diff(driver_height) <= tolerance_blocks
+}
+
+/// Smallest handover window the operator runs with. An empty window would never
+/// report a handover slot, so the outgoing operator would keep its role to the end of the epoch.
+pub const MIN_HANDOVER_WINDOW_SLOTS: u64 = 1;
+
+/// Raises a zero handover window to `MIN_HANDOVER_WINDOW_SLOTS`.
+pub fn floor_handover_window_slots(handover_window_slots: u64) -> u64 {
+    if handover_window_slots < MIN_HANDOVER_WINDOW_SLOTS {
+        warn!(
+            "Handover window slots is {}, using the minimum of {} slot(s) instead",
+            handover_window_slots, MIN_HANDOVER_WINDOW_SLOTS
+        );
+        return MIN_HANDOVER_WINDOW_SLOTS;
+    }
+    handover_window_slots
+}
+
+/// Checks that the handover window fits into an epoch, leaving at least one slot before it.
+pub fn validate_handover_window_slots(
+    handover_window_slots: u64,
+    slots_per_epoch: u64,
+) -> Result<(), Error> {
+    if handover_window_slots >= slots_per_epoch {
+        return Err(anyhow::anyhow!(
+            "HANDOVER_WINDOW_SLOTS ({}) must be less than slots per epoch ({})",
+            handover_window_slots,
+            slots_per_epoch
+        ));
+    }
+    Ok(())
+}
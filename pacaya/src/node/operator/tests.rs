@@ -574,6 +574,41 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_transition_slot_metrics() {
+        // Inside the transition window, becoming the operator
+        let mut operator = create_operator_with_error_in_execution_layer(Arc::new(
+            ExecutionLayerMockErrorToEarly {},
+        ));
+        operator.get_status(&get_l2_slot_info()).await.unwrap();
+        let metrics = operator.metrics.gather();
+        assert!(
+            metrics.contains("catalyst_operator_transition_slots_total{outcome=\"flipped\"} 1")
+        );
+
+        // Inside the transition window, continuing as the operator
+        let mut operator = create_operator_with_error_in_execution_layer(Arc::new(
+            ExecutionLayerMockErrorToEarly {},
+        ));
+        operator.current_operator_address = PRECONFER_ADDRESS;
+        operator.get_status(&get_l2_slot_info()).await.unwrap();
+        let metrics = operator.metrics.gather();
+        assert!(
+            metrics.contains("catalyst_operator_transition_slots_total{outcome=\"unchanged\"} 1")
+        );
+        assert!(!metrics.contains("outcome=\"flipped\""));
+
+        // Outside the transition window
+        let mut operator = create_operator(5 * 12, true, false);
+        operator.get_status(&get_l2_slot_info()).await.unwrap();
+        assert!(
+            !operator
+                .metrics
+                .gather()
+                .contains("catalyst_operator_transition_slots_total")
+        );
+    }
+
     #[tokio::test]
     async fn test_get_l1_submitter_status() {
         // Current operator but not next operator during handover window
@@ -716,6 +751,35 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_fork_transition_skipped_slots_metric() {
+        // fork switch timestamp is 100 seconds, transition period is 15 seconds
+        const INSIDE_TIMESTAMP: u64 = 90;
+        let mut operator = create_operator_with_fork_switch_transition_period(INSIDE_TIMESTAMP);
+        let l2_slot_info = L2SlotInfo::new(0, INSIDE_TIMESTAMP, 0, get_test_hash(), 0, 0);
+        operator.get_status(&l2_slot_info).await.unwrap();
+        operator.get_status(&l2_slot_info).await.unwrap();
+        assert!(operator.in_fork_transition);
+        assert!(
+            operator
+                .metrics
+                .gather()
+                .contains("catalyst_fork_transition_skipped_slots_total 2")
+        );
+
+        const OUTSIDE_TIMESTAMP: u64 = 50;
+        let mut operator = create_operator_with_fork_switch_transition_period(OUTSIDE_TIMESTAMP);
+        let l2_slot_info = L2SlotInfo::new(0, OUTSIDE_TIMESTAMP, 0, get_test_hash(), 0, 0);
+        operator.get_status(&l2_slot_info).await.unwrap();
+        assert!(!operator.in_fork_transition);
+        assert!(
+            operator
+                .metrics
+                .gather()
+                .contains("catalyst_fork_transition_skipped_slots_total 0")
+        );
+    }
+
     #[tokio::test]
     async fn test_get_status_in_grace_period_after_ejection() {
         const TIMESTAMP: u64 = 2 * 12;
@@ -758,6 +822,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_validate_handover_window_slots() {
+        assert!(validate_handover_window_slots(31, 32).is_ok());
+        assert!(validate_handover_window_slots(32, 32).is_err());
+        assert!(validate_handover_window_slots(33, 32).is_err());
+    }
+
+    #[test]
+    fn test_driver_height_within_tolerance() {
+        assert!(is_driver_height_within_tolerance(100, 100, 0));
+        assert!(!is_driver_height_within_tolerance(100, 99, 0));
+        assert!(is_driver_height_within_tolerance(100, 99, 1));
+        assert!(!is_driver_height_within_tolerance(100, 98, 1));
+    }
+
+    #[test]
+    fn test_zero_handover_window_is_floored() {
+        let mut slot_clock = SlotClock::<MockClock>::new(0, 0, 12, 32, 2000);
+        slot_clock.clock.timestamp = 31 * 12; // last slot of epoch
+        let operator = Operator::new(
+            Arc::new(ExecutionLayerMock {
+                current_operator_address: PRECONFER_ADDRESS,
+                next_operator_address: OTHER_OPERATOR_ADDRESS,
+                taiko_inbox_height: 0,
+            }),
+            Arc::new(slot_clock),
+            Arc::new(TaikoMock {
+                end_of_sequencing_block_hash: B256::ZERO,
+            }),
+            0,
+            1000,
+            false,
+            CancellationToken::new(Arc::new(Metrics::new())),
+            ForkInfo::default(),
+            4,
+            0,
+            Arc::new(Metrics::new()),
+        )
+        .unwrap();
+
+        assert_eq!(operator.handover_window_slots, MIN_HANDOVER_WINDOW_SLOTS);
+        assert!(operator.is_handover_window(31));
+        assert!(!operator.is_handover_window(30));
+        assert_eq!(floor_handover_window_slots(6), 6);
+    }
+
+    #[tokio::test]
+    async fn test_get_status_with_handover_window_not_fitting_epoch() {
+        for handover_window_slots in [32, 33] {
+            let mut operator = create_operator(5 * 12, true, false);
+            operator.handover_window_slots = handover_window_slots;
+            assert!(operator.get_status(&get_l2_slot_info()).await.is_err());
+        }
+    }
+
     fn create_operator(
         timestamp: u64,
         current_operator: bool,
@@ -770,6 +889,7 @@ mod tests {
         Operator {
             fork_info: ForkInfo::default(),
             cancel_token: CancellationToken::new(Arc::new(Metrics::new())),
+            metrics: Arc::new(Metrics::new()),
             cancel_counter: 0,
             taiko: Arc::new(TaikoMock {
                 end_of_sequencing_block_hash: B256::ZERO,
@@ -789,6 +909,8 @@ mod tests {
             current_operator_address: Address::ZERO,
             last_ejection_timestamp: None,
             ejection_grace_period_sec: 4,
+            driver_sync_tolerance_blocks: 0,
+            in_fork_transition: false,
         }
     }
 
@@ -818,6 +940,7 @@ mod tests {
         Operator {
             fork_info: ForkInfo::default(),
             cancel_token: CancellationToken::new(Arc::new(Metrics::new())),
+            metrics: Arc::new(Metrics::new()),
             taiko: Arc::new(TaikoMock {
                 end_of_sequencing_block_hash: get_test_hash(),
             }),
@@ -837,6 +960,8 @@ mod tests {
             current_operator_address: Address::ZERO,
             last_ejection_timestamp: None,
             ejection_grace_period_sec: 4,
+            driver_sync_tolerance_blocks: 0,
+            in_fork_transition: false,
         }
     }
 
@@ -852,6 +977,7 @@ mod tests {
         Operator {
             fork_info: ForkInfo::default(),
             cancel_token: CancellationToken::new(Arc::new(Metrics::new())),
+            metrics: Arc::new(Metrics::new()),
             taiko: Arc::new(TaikoUnsyncedMock {
                 end_of_sequencing_block_hash: get_test_hash(),
             }),
@@ -871,6 +997,8 @@ mod tests {
             current_operator_address: Address::ZERO,
             last_ejection_timestamp: None,
             ejection_grace_period_sec: 4,
+            driver_sync_tolerance_blocks: 0,
+            in_fork_transition: false,
         }
     }
 
@@ -880,6 +1008,7 @@ mod tests {
         Operator {
             fork_info: ForkInfo::default(),
             cancel_token: CancellationToken::new(Arc::new(Metrics::new())),
+            metrics: Arc::new(Metrics::new()),
             cancel_counter: 0,
             taiko: Arc::new(TaikoMock {
                 end_of_sequencing_block_hash: B256::ZERO,
@@ -899,6 +1028,8 @@ mod tests {
             current_operator_address: Address::ZERO,
             last_ejection_timestamp: None,
             ejection_grace_period_sec: 4,
+            driver_sync_tolerance_blocks: 0,
+            in_fork_transition: false,
         }
     }
 
@@ -910,6 +1041,7 @@ mod tests {
         Operator {
             fork_info: ForkInfo::default(),
             cancel_token: CancellationToken::new(Arc::new(Metrics::new())),
+            metrics: Arc::new(Metrics::new()),
             cancel_counter: 0,
             taiko: Arc::new(TaikoMock {
                 end_of_sequencing_block_hash: B256::ZERO,
@@ -925,6 +1057,8 @@ mod tests {
             current_operator_address: Address::ZERO,
             last_ejection_timestamp: None,
             ejection_grace_period_sec: 4,
+            driver_sync_tolerance_blocks: 0,
+            in_fork_transition: false,
         }
     }
 
@@ -945,9 +1079,11 @@ mod tests {
                         Duration::from_secs(100), // Permissionless
                     ],
                     fork_switch_transition_period: Duration::from_secs(15),
+                    fork_switch_grace_period: Duration::ZERO,
                 },
             },
             cancel_token: CancellationToken::new(Arc::new(Metrics::new())),
+            metrics: Arc::new(Metrics::new()),
             cancel_counter: 0,
             taiko: Arc::new(TaikoMock {
                 end_of_sequencing_block_hash: B256::ZERO,
@@ -967,6 +1103,8 @@ mod tests {
             current_operator_address: Address::ZERO,
             last_ejection_timestamp: None,
             ejection_grace_period_sec: 4,
+            driver_sync_tolerance_blocks: 0,
+            in_fork_transition: false,
         }
     }
 
@@ -980,6 +1118,7 @@ mod tests {
         Operator {
             fork_info: ForkInfo::default(),
             cancel_token: CancellationToken::new(Arc::new(Metrics::new())),
+            metrics: Arc::new(Metrics::new()),
             cancel_counter: 0,
             taiko: Arc::new(TaikoMock {
                 end_of_sequencing_block_hash: B256::ZERO,
@@ -999,6 +1138,8 @@ mod tests {
             current_operator_address: Address::ZERO,
             last_ejection_timestamp,
             ejection_grace_period_sec,
+            driver_sync_tolerance_blocks: 0,
+            in_fork_transition: false,
         }
     }
 
@@ -7,11 +7,10 @@ use alloy::{
     rpc::types::{Filter, Log},
     sol_types::SolEvent,
 };
-use anyhow::{Error, anyhow};
+use anyhow::Error;
 use common::{
     l1::{traits::ELTrait, transaction_error::TransactionError},
     metrics::Metrics,
-    shared::alloy_tools,
     shared::execution_layer::ExecutionLayer as ExecutionLayerCommon,
 };
 use pacaya::l1::protocol_config::{BaseFeeConfig, ProtocolConfig};
@@ -32,18 +31,10 @@ impl ELTrait for ExecutionLayer {
         transaction_error_channel: Sender<TransactionError>,
         metrics: Arc<Metrics>,
     ) -> Result<Self, Error> {
-        let provider = alloy_tools::construct_alloy_provider(
-            &common_config.signer,
-            common_config
-                .execution_rpc_urls
-                .first()
-                .ok_or_else(|| anyhow!("L1 RPC URL is required"))?,
-        )
-        .await?;
         let protocol_config = ProtocolConfig::default();
 
-        let common =
-            ExecutionLayerCommon::new(provider.clone(), common_config.signer.get_address()).await?;
+        let common = ExecutionLayerCommon::from_config(&common_config, metrics).await?;
+        let provider = common.provider();
 
         Ok(Self {
             common,
@@ -1,10 +1,13 @@
-use alloy::primitives::{B256, Bytes, U256, keccak256};
+use alloy::primitives::{Address, B256, Bytes, U256, keccak256};
 use anyhow::Error;
+use common::metrics::Metrics;
 use common::shared::l2_slot_info_v2::L2SlotContext;
 use common::shared::l2_tx_lists::encode_and_compress;
 use common::utils::rpc_client::JSONRPCClient;
 use secp256k1::SecretKey;
+use serde_json::Value;
 use ssz_rs::prelude::*;
+use std::sync::Arc;
 use std::time::Duration;
 use taiko_alethia_reth::validation::ANCHOR_V3_V4_GAS_LIMIT;
 use taiko_preconfirmation_driver::rpc::{PreconfSlotInfo, server::METHOD_GET_PRECONF_SLOT_INFO};
@@ -13,27 +16,68 @@ use taiko_preconfirmation_driver::rpc::{
 };
 use taiko_preconfirmation_types::{
     Bytes20, PreconfCommitment, Preconfirmation, SignedCommitment, address_to_bytes20,
-    b256_to_bytes32, sign_commitment, u256_to_uint256,
+    b256_to_bytes32, sign_commitment, u256_to_uint256, verify_signed_commitment,
 };
-use tracing::{debug, trace};
+use tracing::{debug, trace, warn};
 /// Client for communicating with the preconfirmation driver's JSON-RPC server.
 ///
 /// Provides a typed wrapper around the `preconf_getPreconfSlotInfo` RPC method
 /// exposed by the preconfirmation driver node.
+///
+/// Multiple driver URLs can be configured for redundancy. Calls go to the first
+/// URL and fail over to the next ones in order, the first successful response wins.
 pub struct PreconfirmationDriver {
-    rpc_client: JSONRPCClient,
+    rpc_clients: Vec<(String, JSONRPCClient)>,
+    metrics: Arc<Metrics>,
 }
 
 impl PreconfirmationDriver {
-    pub fn new_with_timeout(url: &str, timeout: Duration) -> Result<Self, Error> {
-        let rpc_client = JSONRPCClient::new_with_timeout(url, timeout)?;
-        Ok(Self { rpc_client })
+    pub fn new_with_timeout(
+        urls: &[String],
+        timeout: Duration,
+        metrics: Arc<Metrics>,
+    ) -> Result<Self, Error> {
+        if urls.is_empty() {
+            return Err(anyhow::anyhow!(
+                "preconfirmation driver: at least one URL is required"
+            ));
+        }
+        let rpc_clients = urls
+            .iter()
+            .map(|url| Ok((url.clone(), JSONRPCClient::new_with_timeout(url, timeout)?)))
+            .collect::<Result<Vec<_>, Error>>()?;
+        Ok(Self {
+            rpc_clients,
+            metrics,
+        })
+    }
+
+    pub fn url_count(&self) -> usize {
+        self.rpc_clients.len()
+    }
+
+    async fn call_method(&self, method: &str, params: Vec<Value>) -> Result<Value, Error> {
+        let mut last_error = None;
+        for (url, rpc_client) in &self.rpc_clients {
+            match rpc_client.call_method(method, params.clone()).await {
+                Ok(response) => return Ok(response),
+                Err(e) => {
+                    warn!(
+                        "preconfirmation driver: {} failed on {}: {}",
+                        method, url, e
+                    );
+                    last_error = Some(e);
+                }
+            }
+        }
+        Err(last_error.unwrap_or_else(|| {
+            anyhow::anyhow!("preconfirmation driver: no driver URLs configured")
+        }))
     }
 
     pub async fn get_preconf_slot_info(&self, timestamp: U256) -> Result<PreconfSlotInfo, Error> {
         trace!("Calling {}", METHOD_GET_PRECONF_SLOT_INFO);
         let response = self
-            .rpc_client
             .call_method(
                 METHOD_GET_PRECONF_SLOT_INFO,
                 vec![serde_json::to_value(timestamp)?],
@@ -50,7 +94,9 @@ impl PreconfirmationDriver {
         Ok(slot_info)
     }
 
-    /// Function to publish a Signed Preconfirmation Commitment and a Transaction List
+    /// Function to publish a Signed Preconfirmation Commitment and a Transaction List.
+    /// The commitment must recover to `preconfer_address`, the address the driver expects
+    /// the commitments of the current preconfer to be signed by.
     pub async fn post_preconf_requests(
         &self,
         l2_slot_context: &L2SlotContext,
@@ -58,6 +104,7 @@ impl PreconfirmationDriver {
         coinbase: alloy::primitives::Address,
         anchor_block_id: u64,
         signer_key: &SecretKey,
+        preconfer_address: Address,
     ) -> Result<PublishBlockResponse, Error> {
         let timestamp_sec = l2_slot_context.info.slot_timestamp();
         let tx_list_bytes = encode_and_compress(tx_list)?;
@@ -95,11 +142,8 @@ impl PreconfirmationDriver {
             slasher_address: Bytes20::default(),
         };
 
-        let signature = sign_commitment(&commitment, signer_key)?;
-        let signed_commitment = SignedCommitment {
-            commitment,
-            signature,
-        };
+        let signed_commitment =
+            self.sign_commitment_as(commitment, signer_key, preconfer_address)?;
         let signed_commitment_bytes = ssz_rs::serialize(&signed_commitment)
             .map_err(|e| anyhow::anyhow!("SSZ Serialization Failed: {:?}", e))?;
 
@@ -117,13 +161,215 @@ impl PreconfirmationDriver {
         })
     }
 
+    /// Signs the commitment and checks that it recovers to the expected preconfer, so a
+    /// misconfigured sequencer key is caught before the commitment is published.
+    fn sign_commitment_as(
+        &self,
+        commitment: PreconfCommitment,
+        signer_key: &SecretKey,
+        preconfer_address: Address,
+    ) -> Result<SignedCommitment, Error> {
+        let signature = sign_commitment(&commitment, signer_key)?;
+        let signed_commitment = SignedCommitment {
+            commitment,
+            signature,
+        };
+        if let Err(e) = verify_commitment_signer(&signed_commitment, preconfer_address) {
+            self.metrics.inc_preconf_commitment_signer_mismatches();
+            return Err(e);
+        }
+        Ok(signed_commitment)
+    }
+
     async fn publish_block(&self, req: PublishBlockRequest) -> Result<PublishBlockResponse, Error> {
         debug!("Calling {}", METHOD_PUBLISH_BLOCK);
         let response = self
-            .rpc_client
             .call_method(METHOD_PUBLISH_BLOCK, vec![serde_json::to_value(req)?])
             .await?;
         let block_response: PublishBlockResponse = serde_json::from_value(response)?;
         Ok(block_response)
     }
 }
+
+/// Recovers the signer of a signed commitment and checks that it is the expected sequencer.
+fn verify_commitment_signer(
+    signed_commitment: &SignedCommitment,
+    expected_signer: Address,
+) -> Result<(), Error> {
+    let recovered_signer = verify_signed_commitment(signed_commitment).map_err(|e| {
+        anyhow::anyhow!(
+            "preconfirmation driver: failed to recover commitment signer: {}",
+            e
+        )
+    })?;
+    if recovered_signer != expected_signer {
+        return Err(anyhow::anyhow!(
+            "preconfirmation driver: commitment signer mismatch, recovered {} but expected preconfer {}",
+            recovered_signer,
+            expected_signer
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use secp256k1::Secp256k1;
+
+    #[tokio::test]
+    async fn test_call_method_fails_over_to_secondary_driver() {
+        let mut primary = mockito::Server::new_async().await;
+        let mut secondary = mockito::Server::new_async().await;
+
+        let primary_mock = primary
+            .mock("POST", "/")
+            .with_status(503)
+            .create_async()
+            .await;
+        let secondary_mock = secondary
+            .mock("POST", "/")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body_from_request(|request| {
+                let body: Value = serde_json::from_slice(request.body().expect("request body"))
+                    .expect("valid JSON-RPC request");
+                serde_json::json!({"jsonrpc": "2.0", "id": body["id"], "result": "accepted"})
+                    .to_string()
+                    .into()
+            })
+            .create_async()
+            .await;
+
+        let driver = PreconfirmationDriver::new_with_timeout(
+            &[primary.url(), secondary.url()],
+            Duration::from_secs(1),
+            Arc::new(Metrics::new()),
+        )
+        .expect("failed to create driver");
+
+        let response = driver
+            .call_method(METHOD_PUBLISH_BLOCK, vec![])
+            .await
+            .expect("secondary driver should accept the request");
+
+        assert_eq!(response, Value::String("accepted".to_string()));
+        primary_mock.assert_async().await;
+        secondary_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_call_method_fails_when_all_drivers_fail() {
+        let mut primary = mockito::Server::new_async().await;
+        let mut secondary = mockito::Server::new_async().await;
+        primary
+            .mock("POST", "/")
+            .with_status(503)
+            .create_async()
+            .await;
+        secondary
+            .mock("POST", "/")
+            .with_status(503)
+            .create_async()
+            .await;
+
+        let driver = PreconfirmationDriver::new_with_timeout(
+            &[primary.url(), secondary.url()],
+            Duration::from_secs(1),
+            Arc::new(Metrics::new()),
+        )
+        .expect("failed to create driver");
+
+        assert!(
+            driver
+                .call_method(METHOD_PUBLISH_BLOCK, vec![])
+                .await
+                .is_err()
+        );
+    }
+
+    fn sequencer_address(signer_key: &SecretKey) -> Address {
+        let public_key = signer_key.public_key(&Secp256k1::new());
+        Address::from_raw_public_key(&public_key.serialize_uncompressed()[1..])
+    }
+
+    fn test_commitment() -> PreconfCommitment {
+        PreconfCommitment {
+            preconf: Preconfirmation {
+                eop: false,
+                block_number: u256_to_uint256(U256::from(10)),
+                timestamp: u256_to_uint256(U256::from(1_700_000_000)),
+                gas_limit: u256_to_uint256(U256::from(30_000_000)),
+                coinbase: address_to_bytes20(Address::repeat_byte(0x11)),
+                anchor_block_number: u256_to_uint256(U256::from(5)),
+                raw_tx_list_hash: b256_to_bytes32(B256::repeat_byte(0x22)),
+                parent_preconfirmation_hash: b256_to_bytes32(B256::ZERO),
+                submission_window_end: u256_to_uint256(U256::from(1_700_000_012)),
+                prover_auth: Bytes20::default(),
+                proposal_id: u256_to_uint256(U256::from(10)),
+            },
+            slasher_address: Bytes20::default(),
+        }
+    }
+
+    fn signed_test_commitment(signer_key: &SecretKey) -> SignedCommitment {
+        let commitment = test_commitment();
+        let signature = sign_commitment(&commitment, signer_key).expect("failed to sign");
+        SignedCommitment {
+            commitment,
+            signature,
+        }
+    }
+
+    #[test]
+    fn test_sign_commitment_rejects_key_of_another_preconfer() {
+        let metrics = Arc::new(Metrics::new());
+        let driver = PreconfirmationDriver::new_with_timeout(
+            &["http://localhost:1".to_string()],
+            Duration::from_secs(1),
+            metrics.clone(),
+        )
+        .expect("failed to create driver");
+        let preconfer_key = SecretKey::from_slice(&[0x01; 32]).expect("valid key");
+        let misconfigured_key = SecretKey::from_slice(&[0x02; 32]).expect("valid key");
+        let preconfer_address = sequencer_address(&preconfer_key);
+
+        assert!(
+            driver
+                .sign_commitment_as(test_commitment(), &misconfigured_key, preconfer_address)
+                .is_err()
+        );
+        assert!(
+            metrics
+                .gather()
+                .contains("catalyst_preconf_commitment_signer_mismatches 1")
+        );
+
+        assert!(
+            driver
+                .sign_commitment_as(test_commitment(), &preconfer_key, preconfer_address)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_verify_commitment_signer_accepts_sequencer_key() {
+        let signer_key = SecretKey::from_slice(&[0x01; 32]).expect("valid key");
+        let signed_commitment = signed_test_commitment(&signer_key);
+
+        assert!(
+            verify_commitment_signer(&signed_commitment, sequencer_address(&signer_key)).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_verify_commitment_signer_rejects_other_key() {
+        let signer_key = SecretKey::from_slice(&[0x01; 32]).expect("valid key");
+        let other_key = SecretKey::from_slice(&[0x02; 32]).expect("valid key");
+        let signed_commitment = signed_test_commitment(&other_key);
+
+        assert!(
+            verify_commitment_signer(&signed_commitment, sequencer_address(&signer_key)).is_err()
+        );
+    }
+}
@@ -9,7 +9,7 @@ use crate::node::config::NodeConfig;
 use crate::utils::config::Config as PermissionlessConfig;
 use anyhow::Error;
 use common::{
-    batch_builder::BatchBuilderConfig,
+    batch_builder::{BatchBuilderConfig, ForkBatchLimits},
     config::Config,
     config::ConfigTrait,
     fork_info::ForkInfo,
@@ -24,7 +24,9 @@ use common::{
 };
 use shasta::l1::execution_layer::ExecutionLayer as ShastaExecutionLayer;
 use shasta::{
-    ProposalManager, l1::config::EthereumL1Config as ShastaEthereumL1Config, l2::taiko::Taiko,
+    ProposalManager,
+    l1::config::{DEFAULT_OPERATOR_STATUS_MAX_AGE_SEC, EthereumL1Config as ShastaEthereumL1Config},
+    l2::taiko::Taiko,
 };
 use std::sync::Arc;
 use tokio::sync::mpsc;
@@ -34,7 +36,7 @@ pub async fn create_permissionless_node(
     config: Config,
     metrics: Arc<Metrics>,
     cancel_token: CancellationToken,
-    _fork_info: ForkInfo,
+    fork_info: ForkInfo,
 ) -> Result<(), Error> {
     info!("Creating Permissionless node");
 
@@ -45,6 +47,8 @@ pub async fn create_permissionless_node(
     let (transaction_error_sender, transaction_error_receiver) = mpsc::channel(100);
     let shasta_l1_config = ShastaEthereumL1Config {
         shasta_inbox: permissionless_config.shasta_inbox,
+        verify_blobs_before_submit: false,
+        operator_status_max_age_sec: DEFAULT_OPERATOR_STATUS_MAX_AGE_SEC,
     };
     let ethereum_l1 = Arc::new(
         EthereumL1::<ShastaExecutionLayer>::new(
@@ -56,16 +60,35 @@ pub async fn create_permissionless_node(
         .await
         .map_err(|e| anyhow::anyhow!("Failed to create EthereumL1: {}", e))?,
     );
+
+    common_l1::clock_skew::check_l1_clock_skew(
+        ethereum_l1.execution_layer.common(),
+        &common_l1::slot_clock::RealClock,
+        config.max_l1_clock_skew_sec,
+        config.refuse_start_on_clock_skew,
+        &metrics,
+    )
+    .await?;
+
+    common::utils::rpc_health::RpcHealthMonitor::new(
+        config.l1_rpc_urls.clone(),
+        vec![config.l2_rpc_url.clone()],
+        cancel_token.clone(),
+        metrics.clone(),
+        config.rpc_health_check_interval_sec,
+    )
+    .run();
+
     let preconfer_address = ethereum_l1.execution_layer.common().preconfer_address();
 
     let taiko_config = pacaya::l2::config::TaikoConfig::new(&config)
         .await
         .map_err(|e| anyhow::anyhow!("Failed to create TaikoConfig: {}", e))?;
 
-    let l2_engine = L2Engine::new(L2EngineConfig::new(
-        &config,
-        taiko_config.signer.get_address(),
-    )?)
+    let l2_engine = L2Engine::new(
+        L2EngineConfig::new(&config, taiko_config.signer.get_address())?,
+        metrics.clone(),
+    )
     .map_err(|e| anyhow::anyhow!("Failed to create L2Engine: {}", e))?;
     let inbox_config = ethereum_l1.execution_layer.fetch_inbox_config().await?;
 
@@ -75,6 +98,7 @@ pub async fn create_permissionless_node(
         metrics.clone(),
         taiko_config,
         l2_engine,
+        None,
     )
     .await?;
     let taiko = Arc::new(taiko);
@@ -97,7 +121,7 @@ pub async fn create_permissionless_node(
 
     let max_anchor_height_offset = taiko.get_protocol_config().get_max_anchor_height_offset();
 
-    let batch_builder_config = BatchBuilderConfig {
+    let mut batch_builder_config = BatchBuilderConfig {
         max_bytes_size_of_batch: config.max_bytes_size_of_batch,
         max_blocks_per_batch,
         l1_slot_duration_sec: config.l1_slot_duration_sec,
@@ -106,15 +130,42 @@ pub async fn create_permissionless_node(
             - config.max_anchor_height_offset_reduction,
         default_coinbase: ethereum_l1.execution_layer.get_preconfer_address(),
         preconf_min_txs: config.preconf_min_txs,
+        preconf_min_txs_adaptive: config.preconf_min_txs_adaptive.clone(),
         preconf_max_skipped_l2_slots: config.preconf_max_skipped_l2_slots,
+        force_empty_block_after_skipped_l2_slots: config.force_empty_block_after_skipped_l2_slots,
         proposal_max_time_sec: config.proposal_max_time_sec,
         max_forced_inclusions: config.max_forced_inclusions_per_proposal,
+        max_batch_age_sec: config.max_batch_age_sec,
+        fork_switch_grace: None,
     };
 
+    // The previous fork proposes to the same Shasta inbox, bounded by its derivation and
+    // anchor offset limits
+    let previous_fork_limits = ForkBatchLimits {
+        max_blocks_per_batch: taiko_protocol::shasta::constants::DERIVATION_SOURCE_MAX_BLOCKS
+            .try_into()?,
+        max_anchor_height_offset: max_anchor_height_offset
+            .saturating_sub(config.max_anchor_height_offset_reduction),
+    };
+    let current_time = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH)?;
+    if let Some(grace_period_end) = batch_builder_config.apply_fork_switch_grace_limits(
+        &fork_info,
+        current_time,
+        previous_fork_limits,
+    ) {
+        info!(
+            "Within the fork switch grace period until {}s, using conservative batch limits: max blocks per batch {}, max anchor height offset {}",
+            grace_period_end.as_secs(),
+            batch_builder_config.max_blocks_per_batch,
+            batch_builder_config.max_anchor_height_offset
+        );
+    }
+
     let preconfirmation_driver = Arc::new(
         l2::preconfirmation_driver::PreconfirmationDriver::new_with_timeout(
-            &permissionless_config.preconfirmation_driver_url,
+            &permissionless_config.preconfirmation_driver_urls,
             permissionless_config.preconfirmation_driver_timeout,
+            metrics.clone(),
         )
         .map_err(|e| anyhow::anyhow!("Failed to create PreconfirmationDriver: {}", e))?,
     );
@@ -124,11 +175,13 @@ pub async fn create_permissionless_node(
         taiko.clone(),
         preconfer_address,
         permissionless_config.sequencer_key,
+        preconfer_address,
     ));
 
     let proposal_manager = ProposalManager::new(
         permissionless_config.l1_height_lag,
         config.min_anchor_offset,
+        config.anchor_confirmation_depth,
         batch_builder_config,
         ethereum_l1.clone(),
         taiko.clone(),
@@ -137,11 +190,19 @@ pub async fn create_permissionless_node(
         cancel_token.clone(),
         permissionless_config.max_blocks_to_reanchor,
         permissionless_config.propose_forced_inclusion,
+        false,
+        false,
+        0,
+        permissionless_config.anchor_timestamp_fetch_max_retries,
     )
     .await
     .map_err(|e| anyhow::anyhow!("Failed to create ProposalManager: {}", e))?;
 
-    let operator = crate::node::operator::Operator::new(preconfirmation_driver, preconfer_address);
+    let operator = crate::node::operator::Operator::new(
+        preconfirmation_driver,
+        preconfer_address,
+        permissionless_config.preconfirmation_driver_timeout,
+    );
 
     let node = node::Node::new(
         cancel_token.clone(),
@@ -16,6 +16,7 @@ pub struct PermissionlessBlockAdvancer {
     taiko: Arc<Taiko>,
     coinbase: Address,
     signer_key: SecretKey,
+    preconfer_address: Address,
 }
 
 impl PermissionlessBlockAdvancer {
@@ -24,12 +25,14 @@ impl PermissionlessBlockAdvancer {
         taiko: Arc<Taiko>,
         coinbase: Address,
         signer_key: SecretKey,
+        preconfer_address: Address,
     ) -> Self {
         Self {
             preconfirmation_driver,
             taiko,
             coinbase,
             signer_key,
+            preconfer_address,
         }
     }
 }
@@ -67,6 +70,7 @@ impl BlockAdvancer for PermissionlessBlockAdvancer {
                     self.coinbase,
                     l2_block_payload.anchor_block_id,
                     &self.signer_key,
+                    self.preconfer_address,
                 )
                 .await
                 .map_err(|e| anyhow::anyhow!("Failed to post preconfirmation requests: {}", e))?;
@@ -16,7 +16,7 @@ use tokio::{
     sync::mpsc::{Receiver, error::TryRecvError},
     time::Duration,
 };
-use tracing::{debug, error, info, warn};
+use tracing::{Instrument, debug, error, info, warn};
 pub mod block_advancer;
 pub mod config;
 pub mod operator;
@@ -25,7 +25,7 @@ pub struct Node {
     cancel_token: CancellationToken,
     ethereum_l1: Arc<EthereumL1<ShastaExecutionLayer>>,
     transaction_error_channel: Receiver<TransactionError>,
-    _metrics: Arc<Metrics>,
+    metrics: Arc<Metrics>,
     watchdog: common_utils::watchdog::Watchdog,
     config: NodeConfig,
     operator: Operator,
@@ -53,7 +53,7 @@ impl Node {
             cancel_token,
             ethereum_l1,
             transaction_error_channel,
-            _metrics: metrics,
+            metrics,
             watchdog,
             config,
             operator,
@@ -88,7 +88,13 @@ impl Node {
                 return;
             }
 
-            if let Err(err) = self.main_block_preconfirmation_step().await {
+            let heartbeat_span =
+                common_utils::logging::heartbeat_span(&self.ethereum_l1.slot_clock);
+            if let Err(err) = self
+                .main_block_preconfirmation_step()
+                .instrument(heartbeat_span)
+                .await
+            {
                 error!("Failed to execute main block preconfirmation step: {}", err);
                 self.watchdog.increment();
             } else {
@@ -256,6 +262,7 @@ impl Node {
     }
 
     async fn handle_transaction_error(&mut self, error: &TransactionError) -> Result<(), Error> {
+        self.metrics.inc_transaction_error(error);
         match error {
             TransactionError::ReanchorRequired => {
                 warn!("Unexpected ReanchorRequired error received");
@@ -308,6 +315,21 @@ impl Node {
                 warn!("Proposal transaction executed too late.");
                 Ok(())
             }
+            TransactionError::ContractRevert(contract_error) => {
+                if contract_error.is_not_the_operator() {
+                    warn!(
+                        "Proposal transaction executed too late, reverted with {:?}",
+                        contract_error
+                    );
+                    return Ok(());
+                }
+                self.cancel_token.cancel_on_critical_error();
+                Err(anyhow::anyhow!(
+                    "Transaction reverted with {:?} ({}), exiting",
+                    contract_error,
+                    contract_error.selector()
+                ))
+            }
             TransactionError::BuildFailed => {
                 self.cancel_token.cancel_on_critical_error();
                 Err(anyhow::anyhow!("Transaction build failed, exiting"))
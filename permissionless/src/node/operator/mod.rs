@@ -6,28 +6,102 @@ use anyhow::Error;
 use common::shared::l2_slot_info_v2::L2SlotInfoV2;
 use status::Status;
 use std::sync::Arc;
+use std::time::Duration;
+use tracing::warn;
+
+/// Time given to the slot info lookup on top of the driver's own per URL timeouts.
+const SLOT_INFO_TIMEOUT_MARGIN: Duration = Duration::from_millis(500);
 
 pub struct Operator {
     driver: Arc<PreconfirmationDriver>,
     preconfer_address: Address,
+    slot_info_timeout: Duration,
 }
 
 impl Operator {
-    pub fn new(driver: Arc<PreconfirmationDriver>, preconfer_address: Address) -> Self {
+    /// `driver_timeout` is the request timeout of the driver. The driver tries each of its URLs
+    /// in turn, so the slot info lookup is bounded above all of their timeouts.
+    pub fn new(
+        driver: Arc<PreconfirmationDriver>,
+        preconfer_address: Address,
+        driver_timeout: Duration,
+    ) -> Self {
+        let url_count = u32::try_from(driver.url_count()).unwrap_or(u32::MAX);
+        let slot_info_timeout = driver_timeout
+            .saturating_mul(url_count)
+            .saturating_add(SLOT_INFO_TIMEOUT_MARGIN);
         Self {
             driver,
             preconfer_address,
+            slot_info_timeout,
         }
     }
 
     pub async fn get_status(&self, l2_slot_info: L2SlotInfoV2) -> Result<Status, Error> {
-        let preconf_slot_info = self
-            .driver
-            .get_preconf_slot_info(U256::from(l2_slot_info.slot_timestamp()))
-            .await?;
+        let slot_timestamp = l2_slot_info.slot_timestamp();
+        let preconf_slot_info = match tokio::time::timeout(
+            self.slot_info_timeout,
+            self.driver
+                .get_preconf_slot_info(U256::from(slot_timestamp)),
+        )
+        .await
+        {
+            Ok(Ok(preconf_slot_info)) => preconf_slot_info,
+            Ok(Err(err)) => {
+                // Every driver URL failed or timed out, skip preconfirming this slot as well
+                warn!(
+                    "get_preconf_slot_info failed for slot timestamp {}, treating node as non-preconfer: {}",
+                    slot_timestamp, err
+                );
+                return Ok(Status::new(false, false));
+            }
+            Err(_) => {
+                // A slow driver must not stall the heartbeat, skip preconfirming this slot instead
+                warn!(
+                    "get_preconf_slot_info timed out after {} ms for slot timestamp {}, treating node as non-preconfer",
+                    self.slot_info_timeout.as_millis(),
+                    slot_timestamp
+                );
+                return Ok(Status::new(false, false));
+            }
+        };
 
         let preconfer = preconf_slot_info.signer == self.preconfer_address;
 
         Ok(Status::new(preconfer, false))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+
+    #[tokio::test]
+    async fn test_get_status_returns_non_preconfer_on_slow_driver() {
+        // The listener accepts connections but never responds, simulating a stuck driver
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("failed to bind listener");
+        let url = format!("http://{}", listener.local_addr().expect("no local addr"));
+        // The same timeout is given to the driver and the operator, as in production
+        let driver_timeout = Duration::from_millis(100);
+        let driver = Arc::new(
+            PreconfirmationDriver::new_with_timeout(
+                &[url],
+                driver_timeout,
+                Arc::new(common::metrics::Metrics::new()),
+            )
+            .expect("failed to create driver"),
+        );
+        let operator = Operator::new(driver, Address::ZERO, driver_timeout);
+
+        let l2_slot_info = L2SlotInfoV2::new(0, 1_700_000_000, 0, Default::default(), 0, 0);
+        let status = operator
+            .get_status(l2_slot_info)
+            .await
+            .expect("status should not fail on timeout");
+
+        assert!(!status.is_preconfer());
+    }
+}
@@ -19,12 +19,13 @@ pub struct L1ContractAddresses {
 #[derive(Clone)]
 pub struct Config {
     pub contract_addresses: L1ContractAddresses,
-    pub preconfirmation_driver_url: String,
+    pub preconfirmation_driver_urls: Vec<String>,
     pub preconfirmation_driver_timeout: Duration,
     pub shasta_inbox: Address,
     pub l1_height_lag: u64,
     pub max_blocks_to_reanchor: u64,
     pub propose_forced_inclusion: bool,
+    pub anchor_timestamp_fetch_max_retries: u64,
     pub sequencer_key: SecretKey,
 }
 
@@ -61,8 +62,18 @@ impl ConfigTrait for Config {
             })?;
 
         const PRECONFIRMATION_DRIVER_URL: &str = "PRECONFIRMATION_DRIVER_URL";
-        let preconfirmation_driver_url = std::env::var(PRECONFIRMATION_DRIVER_URL)
-            .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", PRECONFIRMATION_DRIVER_URL, e))?;
+        let preconfirmation_driver_urls: Vec<String> = std::env::var(PRECONFIRMATION_DRIVER_URL)
+            .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", PRECONFIRMATION_DRIVER_URL, e))?
+            .split(",")
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+        if preconfirmation_driver_urls.is_empty() {
+            return Err(anyhow::anyhow!(
+                "{} must contain at least one URL",
+                PRECONFIRMATION_DRIVER_URL
+            ));
+        }
         const PRECONFIRMATION_DRIVER_TIMEOUT_MS: &str = "PRECONFIRMATION_DRIVER_TIMEOUT_MS";
         let preconfirmation_driver_timeout = Duration::from_millis(
             std::env::var(PRECONFIRMATION_DRIVER_TIMEOUT_MS)
@@ -98,6 +109,14 @@ impl ConfigTrait for Config {
             .parse::<bool>()
             .map_err(|e| anyhow::anyhow!("PROPOSE_FORCED_INCLUSION must be a boolean: {}", e))?;
 
+        let anchor_timestamp_fetch_max_retries =
+            std::env::var("ANCHOR_TIMESTAMP_FETCH_MAX_RETRIES")
+                .unwrap_or("2".to_string())
+                .parse::<u64>()
+                .map_err(|e| {
+                    anyhow::anyhow!("ANCHOR_TIMESTAMP_FETCH_MAX_RETRIES must be a number: {}", e)
+                })?;
+
         let sequencer_key_str = std::env::var("SEQUENCER_KEY")
             .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", "SEQUENCER_KEY", e))?;
         let sequencer_key_bytes = hex::decode(sequencer_key_str.trim_start_matches("0x"))
@@ -113,12 +132,13 @@ impl ConfigTrait for Config {
                 lookahead_slasher_address,
                 preconf_slasher_address,
             },
-            preconfirmation_driver_url,
+            preconfirmation_driver_urls,
             preconfirmation_driver_timeout,
             shasta_inbox,
             l1_height_lag,
             max_blocks_to_reanchor,
             propose_forced_inclusion,
+            anchor_timestamp_fetch_max_retries,
             sequencer_key,
         })
     }
@@ -129,8 +149,8 @@ impl fmt::Display for Config {
         writeln!(f, "Contract addresses: {:#?}", self.contract_addresses)?;
         writeln!(
             f,
-            "Preconfirmation driver URL: {}",
-            self.preconfirmation_driver_url
+            "Preconfirmation driver URLs: {}",
+            self.preconfirmation_driver_urls.join(", ")
         )?;
         writeln!(
             f,
@@ -145,6 +165,11 @@ impl fmt::Display for Config {
             "Propose forced inclusion: {}",
             self.propose_forced_inclusion
         )?;
+        writeln!(
+            f,
+            "Anchor timestamp fetch max retries: {}",
+            self.anchor_timestamp_fetch_max_retries
+        )?;
 
         Ok(())
     }
@@ -23,12 +23,13 @@ use alloy::{
 use anyhow::{Error, anyhow};
 use common::{
     l1::{
+        height_cross_check::L1HeightCrossCheck,
         traits::{ELTrait, PreconferProvider},
         transaction_error::TransactionError,
     },
     metrics::Metrics,
     shared::{
-        alloy_tools, execution_layer::ExecutionLayer as ExecutionLayerCommon,
+        execution_layer::ExecutionLayer as ExecutionLayerCommon,
         transaction_monitor::TransactionMonitor,
     },
 };
@@ -50,6 +51,7 @@ pub struct ExecutionLayer {
     extra_gas_percentage: u64,
     proposal_cipher: crate::privacy::ProposalCipher,
     fi_max_per_proposal: u16,
+    metrics: Arc<Metrics>,
 }
 
 impl ELTrait for ExecutionLayer {
@@ -60,16 +62,13 @@ impl ELTrait for ExecutionLayer {
         transaction_error_channel: Sender<TransactionError>,
         metrics: Arc<Metrics>,
     ) -> Result<Self, Error> {
-        let provider = alloy_tools::construct_alloy_provider(
-            &common_config.signer,
-            common_config
-                .execution_rpc_urls
-                .first()
-                .ok_or_else(|| anyhow!("L1 RPC URL is required"))?,
-        )
-        .await?;
-        let common =
-            ExecutionLayerCommon::new(provider.clone(), common_config.signer.get_address()).await?;
+        let mut common = ExecutionLayerCommon::from_config(&common_config, metrics.clone()).await?;
+        let provider = common.provider();
+        if let Some(height_cross_check) =
+            L1HeightCrossCheck::from_config(&common_config, metrics.clone()).await?
+        {
+            common.set_height_cross_check(height_cross_check);
+        }
 
         let transaction_monitor = TransactionMonitor::new(
             provider.clone(),
@@ -136,6 +135,7 @@ impl ELTrait for ExecutionLayer {
             extra_gas_percentage,
             proposal_cipher,
             fi_max_per_proposal: specific_config.fi_max_per_proposal,
+            metrics,
         })
     }
 
@@ -232,6 +232,8 @@ impl ExecutionLayer {
             batch.zk_proof.is_some(),
         );
 
+        self.record_tx_list_compression_ratio(&batch);
+
         // Decide how many forced inclusions to consume from the queue. Capped by
         // `fi_max_per_proposal`; the contract enforces consumption of any "due"
         // FI (one whose timestamp + forcedInclusionDelay has passed) — so if the
@@ -261,6 +263,7 @@ impl ExecutionLayer {
         let builder = ProposalTxBuilder::new(
             self.provider.clone(),
             self.extra_gas_percentage,
+            self.transaction_monitor.fee_history_cache(),
             self.proof_type,
             self.mock_mode,
             self.proposal_cipher.clone(),
@@ -282,6 +285,13 @@ impl ExecutionLayer {
             .map_err(|e| Error::msg(format!("Sending batch to L1 failed: {e}")))
     }
 
+    fn record_tx_list_compression_ratio(&self, batch: &Proposal) {
+        if let Some(ratio) = batch.compression_ratio {
+            tracing::debug!("Tx list compression ratio: {:.2}", ratio);
+            self.metrics.set_tx_list_compression_ratio(ratio);
+        }
+    }
+
     pub async fn is_transaction_in_progress(&self) -> Result<bool, Error> {
         self.transaction_monitor.is_transaction_in_progress().await
     }
@@ -24,7 +24,7 @@ use alloy::{
 };
 use alloy_json_rpc::RpcError;
 use anyhow::Error;
-use common::l1::{fees_per_gas::FeesPerGas, tools, transaction_error::TransactionError};
+use common::l1::{fee_history_cache::FeeHistoryCache, tools, transaction_error::TransactionError};
 use taiko_protocol::shasta::{
     BlobCoder,
     manifest::{BlockManifest, DerivationSourceManifest},
@@ -34,6 +34,7 @@ use tracing::{info, warn};
 pub struct ProposalTxBuilder {
     provider: DynProvider,
     extra_gas_percentage: u64,
+    fee_history_cache: FeeHistoryCache,
     proof_type: ProofType,
     mock_mode: bool,
     cipher: crate::privacy::ProposalCipher,
@@ -43,6 +44,7 @@ impl ProposalTxBuilder {
     pub fn new(
         provider: DynProvider,
         extra_gas_percentage: u64,
+        fee_history_cache: FeeHistoryCache,
         proof_type: ProofType,
         mock_mode: bool,
         cipher: crate::privacy::ProposalCipher,
@@ -50,6 +52,7 @@ impl ProposalTxBuilder {
         Self {
             provider,
             extra_gas_percentage,
+            fee_history_cache,
             proof_type,
             mock_mode,
             cipher,
@@ -91,7 +94,7 @@ impl ProposalTxBuilder {
         };
         let tx_blob_gas = tx_blob_gas + tx_blob_gas * self.extra_gas_percentage / 100;
 
-        let fees_per_gas = match FeesPerGas::get_fees_per_gas(&self.provider).await {
+        let fees_per_gas = match self.fee_history_cache.get().await {
             Ok(fees_per_gas) => fees_per_gas,
             Err(e) => {
                 warn!("Build proposeBatch: Failed to get fees per gas: {}", e);
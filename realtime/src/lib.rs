@@ -10,13 +10,17 @@ mod utils;
 use crate::utils::config::RealtimeConfig;
 use anyhow::Error;
 use common::{
-    batch_builder::BatchBuilderConfig,
+    batch_builder::{BatchBuilderConfig, ForkBatchLimits},
     config::Config,
     config::ConfigTrait,
     fork_info::ForkInfo,
-    l1::{self as common_l1, traits::PreconferProvider},
+    l1::{
+        self as common_l1,
+        traits::{ELTrait, PreconferProvider},
+    },
     l2::engine::{L2Engine, L2EngineConfig},
     metrics,
+    shared::anchor_block_info::AnchorBlockInfo,
     utils::cancellation_token::CancellationToken,
 };
 use l1::execution_layer::ExecutionLayer;
@@ -49,14 +53,32 @@ pub async fn create_realtime_node(
 
     let ethereum_l1 = Arc::new(ethereum_l1);
 
+    common_l1::clock_skew::check_l1_clock_skew(
+        ethereum_l1.execution_layer.common(),
+        &common_l1::slot_clock::RealClock,
+        config.max_l1_clock_skew_sec,
+        config.refuse_start_on_clock_skew,
+        &metrics,
+    )
+    .await?;
+
+    common::utils::rpc_health::RpcHealthMonitor::new(
+        config.l1_rpc_urls.clone(),
+        vec![config.l2_rpc_url.clone()],
+        cancel_token.clone(),
+        metrics.clone(),
+        config.rpc_health_check_interval_sec,
+    )
+    .run();
+
     let taiko_config = pacaya::l2::config::TaikoConfig::new(&config)
         .await
         .map_err(|e| anyhow::anyhow!("Failed to create TaikoConfig: {}", e))?;
 
-    let l2_engine = L2Engine::new(L2EngineConfig::new(
-        &config,
-        taiko_config.signer.get_address(),
-    )?)
+    let l2_engine = L2Engine::new(
+        L2EngineConfig::new(&config, taiko_config.signer.get_address())?,
+        metrics.clone(),
+    )
     .map_err(|e| anyhow::anyhow!("Failed to create L2Engine: {}", e))?;
     let protocol_config = ethereum_l1.execution_layer.fetch_protocol_config().await?;
 
@@ -76,8 +98,13 @@ pub async fn create_realtime_node(
         preconf_heartbeat_ms: config.preconf_heartbeat_ms,
         handover_window_slots: 8,
         handover_start_buffer_ms: 500,
+        driver_sync_tolerance_blocks: config.driver_sync_tolerance_blocks,
         l1_height_lag: 8,
+        min_anchor_offset: config.min_anchor_offset,
+        anchor_confirmation_depth: config.anchor_confirmation_depth,
+        anchor_prefetch: realtime_config.anchor_prefetch,
         simulate_not_submitting_at_the_end_of_epoch: false,
+        kzg_eager_warmup: config.kzg_eager_warmup,
     };
 
     let max_blocks_per_batch = if config.max_blocks_per_batch == 0 {
@@ -88,8 +115,16 @@ pub async fn create_realtime_node(
 
     // Use 256-block limit for anchor offset
     let max_anchor_height_offset = 256u64;
-
-    let batch_builder_config = BatchBuilderConfig {
+    AnchorBlockInfo::validate_min_anchor_offset(
+        config.min_anchor_offset,
+        max_anchor_height_offset - config.max_anchor_height_offset_reduction,
+    )?;
+    AnchorBlockInfo::validate_anchor_confirmation_depth(
+        config.anchor_confirmation_depth,
+        max_anchor_height_offset - config.max_anchor_height_offset_reduction,
+    )?;
+
+    let mut batch_builder_config = BatchBuilderConfig {
         max_bytes_size_of_batch: config.max_bytes_size_of_batch,
         max_blocks_per_batch,
         l1_slot_duration_sec: config.l1_slot_duration_sec,
@@ -98,24 +133,49 @@ pub async fn create_realtime_node(
             - config.max_anchor_height_offset_reduction,
         default_coinbase: ethereum_l1.execution_layer.get_preconfer_address(),
         preconf_min_txs: config.preconf_min_txs,
+        preconf_min_txs_adaptive: config.preconf_min_txs_adaptive.clone(),
         preconf_max_skipped_l2_slots: config.preconf_max_skipped_l2_slots,
+        force_empty_block_after_skipped_l2_slots: config.force_empty_block_after_skipped_l2_slots,
         proposal_max_time_sec: config.proposal_max_time_sec,
         max_forced_inclusions: config.max_forced_inclusions_per_proposal,
+        max_batch_age_sec: config.max_batch_age_sec,
+        fork_switch_grace: None,
     };
 
+    // The previous fork proposes to the Shasta inbox, bounded by its derivation and anchor
+    // offset limits
+    let previous_fork_limits = ForkBatchLimits {
+        max_blocks_per_batch: taiko_protocol::shasta::constants::DERIVATION_SOURCE_MAX_BLOCKS
+            .try_into()?,
+        max_anchor_height_offset: taiko_protocol::shasta::constants::max_anchor_offset_for_chain(
+            taiko.l2_execution_layer().common().chain_id(),
+        )
+        .saturating_sub(config.max_anchor_height_offset_reduction),
+    };
+    let current_time = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH)?;
+    if let Some(grace_period_end) = batch_builder_config.apply_fork_switch_grace_limits(
+        &fork_info,
+        current_time,
+        previous_fork_limits,
+    ) {
+        info!(
+            "Within the fork switch grace period until {}s, using conservative batch limits: max blocks per batch {}, max anchor height offset {}",
+            grace_period_end.as_secs(),
+            batch_builder_config.max_blocks_per_batch,
+            batch_builder_config.max_anchor_height_offset
+        );
+    }
+
     // Initialize chain monitor for ProposedAndProved events
     let chain_monitor = Arc::new(
         chain_monitor::RealtimeChainMonitor::new(
-            config
-                .l1_rpc_urls
-                .first()
-                .ok_or_else(|| anyhow::anyhow!("L1 RPC URL is required"))?
-                .clone(),
+            config.chain_monitor_l1_url()?,
             config.l2_rpc_url.clone(),
             realtime_config.realtime_inbox,
             cancel_token.clone(),
             "ProposedAndProved",
             chain_monitor::print_proposed_and_proved_info,
+            config.chain_monitor_log_chunk_blocks,
             metrics.clone(),
         )
         .map_err(|e| anyhow::anyhow!("Failed to create RealtimeChainMonitor: {}", e))?,
@@ -146,6 +206,7 @@ pub async fn create_realtime_node(
         cancel_token.clone(),
         ethereum_l1.clone(),
         taiko.clone(),
+        metrics.clone(),
         batch_builder_config,
         transaction_error_receiver,
         fork_info,
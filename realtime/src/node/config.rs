@@ -3,6 +3,11 @@ pub struct NodeConfig {
     pub preconf_heartbeat_ms: u64,
     pub handover_window_slots: u64,
     pub handover_start_buffer_ms: u64,
+    pub driver_sync_tolerance_blocks: u64,
     pub l1_height_lag: u64,
+    pub min_anchor_offset: u64,
+    pub anchor_confirmation_depth: u64,
+    pub anchor_prefetch: bool,
     pub simulate_not_submitting_at_the_end_of_epoch: bool,
+    pub kzg_eager_warmup: bool,
 }
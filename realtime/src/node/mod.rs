@@ -17,6 +17,7 @@ use crate::l1::execution_layer::ExecutionLayer;
 use crate::l2::taiko::Taiko;
 use common::batch_builder::BatchBuilderConfig;
 use common::l1::traits::PreconferProvider;
+use common::metrics::Metrics;
 use common::shared::head_verifier::HeadVerifier;
 use common::shared::l2_slot_info_v2::L2SlotInfoV2;
 use proposal_manager::BatchManager;
@@ -36,6 +37,7 @@ pub struct Node {
     proposal_manager: BatchManager,
     head_verifier: HeadVerifier,
     transaction_error_channel: Receiver<TransactionError>,
+    metrics: Arc<Metrics>,
     preconf_only: bool,
 }
 
@@ -46,6 +48,7 @@ impl Node {
         cancel_token: CancellationToken,
         ethereum_l1: Arc<EthereumL1<ExecutionLayer>>,
         taiko: Arc<Taiko>,
+        metrics: Arc<Metrics>,
         batch_builder_config: BatchBuilderConfig,
         transaction_error_channel: Receiver<TransactionError>,
         fork_info: ForkInfo,
@@ -67,6 +70,8 @@ impl Node {
             cancel_token.clone(),
             fork_info.clone(),
             0,
+            config.driver_sync_tolerance_blocks,
+            metrics.clone(),
         )
         .map_err(|e| anyhow::anyhow!("Failed to create Operator: {}", e))?;
         let watchdog = common_utils::watchdog::Watchdog::new(
@@ -77,9 +82,13 @@ impl Node {
 
         let proposal_manager = BatchManager::new(
             config.l1_height_lag,
+            config.min_anchor_offset,
+            config.anchor_confirmation_depth,
+            config.anchor_prefetch,
             batch_builder_config,
             ethereum_l1.clone(),
             taiko.clone(),
+            metrics.clone(),
             cancel_token.clone(),
             last_finalized_block_hash,
             raiko_client,
@@ -91,12 +100,19 @@ impl Node {
         .await
         .map_err(|e| anyhow::anyhow!("Failed to create BatchManager: {}", e))?;
 
-        let start = std::time::Instant::now();
-        common::blob::build_default_kzg_settings();
-        info!(
-            "Setup build_default_kzg_settings in {} milliseconds",
-            start.elapsed().as_millis()
-        );
+        match common::blob::warmup_kzg_settings(
+            config.kzg_eager_warmup,
+            common::blob::build_default_kzg_settings,
+        ) {
+            Some(duration) => {
+                info!(
+                    "Setup build_default_kzg_settings in {} milliseconds",
+                    duration.as_millis()
+                );
+                metrics.set_kzg_setup_ms(duration);
+            }
+            None => info!("Skipping eager build_default_kzg_settings"),
+        }
 
         Ok(Self {
             config,
@@ -108,6 +124,7 @@ impl Node {
             proposal_manager,
             head_verifier,
             transaction_error_channel,
+            metrics,
             preconf_only,
         })
     }
@@ -244,6 +261,8 @@ impl Node {
                     .await?;
 
                 self.verify_preconfed_block(preconfed_block).await?;
+            } else if let Err(err) = self.proposal_manager.prefetch_anchor_block_info().await {
+                warn!("Failed to prefetch anchor block info: {}", err);
             }
         }
 
@@ -300,6 +319,7 @@ impl Node {
         _current_status: &OperatorStatus,
         _l2_slot_info: &L2SlotInfoV2,
     ) -> Result<(), Error> {
+        self.metrics.inc_transaction_error(error);
         match error {
             TransactionError::ReanchorRequired => {
                 warn!("Unexpected ReanchorRequired error received");
@@ -349,6 +369,20 @@ impl Node {
                 warn!("Propose batch transaction executed too late.");
                 Ok(())
             }
+            TransactionError::ContractRevert(contract_error) => {
+                if contract_error.is_not_the_operator() {
+                    warn!(
+                        "Propose batch transaction executed too late, reverted with {:?}",
+                        contract_error
+                    );
+                    return Ok(());
+                }
+                warn!(
+                    "L1 transaction reverted with {:?}. Reorging preconfirmed L2 blocks.",
+                    contract_error
+                );
+                self.recover_from_failed_submission().await
+            }
             TransactionError::BuildFailed => {
                 self.cancel_token.cancel_on_critical_error();
                 Err(anyhow::anyhow!("Transaction build failed, exiting"))
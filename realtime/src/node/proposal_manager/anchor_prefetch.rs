@@ -0,0 +1,58 @@
+use common::shared::anchor_block_info::AnchorBlockInfo;
+
+/// Anchor block info computed ahead of time during an idle slot.
+/// The cached value is only valid within the L1 slot it was computed in,
+/// as the L1 head and the anchor selection move on with the next slot.
+#[derive(Default)]
+pub struct AnchorPrefetchCache {
+    entry: Option<(u64, AnchorBlockInfo)>,
+}
+
+impl AnchorPrefetchCache {
+    pub fn is_valid_for(&self, l1_slot: u64) -> bool {
+        self.entry
+            .as_ref()
+            .is_some_and(|(slot, _)| *slot == l1_slot)
+    }
+
+    pub fn store(&mut self, l1_slot: u64, anchor_block_info: AnchorBlockInfo) {
+        self.entry = Some((l1_slot, anchor_block_info));
+    }
+
+    /// Takes the cached anchor block info if it was computed in the given L1 slot.
+    /// A stale entry is dropped.
+    pub fn take(&mut self, l1_slot: u64) -> Option<AnchorBlockInfo> {
+        match self.entry.take() {
+            Some((slot, anchor_block_info)) if slot == l1_slot => Some(anchor_block_info),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::B256;
+
+    #[test]
+    fn test_cached_anchor_is_used_within_slot() {
+        let mut cache = AnchorPrefetchCache::default();
+        cache.store(10, AnchorBlockInfo::new(100, 1200, B256::ZERO, B256::ZERO));
+
+        assert!(cache.is_valid_for(10));
+        assert_eq!(cache.take(10).map(|info| info.id()), Some(100));
+        // Consumed by the batch that used it
+        assert!(cache.take(10).is_none());
+    }
+
+    #[test]
+    fn test_cached_anchor_is_invalidated_when_l1_advances() {
+        let mut cache = AnchorPrefetchCache::default();
+        cache.store(10, AnchorBlockInfo::new(100, 1200, B256::ZERO, B256::ZERO));
+
+        assert!(!cache.is_valid_for(11));
+        assert!(cache.take(11).is_none());
+        // Stale entry is dropped
+        assert!(!cache.is_valid_for(10));
+    }
+}
@@ -179,6 +179,7 @@ async fn submission_task(
             blocks: block_manifests,
         };
         let manifest_data = manifest.encode_and_compress()?;
+        proposal.set_compression_ratio(manifest_data.len());
 
         // Privacy: re-frame [Shasta frame(64B) || scheme(1B) || scheme_body] using
         // the cipher. AES-256-GCM uses a fresh random nonce per call, so this MUST
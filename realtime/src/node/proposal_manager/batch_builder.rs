@@ -6,9 +6,18 @@ use crate::node::proposal_manager::{
 };
 use alloy::primitives::{B256, FixedBytes};
 use anyhow::Error;
-use common::{batch_builder::BatchBuilderConfig, shared::l2_block_v2::L2BlockV2Draft};
-use common::{l1::slot_clock::SlotClock, shared::anchor_block_info::AnchorBlockInfo};
-use std::{collections::VecDeque, sync::Arc};
+use common::{
+    batch_builder::{BatchBuilderConfig, MinTxsController},
+    shared::l2_block_v2::L2BlockV2Draft,
+};
+use common::{
+    l1::slot_clock::SlotClock, metrics::Metrics, shared::anchor_block_info::AnchorBlockInfo,
+};
+use std::{
+    collections::VecDeque,
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
 use tracing::{debug, info, trace, warn};
 
 pub struct BatchBuilder {
@@ -16,15 +25,27 @@ pub struct BatchBuilder {
     proposals_to_send: VecDeque<Proposal>,
     current_proposal: Option<Proposal>,
     slot_clock: Arc<SlotClock>,
+    min_txs_controller: MinTxsController,
+    metrics: Arc<Metrics>,
 }
 
 impl BatchBuilder {
-    pub fn new(config: BatchBuilderConfig, slot_clock: Arc<SlotClock>) -> Self {
+    pub fn new(
+        config: BatchBuilderConfig,
+        slot_clock: Arc<SlotClock>,
+        metrics: Arc<Metrics>,
+    ) -> Self {
+        let min_txs_controller = MinTxsController::new(
+            config.preconf_min_txs,
+            config.preconf_min_txs_adaptive.clone(),
+        );
         Self {
             config,
             proposals_to_send: VecDeque::new(),
             current_proposal: None,
             slot_clock,
+            min_txs_controller,
+            metrics,
         }
     }
 
@@ -74,6 +95,16 @@ impl BatchBuilder {
     ) {
         self.finalize_current_batch();
 
+        let current_time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        if self.config.end_fork_switch_grace_if_over(current_time) {
+            info!(
+                "Fork switch grace period is over, using fork batch limits: max blocks per batch {}, max anchor height offset {}",
+                self.config.max_blocks_per_batch, self.config.max_anchor_height_offset
+            );
+        }
+
         self.current_proposal = Some(Proposal {
             l2_blocks: vec![],
             total_bytes: 0,
@@ -90,6 +121,8 @@ impl BatchBuilder {
             l1_calls: vec![],
             zk_proof: None,
             blob_payload: None,
+            compression_ratio: None,
+            created_at_sec: current_time.as_secs(),
         });
     }
 
@@ -183,6 +216,10 @@ impl BatchBuilder {
         }
     }
 
+    pub fn has_current_batch(&self) -> bool {
+        self.current_proposal.is_some()
+    }
+
     pub fn is_empty(&self) -> bool {
         trace!(
             "batch_builder::is_empty: current_proposal is none: {}, proposals_to_send len: {}",
@@ -194,19 +231,34 @@ impl BatchBuilder {
 
     /// Finalize the current batch if appropriate for submission.
     pub fn finalize_if_needed(&mut self, submit_only_full_batches: bool) {
-        if self.current_proposal.is_some()
-            && (!submit_only_full_batches
-                || !self.config.is_within_block_limit(
-                    u16::try_from(
-                        self.current_proposal
-                            .as_ref()
-                            .map(|b| b.l2_blocks.len())
-                            .unwrap_or(0),
-                    )
-                    .unwrap_or(u16::MAX)
-                        + 1,
-                ))
+        let current_time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        self.finalize_if_needed_at(submit_only_full_batches, current_time);
+    }
+
+    fn finalize_if_needed_at(&mut self, submit_only_full_batches: bool, current_time: u64) {
+        let Some(current_proposal) = self.current_proposal.as_ref() else {
+            return;
+        };
+        let block_count = u16::try_from(current_proposal.l2_blocks.len()).unwrap_or(u16::MAX);
+        if !submit_only_full_batches
+            || !self
+                .config
+                .is_within_block_limit(block_count.saturating_add(1))
+        {
+            self.finalize_current_batch();
+        } else if !current_proposal.l2_blocks.is_empty()
+            && self
+                .config
+                .is_older_than_max_batch_age(current_proposal.created_at_sec, current_time)
         {
+            info!(
+                "Batch reached max batch age with {} blocks, submitting it",
+                current_proposal.l2_blocks.len()
+            );
+            self.metrics.inc_batch_age_forced_submissions();
             self.finalize_current_batch();
         }
     }
@@ -293,6 +345,16 @@ impl BatchBuilder {
         }
     }
 
+    /// Feeds the number of pending transactions into the `preconf_min_txs` controller.
+    pub fn observe_pending_txs(&mut self, pending_tx_list: &Option<PreBuiltTxList>) {
+        let number_of_pending_txs = pending_tx_list
+            .as_ref()
+            .map(|tx_list| tx_list.get_tx_list().len())
+            .unwrap_or(0) as u64;
+        self.min_txs_controller
+            .observe_mempool_depth(number_of_pending_txs);
+    }
+
     pub fn should_new_block_be_created(
         &self,
         pending_tx_list: &Option<PreBuiltTxList>,
@@ -308,7 +370,7 @@ impl BatchBuilder {
             return true;
         }
 
-        if number_of_pending_txs >= self.config.preconf_min_txs {
+        if number_of_pending_txs >= self.min_txs_controller.min_txs() {
             return true;
         }
 
@@ -318,7 +380,7 @@ impl BatchBuilder {
             let number_of_l2_slots =
                 (current_l2_slot_timestamp.saturating_sub(last_block.timestamp_sec)) * 1000
                     / self.slot_clock.get_preconf_heartbeat_ms();
-            return number_of_l2_slots > self.config.preconf_max_skipped_l2_slots;
+            return number_of_l2_slots > self.config.max_skipped_l2_slots(number_of_pending_txs);
         }
 
         true
@@ -326,3 +388,60 @@ impl BatchBuilder {
 }
 
 use common::shared::l2_tx_lists::PreBuiltTxList;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::Address;
+    use common::shared::l2_block_v2::L2BlockV2;
+
+    fn make_builder(max_batch_age_sec: Option<u64>) -> BatchBuilder {
+        let config = BatchBuilderConfig {
+            max_bytes_size_of_batch: 1000,
+            max_blocks_per_batch: 10,
+            l1_slot_duration_sec: 12,
+            max_time_shift_between_blocks_sec: 255,
+            max_anchor_height_offset: 64,
+            default_coinbase: Address::ZERO,
+            preconf_min_txs: 0,
+            preconf_min_txs_adaptive: None,
+            preconf_max_skipped_l2_slots: 0,
+            force_empty_block_after_skipped_l2_slots: None,
+            proposal_max_time_sec: 120,
+            max_forced_inclusions: 0,
+            max_batch_age_sec,
+            fork_switch_grace: None,
+        };
+        let mut builder = BatchBuilder::new(
+            config,
+            Arc::new(SlotClock::new(0, 0, 12, 32, 3000)),
+            Arc::new(Metrics::new()),
+        );
+        builder.current_proposal = Some(Proposal {
+            l2_blocks: vec![L2BlockV2::new_empty(1001, Address::ZERO, 1, 1_000_000)],
+            created_at_sec: 1000,
+            ..Default::default()
+        });
+        builder
+    }
+
+    #[test]
+    fn test_partial_batch_finalized_after_max_batch_age() {
+        let mut builder = make_builder(Some(30));
+
+        builder.finalize_if_needed_at(true, 1029);
+        assert!(builder.has_current_batch());
+
+        builder.finalize_if_needed_at(true, 1030);
+        assert!(!builder.has_current_batch());
+        assert_eq!(builder.get_number_of_batches(), 1);
+    }
+
+    #[test]
+    fn test_partial_batch_kept_without_max_batch_age() {
+        let mut builder = make_builder(None);
+
+        builder.finalize_if_needed_at(true, 1100);
+        assert!(builder.has_current_batch());
+    }
+}
@@ -1,3 +1,4 @@
+mod anchor_prefetch;
 mod async_submitter;
 mod batch_builder;
 pub mod bridge_handler;
@@ -13,6 +14,7 @@ use crate::{l1::execution_layer::ExecutionLayer, l2::taiko::Taiko};
 use alloy::consensus::Transaction as _;
 use alloy::primitives::aliases::U48;
 use alloy::primitives::{B256, FixedBytes};
+use anchor_prefetch::AnchorPrefetchCache;
 use anyhow::Error;
 use async_submitter::AsyncSubmitter;
 use batch_builder::BatchBuilder;
@@ -21,6 +23,7 @@ use common::{batch_builder::BatchBuilderConfig, shared::l2_slot_info_v2::L2SlotC
 use common::{
     l1::{ethereum_l1::EthereumL1, traits::ELTrait},
     l2::taiko_driver::{OperationType, models::BuildPreconfBlockResponse},
+    metrics::Metrics,
     shared::{
         anchor_block_info::AnchorBlockInfo, l2_block_v2::L2BlockV2Draft,
         l2_tx_lists::PreBuiltTxList,
@@ -32,15 +35,18 @@ use std::{net::SocketAddr, sync::Arc};
 use tokio::sync::Mutex;
 use tracing::{debug, error, info, warn};
 
-const MIN_ANCHOR_OFFSET: u64 = 2;
-
 pub struct BatchManager {
     batch_builder: BatchBuilder,
     async_submitter: AsyncSubmitter,
     bridge_handler: Arc<Mutex<BridgeHandler>>,
     ethereum_l1: Arc<EthereumL1<ExecutionLayer>>,
     pub taiko: Arc<Taiko>,
+    metrics: Arc<Metrics>,
     l1_height_lag: u64,
+    min_anchor_offset: u64,
+    anchor_confirmation_depth: u64,
+    anchor_prefetch: bool,
+    prefetched_anchor: AnchorPrefetchCache,
     last_finalized_block_hash: B256,
     last_finalized_block_number: Arc<AtomicU64>,
     /// L1→L2 return signal slot discovered during Pass 2 (L2Direct pre-sim).
@@ -59,9 +65,13 @@ impl BatchManager {
     #[allow(clippy::too_many_arguments)]
     pub async fn new(
         l1_height_lag: u64,
+        min_anchor_offset: u64,
+        anchor_confirmation_depth: u64,
+        anchor_prefetch: bool,
         config: BatchBuilderConfig,
         ethereum_l1: Arc<EthereumL1<ExecutionLayer>>,
         taiko: Arc<Taiko>,
+        metrics: Arc<Metrics>,
         cancel_token: CancellationToken,
         last_finalized_block_hash: B256,
         raiko_client: RaikoClient,
@@ -114,12 +124,21 @@ impl BatchManager {
         );
 
         Ok(Self {
-            batch_builder: BatchBuilder::new(config, ethereum_l1.slot_clock.clone()),
+            batch_builder: BatchBuilder::new(
+                config,
+                ethereum_l1.slot_clock.clone(),
+                metrics.clone(),
+            ),
             async_submitter,
             bridge_handler,
             ethereum_l1,
             taiko,
+            metrics,
             l1_height_lag,
+            min_anchor_offset,
+            anchor_confirmation_depth,
+            anchor_prefetch,
+            prefetched_anchor: AnchorPrefetchCache::default(),
             last_finalized_block_hash,
             last_finalized_block_number,
             pending_return_signal: None,
@@ -208,10 +227,11 @@ impl BatchManager {
     }
 
     pub fn should_new_block_be_created(
-        &self,
+        &mut self,
         pending_tx_list: &Option<PreBuiltTxList>,
         l2_slot_context: &L2SlotContext,
     ) -> bool {
+        self.batch_builder.observe_pending_txs(pending_tx_list);
         self.batch_builder.should_new_block_be_created(
             pending_tx_list,
             l2_slot_context.info.slot_timestamp(),
@@ -513,6 +533,50 @@ impl BatchManager {
     }
 
     async fn create_new_batch(&mut self) -> Result<u64, Error> {
+        let l1_slot = self.ethereum_l1.slot_clock.get_current_slot()?;
+        let anchor_block_info = match self.prefetched_anchor.take(l1_slot) {
+            Some(anchor_block_info) => {
+                debug!(
+                    "Using prefetched anchor block {} for the new batch",
+                    anchor_block_info.id()
+                );
+                anchor_block_info
+            }
+            None => self.fetch_anchor_block_info().await?,
+        };
+
+        let anchor_block_id = anchor_block_info.id();
+        // Use B256::ZERO as placeholder -- real last_finalized_block_hash is stamped at submission time
+        self.batch_builder
+            .create_new_batch(anchor_block_info, B256::ZERO);
+
+        Ok(anchor_block_id)
+    }
+
+    /// Computes the anchor block info for the next batch during an idle slot,
+    /// so the first block of the batch doesn't wait for the L1 and Geth queries.
+    pub async fn prefetch_anchor_block_info(&mut self) -> Result<(), Error> {
+        if !self.anchor_prefetch || self.batch_builder.has_current_batch() {
+            return Ok(());
+        }
+
+        let l1_slot = self.ethereum_l1.slot_clock.get_current_slot()?;
+        if self.prefetched_anchor.is_valid_for(l1_slot) {
+            return Ok(());
+        }
+
+        let anchor_block_info = self.fetch_anchor_block_info().await?;
+        debug!(
+            "Prefetched anchor block {} in L1 slot {}",
+            anchor_block_info.id(),
+            l1_slot
+        );
+        self.prefetched_anchor.store(l1_slot, anchor_block_info);
+
+        Ok(())
+    }
+
+    async fn fetch_anchor_block_info(&self) -> Result<AnchorBlockInfo, Error> {
         let last_anchor_id = self
             .taiko
             .l2_execution_layer()
@@ -522,20 +586,14 @@ impl BatchManager {
                 warn!("Failed to get last synced anchor block ID from Taiko Geth: {e}");
                 0
             });
-        let anchor_block_info = AnchorBlockInfo::from_chain_state(
+        AnchorBlockInfo::from_chain_state(
             self.ethereum_l1.execution_layer.common(),
             self.l1_height_lag,
             last_anchor_id,
-            MIN_ANCHOR_OFFSET,
+            self.min_anchor_offset,
+            self.anchor_confirmation_depth,
         )
-        .await?;
-
-        let anchor_block_id = anchor_block_info.id();
-        // Use B256::ZERO as placeholder -- real last_finalized_block_hash is stamped at submission time
-        self.batch_builder
-            .create_new_batch(anchor_block_info, B256::ZERO);
-
-        Ok(anchor_block_id)
+        .await
     }
 
     fn remove_last_l2_block(&mut self) {
@@ -550,6 +608,7 @@ impl BatchManager {
         self.batch_builder = batch_builder::BatchBuilder::new(
             self.batch_builder.get_config().clone(),
             self.ethereum_l1.slot_clock.clone(),
+            self.metrics.clone(),
         );
 
         Ok(())
@@ -46,6 +46,13 @@ pub struct Proposal {
     /// produce a different ciphertext and a different blob hash than the one
     /// Raiko proved against.
     pub blob_payload: Option<Vec<u8>>,
+
+    /// Ratio of the RLP encoded tx list bytes to the compressed manifest bytes,
+    /// set whenever the manifest is compressed.
+    pub compression_ratio: Option<f64>,
+
+    /// Unix time in seconds the proposal was created at, used to enforce `max_batch_age_sec`.
+    pub created_at_sec: u64,
 }
 
 impl Proposal {
@@ -88,6 +95,22 @@ impl Proposal {
         );
 
         self.total_bytes = manifest_data.len() as u64;
+        self.set_compression_ratio(manifest_data.len());
+    }
+
+    /// Records the compression ratio of the manifest compressed into `compressed_len` bytes.
+    pub fn set_compression_ratio(&mut self, compressed_len: usize) {
+        if compressed_len == 0 {
+            return;
+        }
+        let tx_list_bytes: u64 = self
+            .l2_blocks
+            .iter()
+            .map(|l2_block| l2_block.prebuilt_tx_list.get_bytes_length())
+            .sum();
+        #[allow(clippy::cast_precision_loss)]
+        let ratio = tx_list_bytes as f64 / compressed_len as f64;
+        self.compression_ratio = Some(ratio);
     }
 
     fn create_block_from_draft(&mut self, l2_draft_block: L2BlockV2Draft) -> L2BlockV2 {
@@ -120,3 +143,36 @@ impl Proposal {
         self.add_l2_block(l2_block)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::shared::l2_tx_lists::PreBuiltTxList;
+
+    fn l2_block_with_tx_list_bytes(bytes_length: u64) -> L2BlockV2 {
+        let tx_list = serde_json::from_str::<PreBuiltTxList>(&format!(
+            r#"{{"TxList":[],"EstimatedGasUsed":0,"BytesLength":{bytes_length}}}"#
+        ))
+        .expect("valid tx list json");
+        L2BlockV2::new_from(tx_list, 1000, Address::ZERO, 1, 1_000_000)
+    }
+
+    #[test]
+    fn test_compression_ratio() {
+        let mut proposal = Proposal {
+            l2_blocks: vec![
+                l2_block_with_tx_list_bytes(600),
+                l2_block_with_tx_list_bytes(400),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(proposal.compression_ratio, None);
+
+        // An empty compressed payload leaves the ratio unset
+        proposal.set_compression_ratio(0);
+        assert_eq!(proposal.compression_ratio, None);
+
+        proposal.set_compression_ratio(250);
+        assert_eq!(proposal.compression_ratio, Some(4.0));
+    }
+}
@@ -36,6 +36,8 @@ pub struct RealtimeConfig {
     pub privacy_symmetric_key: Option<[u8; 32]>,
     /// Maximum number of forced inclusions to consume per proposal.
     pub fi_max_per_proposal: u16,
+    /// When true, the anchor block info for the next batch is computed during idle slots.
+    pub anchor_prefetch: bool,
 }
 
 impl ConfigTrait for RealtimeConfig {
@@ -128,6 +130,10 @@ impl ConfigTrait for RealtimeConfig {
             .and_then(|v| v.parse().ok())
             .unwrap_or(4);
 
+        let anchor_prefetch = std::env::var("ANCHOR_PREFETCH")
+            .map(|v| v.to_lowercase() != "false" && v != "0")
+            .unwrap_or(false);
+
         Ok(RealtimeConfig {
             realtime_inbox,
             proposer_multicall,
@@ -147,6 +153,7 @@ impl ConfigTrait for RealtimeConfig {
             privacy_mode,
             privacy_symmetric_key,
             fi_max_per_proposal,
+            anchor_prefetch,
         })
     }
 }
@@ -181,6 +188,7 @@ impl fmt::Debug for RealtimeConfig {
                 &self.privacy_symmetric_key.as_ref().map(|_| "<redacted>"),
             )
             .field("fi_max_per_proposal", &self.fi_max_per_proposal)
+            .field("anchor_prefetch", &self.anchor_prefetch)
             .finish()
     }
 }
@@ -216,6 +224,7 @@ impl fmt::Display for RealtimeConfig {
             }
         )?;
         writeln!(f, "FI max per proposal: {}", self.fi_max_per_proposal)?;
+        writeln!(f, "Anchor prefetch: {}", self.anchor_prefetch)?;
         Ok(())
     }
 }
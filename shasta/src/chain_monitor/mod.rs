@@ -1,6 +1,11 @@
-use common::chain_monitor::ChainMonitor;
+use alloy::primitives::Address;
+use common::{chain_monitor::ChainMonitor, metrics::Metrics};
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
 use taiko_bindings::inbox::Inbox;
-use tracing::info;
+use tracing::{info, warn};
 
 pub type ShastaChainMonitor = ChainMonitor<Inbox::Proposed>;
 
@@ -10,3 +15,60 @@ pub fn print_proposed_info(event: &Inbox::Proposed) {
         event.id, event.proposer, event.endOfSubmissionWindowTimestamp
     );
 }
+
+/// Flags proposals submitted by third parties while we are preconfirming or submitting.
+/// Proposals from our own addresses are never treated as conflicts.
+pub struct ProposalConflictDetector {
+    own_proposers: Vec<Address>,
+    in_our_window: AtomicBool,
+    metrics: Arc<Metrics>,
+}
+
+impl ProposalConflictDetector {
+    pub fn new(own_proposers: Vec<Address>, metrics: Arc<Metrics>) -> Self {
+        Self {
+            own_proposers,
+            in_our_window: AtomicBool::new(false),
+            metrics,
+        }
+    }
+
+    pub fn set_in_our_window(&self, in_our_window: bool) {
+        self.in_our_window.store(in_our_window, Ordering::Relaxed);
+    }
+
+    pub fn is_conflict(&self, proposer: Address) -> bool {
+        self.in_our_window.load(Ordering::Relaxed) && !self.own_proposers.contains(&proposer)
+    }
+
+    pub fn handle_proposed(&self, event: &Inbox::Proposed) {
+        print_proposed_info(event);
+        if self.is_conflict(event.proposer) {
+            warn!(
+                "Competing proposal {} by {} during our sequencing window",
+                event.id, event.proposer
+            );
+            self.metrics.inc_conflicting_proposals();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_third_party_proposals_are_conflicts() {
+        let own = Address::repeat_byte(0x01);
+        let third_party = Address::repeat_byte(0x02);
+        let detector = ProposalConflictDetector::new(vec![own], Arc::new(Metrics::new()));
+
+        detector.set_in_our_window(true);
+        assert!(!detector.is_conflict(own));
+        assert!(detector.is_conflict(third_party));
+
+        detector.set_in_our_window(false);
+        assert!(!detector.is_conflict(own));
+        assert!(!detector.is_conflict(third_party));
+    }
+}
@@ -1,7 +1,9 @@
+use crate::l1::config::DEFAULT_OPERATOR_STATUS_MAX_AGE_SEC;
 use alloy::primitives::Address;
 use anyhow::Error;
 use common::config::{ConfigTrait, address_parse_error};
 use std::str::FromStr;
+use taiko_protocol::shasta::constants::{MAX_BLOCK_GAS_LIMIT, MIN_BLOCK_GAS_LIMIT};
 
 #[derive(Debug, Clone)]
 pub struct ShastaConfig {
@@ -10,9 +12,36 @@ pub struct ShastaConfig {
     pub handover_start_buffer_ms: u64,
     pub l1_height_lag: u64,
     pub propose_forced_inclusion: bool,
+    pub allow_forced_inclusion_head_rollback: bool,
+    pub disable_forced_inclusion: bool,
     pub simulate_not_submitting_at_the_end_of_epoch: bool,
     pub max_blocks_to_reanchor: u64,
+    pub driver_transient_error_max_retries: u64,
+    pub anchor_timestamp_fetch_max_retries: u64,
+    pub max_startup_recovery_blocks: Option<u64>,
+    pub reanchor_alert_threshold: Option<u64>,
+    pub verify_produced_block: bool,
+    pub disable_verifier: bool,
+    pub log_status_transitions: bool,
     pub ejection_grace_period_sec: u64,
+    pub own_proposer_addresses: Vec<Address>,
+    pub safe_mode_max_head_errors: Option<u64>,
+    pub safe_mode_window_min: u64,
+    pub safe_mode_state_path: String,
+    pub pending_tx_base_fee_min: Option<u64>,
+    pub pending_tx_base_fee_max: Option<u64>,
+    pub admin_api_token: Option<String>,
+    pub inbox_activation_max_wait_sec: Option<u64>,
+    pub reanchor_lazy_tx_fetch: bool,
+    pub nonce_gap_self_heal_timeout_sec: Option<u64>,
+    pub role_loss_reset_cooldown_heartbeats: u64,
+    pub pending_nonce_settle_max_wait_sec: u64,
+    pub submitter_grace_window_sec: u64,
+    pub verify_blobs_before_submit: bool,
+    pub operator_status_max_age_sec: u64,
+    pub anchor_offset_check_interval_heartbeats: u64,
+    pub fixed_block_gas_limit: Option<u64>,
+    pub operator_stall_max_epochs: u64,
 }
 
 impl ConfigTrait for ShastaConfig {
@@ -46,6 +75,22 @@ impl ConfigTrait for ShastaConfig {
             .parse::<bool>()
             .map_err(|e| anyhow::anyhow!("PROPOSE_FORCED_INCLUSION must be a boolean: {}", e))?;
 
+        let allow_forced_inclusion_head_rollback =
+            std::env::var("ALLOW_FORCED_INCLUSION_HEAD_ROLLBACK")
+                .unwrap_or("false".to_string())
+                .parse::<bool>()
+                .map_err(|e| {
+                    anyhow::anyhow!(
+                        "ALLOW_FORCED_INCLUSION_HEAD_ROLLBACK must be a boolean: {}",
+                        e
+                    )
+                })?;
+
+        let disable_forced_inclusion = std::env::var("DISABLE_FORCED_INCLUSION")
+            .unwrap_or("false".to_string())
+            .parse::<bool>()
+            .map_err(|e| anyhow::anyhow!("DISABLE_FORCED_INCLUSION must be a boolean: {}", e))?;
+
         let simulate_not_submitting_at_the_end_of_epoch =
             std::env::var("SIMULATE_NOT_SUBMITTING_AT_THE_END_OF_EPOCH")
                 .unwrap_or("false".to_string())
@@ -62,6 +107,55 @@ impl ConfigTrait for ShastaConfig {
             .parse::<u64>()
             .map_err(|e| anyhow::anyhow!("MAX_BLOCKS_TO_REANCHOR must be a number: {}", e))?;
 
+        let driver_transient_error_max_retries =
+            std::env::var("DRIVER_TRANSIENT_ERROR_MAX_RETRIES")
+                .unwrap_or("0".to_string())
+                .parse::<u64>()
+                .map_err(|e| {
+                    anyhow::anyhow!("DRIVER_TRANSIENT_ERROR_MAX_RETRIES must be a number: {}", e)
+                })?;
+
+        let anchor_timestamp_fetch_max_retries =
+            std::env::var("ANCHOR_TIMESTAMP_FETCH_MAX_RETRIES")
+                .unwrap_or("2".to_string())
+                .parse::<u64>()
+                .map_err(|e| {
+                    anyhow::anyhow!("ANCHOR_TIMESTAMP_FETCH_MAX_RETRIES must be a number: {}", e)
+                })?;
+
+        let max_startup_recovery_blocks = std::env::var("MAX_STARTUP_RECOVERY_BLOCKS")
+            .ok()
+            .map(|val| {
+                val.parse::<u64>().map_err(|e| {
+                    anyhow::anyhow!("MAX_STARTUP_RECOVERY_BLOCKS must be a number: {}", e)
+                })
+            })
+            .transpose()?;
+
+        let reanchor_alert_threshold = std::env::var("REANCHOR_ALERT_THRESHOLD")
+            .ok()
+            .map(|val| {
+                val.parse::<u64>().map_err(|e| {
+                    anyhow::anyhow!("REANCHOR_ALERT_THRESHOLD must be a number: {}", e)
+                })
+            })
+            .transpose()?;
+
+        let verify_produced_block = std::env::var("VERIFY_PRODUCED_BLOCK")
+            .unwrap_or("false".to_string())
+            .parse::<bool>()
+            .map_err(|e| anyhow::anyhow!("VERIFY_PRODUCED_BLOCK must be a boolean: {}", e))?;
+
+        let disable_verifier = std::env::var("DISABLE_VERIFIER")
+            .unwrap_or("false".to_string())
+            .parse::<bool>()
+            .map_err(|e| anyhow::anyhow!("DISABLE_VERIFIER must be a boolean: {}", e))?;
+
+        let log_status_transitions = std::env::var("LOG_STATUS_TRANSITIONS")
+            .unwrap_or("false".to_string())
+            .parse::<bool>()
+            .map_err(|e| anyhow::anyhow!("LOG_STATUS_TRANSITIONS must be a boolean: {}", e))?;
+
         let ejection_grace_period_ms = std::env::var("EJECTION_GRACE_PERIOD_MS")
             .unwrap_or("4000".to_string())
             .parse::<u64>()
@@ -69,15 +163,197 @@ impl ConfigTrait for ShastaConfig {
         let ejection_grace_period_sec =
             std::time::Duration::from_millis(ejection_grace_period_ms).as_secs();
 
+        const OWN_PROPOSER_ADDRESSES: &str = "OWN_PROPOSER_ADDRESSES";
+        let own_proposer_addresses = std::env::var(OWN_PROPOSER_ADDRESSES)
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|address| !address.is_empty())
+            .map(|address| {
+                Address::from_str(address)
+                    .map_err(|e| address_parse_error(OWN_PROPOSER_ADDRESSES, e, address))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let safe_mode_max_head_errors = std::env::var("SAFE_MODE_MAX_HEAD_ERRORS")
+            .ok()
+            .map(|val| {
+                val.parse::<u64>().map_err(|e| {
+                    anyhow::anyhow!("SAFE_MODE_MAX_HEAD_ERRORS must be a number: {}", e)
+                })
+            })
+            .transpose()?;
+
+        let safe_mode_window_min = std::env::var("SAFE_MODE_WINDOW_MIN")
+            .unwrap_or("30".to_string())
+            .parse::<u64>()
+            .map_err(|e| anyhow::anyhow!("SAFE_MODE_WINDOW_MIN must be a number: {}", e))?;
+
+        let safe_mode_state_path =
+            std::env::var("SAFE_MODE_STATE_PATH").unwrap_or("safe_mode_head_errors".to_string());
+
+        let pending_tx_base_fee_min = std::env::var("PENDING_TX_BASE_FEE_MIN_WEI")
+            .ok()
+            .map(|val| {
+                val.parse::<u64>().map_err(|e| {
+                    anyhow::anyhow!("PENDING_TX_BASE_FEE_MIN_WEI must be a number: {}", e)
+                })
+            })
+            .transpose()?;
+
+        let pending_tx_base_fee_max = std::env::var("PENDING_TX_BASE_FEE_MAX_WEI")
+            .ok()
+            .map(|val| {
+                val.parse::<u64>().map_err(|e| {
+                    anyhow::anyhow!("PENDING_TX_BASE_FEE_MAX_WEI must be a number: {}", e)
+                })
+            })
+            .transpose()?;
+
+        if let (Some(min), Some(max)) = (pending_tx_base_fee_min, pending_tx_base_fee_max)
+            && min > max
+        {
+            return Err(anyhow::anyhow!(
+                "PENDING_TX_BASE_FEE_MIN_WEI ({}) must not be greater than PENDING_TX_BASE_FEE_MAX_WEI ({})",
+                min,
+                max
+            ));
+        }
+
+        // Admin endpoints are only served when a token is configured
+        let admin_api_token = std::env::var("ADMIN_API_TOKEN")
+            .ok()
+            .filter(|token| !token.is_empty());
+
+        // Waits for the Inbox activation indefinitely when not set
+        let inbox_activation_max_wait_sec = std::env::var("INBOX_ACTIVATION_MAX_WAIT_SEC")
+            .ok()
+            .map(|val| {
+                val.parse::<u64>().map_err(|e| {
+                    anyhow::anyhow!("INBOX_ACTIVATION_MAX_WAIT_SEC must be a number: {}", e)
+                })
+            })
+            .transpose()?;
+
+        let reanchor_lazy_tx_fetch = std::env::var("REANCHOR_LAZY_TX_FETCH")
+            .unwrap_or("false".to_string())
+            .parse::<bool>()
+            .map_err(|e| anyhow::anyhow!("REANCHOR_LAZY_TX_FETCH must be a boolean: {}", e))?;
+
+        // Stuck transactions found at startup are only waited for when not set
+        let nonce_gap_self_heal_timeout_sec = std::env::var("NONCE_GAP_SELF_HEAL_TIMEOUT_SEC")
+            .ok()
+            .map(|val| {
+                val.parse::<u64>().map_err(|e| {
+                    anyhow::anyhow!("NONCE_GAP_SELF_HEAL_TIMEOUT_SEC must be a number: {}", e)
+                })
+            })
+            .transpose()?;
+
+        let role_loss_reset_cooldown_heartbeats =
+            std::env::var("ROLE_LOSS_RESET_COOLDOWN_HEARTBEATS")
+                .unwrap_or("3".to_string())
+                .parse::<u64>()
+                .map_err(|e| {
+                    anyhow::anyhow!(
+                        "ROLE_LOSS_RESET_COOLDOWN_HEARTBEATS must be a number: {}",
+                        e
+                    )
+                })?;
+
+        let pending_nonce_settle_max_wait_sec = std::env::var("PENDING_NONCE_SETTLE_MAX_WAIT_SEC")
+            .unwrap_or("12".to_string())
+            .parse::<u64>()
+            .map_err(|e| {
+                anyhow::anyhow!("PENDING_NONCE_SETTLE_MAX_WAIT_SEC must be a number: {}", e)
+            })?;
+
+        let submitter_grace_window_sec = std::env::var("SUBMITTER_GRACE_WINDOW_SEC")
+            .unwrap_or("0".to_string())
+            .parse::<u64>()
+            .map_err(|e| anyhow::anyhow!("SUBMITTER_GRACE_WINDOW_SEC must be a number: {}", e))?;
+
+        let verify_blobs_before_submit = std::env::var("VERIFY_BLOBS_BEFORE_SUBMIT")
+            .unwrap_or("false".to_string())
+            .parse::<bool>()
+            .map_err(|e| anyhow::anyhow!("VERIFY_BLOBS_BEFORE_SUBMIT must be a boolean: {}", e))?;
+
+        let operator_status_max_age_sec = std::env::var("OPERATOR_STATUS_MAX_AGE_SEC")
+            .unwrap_or(DEFAULT_OPERATOR_STATUS_MAX_AGE_SEC.to_string())
+            .parse::<u64>()
+            .map_err(|e| anyhow::anyhow!("OPERATOR_STATUS_MAX_AGE_SEC must be a number: {}", e))?;
+
+        let anchor_offset_check_interval_heartbeats =
+            std::env::var("ANCHOR_OFFSET_CHECK_INTERVAL_HEARTBEATS")
+                .unwrap_or("1".to_string())
+                .parse::<u64>()
+                .map_err(|e| {
+                    anyhow::anyhow!(
+                        "ANCHOR_OFFSET_CHECK_INTERVAL_HEARTBEATS must be a number: {}",
+                        e
+                    )
+                })?;
+
+        // Testing only, pins the gas limit of every block instead of deriving it from the parent
+        let fixed_block_gas_limit = std::env::var("FIXED_BLOCK_GAS_LIMIT")
+            .ok()
+            .map(|val| {
+                let gas_limit = val.parse::<u64>().map_err(|e| {
+                    anyhow::anyhow!("FIXED_BLOCK_GAS_LIMIT must be a number: {}", e)
+                })?;
+                if !(MIN_BLOCK_GAS_LIMIT..=MAX_BLOCK_GAS_LIMIT).contains(&gas_limit) {
+                    return Err(anyhow::anyhow!(
+                        "FIXED_BLOCK_GAS_LIMIT must be between {} and {}, got {}",
+                        MIN_BLOCK_GAS_LIMIT,
+                        MAX_BLOCK_GAS_LIMIT,
+                        gas_limit
+                    ));
+                }
+                Ok(gas_limit)
+            })
+            .transpose()?;
+
+        let operator_stall_max_epochs = std::env::var("OPERATOR_STALL_MAX_EPOCHS")
+            .unwrap_or("0".to_string())
+            .parse::<u64>()
+            .map_err(|e| anyhow::anyhow!("OPERATOR_STALL_MAX_EPOCHS must be a number: {}", e))?;
+
         Ok(ShastaConfig {
             shasta_inbox,
             handover_window_slots,
             handover_start_buffer_ms,
             l1_height_lag,
             propose_forced_inclusion,
+            allow_forced_inclusion_head_rollback,
+            disable_forced_inclusion,
             simulate_not_submitting_at_the_end_of_epoch,
             max_blocks_to_reanchor,
+            driver_transient_error_max_retries,
+            anchor_timestamp_fetch_max_retries,
+            max_startup_recovery_blocks,
+            reanchor_alert_threshold,
+            verify_produced_block,
+            disable_verifier,
+            log_status_transitions,
             ejection_grace_period_sec,
+            own_proposer_addresses,
+            safe_mode_max_head_errors,
+            safe_mode_window_min,
+            safe_mode_state_path,
+            pending_tx_base_fee_min,
+            pending_tx_base_fee_max,
+            admin_api_token,
+            inbox_activation_max_wait_sec,
+            reanchor_lazy_tx_fetch,
+            nonce_gap_self_heal_timeout_sec,
+            role_loss_reset_cooldown_heartbeats,
+            pending_nonce_settle_max_wait_sec,
+            submitter_grace_window_sec,
+            verify_blobs_before_submit,
+            operator_status_max_age_sec,
+            anchor_offset_check_interval_heartbeats,
+            fixed_block_gas_limit,
+            operator_stall_max_epochs,
         })
     }
 }
@@ -98,16 +374,146 @@ impl fmt::Display for ShastaConfig {
             "propose forced inclusion: {}",
             self.propose_forced_inclusion
         )?;
+        writeln!(
+            f,
+            "allow forced inclusion head rollback: {}",
+            self.allow_forced_inclusion_head_rollback
+        )?;
+        writeln!(
+            f,
+            "disable forced inclusion: {}",
+            self.disable_forced_inclusion
+        )?;
         writeln!(
             f,
             "simulate not submitting at the end of epoch: {}",
             self.simulate_not_submitting_at_the_end_of_epoch
         )?;
+        writeln!(
+            f,
+            "driver transient error max retries: {}",
+            self.driver_transient_error_max_retries
+        )?;
+        writeln!(
+            f,
+            "anchor timestamp fetch max retries: {}",
+            self.anchor_timestamp_fetch_max_retries
+        )?;
+        writeln!(
+            f,
+            "max startup recovery blocks: {}",
+            self.max_startup_recovery_blocks
+                .map_or("unlimited".to_string(), |max_blocks| max_blocks.to_string())
+        )?;
+        writeln!(
+            f,
+            "reanchor alert threshold: {}",
+            self.reanchor_alert_threshold
+                .map_or("disabled".to_string(), |threshold| format!(
+                    "{threshold} blocks"
+                ))
+        )?;
+        writeln!(f, "verify produced block: {}", self.verify_produced_block)?;
+        writeln!(f, "disable verifier: {}", self.disable_verifier)?;
+        writeln!(f, "log status transitions: {}", self.log_status_transitions)?;
         writeln!(
             f,
             "ejection grace period: {}s",
             self.ejection_grace_period_sec
         )?;
+        writeln!(
+            f,
+            "own proposer addresses: {:?}",
+            self.own_proposer_addresses
+        )?;
+        writeln!(
+            f,
+            "safe mode: {}",
+            self.safe_mode_max_head_errors
+                .map_or("disabled".to_string(), |max_errors| format!(
+                    "{} unexpected head errors in {} min, state: {}",
+                    max_errors, self.safe_mode_window_min, self.safe_mode_state_path
+                ))
+        )?;
+        writeln!(
+            f,
+            "pending tx base fee clamp: min {}, max {}",
+            self.pending_tx_base_fee_min
+                .map_or("disabled".to_string(), |min| format!("{min}wei")),
+            self.pending_tx_base_fee_max
+                .map_or("disabled".to_string(), |max| format!("{max}wei"))
+        )?;
+        writeln!(
+            f,
+            "admin API: {}",
+            if self.admin_api_token.is_some() {
+                "enabled"
+            } else {
+                "disabled"
+            }
+        )?;
+        writeln!(
+            f,
+            "inbox activation max wait: {}",
+            self.inbox_activation_max_wait_sec
+                .map_or("unlimited".to_string(), |sec| format!("{sec}s"))
+        )?;
+        writeln!(f, "reanchor lazy tx fetch: {}", self.reanchor_lazy_tx_fetch)?;
+        writeln!(
+            f,
+            "nonce gap self heal timeout: {}",
+            self.nonce_gap_self_heal_timeout_sec
+                .map_or("disabled".to_string(), |sec| format!("{sec}s"))
+        )?;
+        writeln!(
+            f,
+            "role loss reset cooldown: {} heartbeats",
+            self.role_loss_reset_cooldown_heartbeats
+        )?;
+        writeln!(
+            f,
+            "pending nonce settle max wait: {}s",
+            self.pending_nonce_settle_max_wait_sec
+        )?;
+        writeln!(
+            f,
+            "submitter grace window: {}",
+            if self.submitter_grace_window_sec == 0 {
+                "disabled".to_string()
+            } else {
+                format!("{}s", self.submitter_grace_window_sec)
+            }
+        )?;
+        writeln!(
+            f,
+            "verify blobs before submit: {}",
+            self.verify_blobs_before_submit
+        )?;
+        writeln!(
+            f,
+            "operator status max age: {}s",
+            self.operator_status_max_age_sec
+        )?;
+        writeln!(
+            f,
+            "anchor offset check interval: {} heartbeats",
+            self.anchor_offset_check_interval_heartbeats
+        )?;
+        writeln!(
+            f,
+            "fixed block gas limit: {}",
+            self.fixed_block_gas_limit
+                .map_or("derived from parent".to_string(), |gas| gas.to_string())
+        )?;
+        writeln!(
+            f,
+            "operator stall max epochs: {}",
+            if self.operator_stall_max_epochs == 0 {
+                "disabled".to_string()
+            } else {
+                self.operator_stall_max_epochs.to_string()
+            }
+        )?;
         Ok(())
     }
 }
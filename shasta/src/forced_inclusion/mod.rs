@@ -1,9 +1,11 @@
 use crate::l1::execution_layer::ExecutionLayer;
-use alloy::rpc::types::Transaction;
+use alloy::{primitives::B256, rpc::types::Transaction};
 use anyhow::Error;
+use common::metrics::Metrics;
 use common::shared::l2_tx_lists::convert_tx_envelopes_to_transactions;
 use common::{blob::blob_parser::get_bytes_from_blobs, l1::ethereum_l1::EthereumL1};
 use std::sync::Arc;
+use taiko_bindings::inbox::IForcedInclusionStore::ForcedInclusion as InboxForcedInclusion;
 
 use taiko_protocol::shasta::manifest::DerivationSourceManifest;
 
@@ -13,22 +15,78 @@ pub struct InboxForcedInclusionState {
     pub tail: u64,
 }
 
+/// Tracks the highest forced inclusion head read from the inbox.
+///
+/// Every forced inclusion below that head has been consumed on L1, so a head read
+/// lower than it means a reorg or a lagging RPC node, not an actual rollback.
+struct ForcedInclusionHeadGuard {
+    highest_head: u64,
+    allow_head_rollback: bool,
+}
+
+impl ForcedInclusionHeadGuard {
+    fn new(highest_head: u64, allow_head_rollback: bool) -> Self {
+        Self {
+            highest_head,
+            allow_head_rollback,
+        }
+    }
+
+    /// Returns the queue index to continue from for the given head,
+    /// and whether the head moved backward.
+    fn resolve(&mut self, head: u64) -> (u64, bool) {
+        if head >= self.highest_head {
+            self.highest_head = head;
+            return (head, false);
+        }
+
+        if self.allow_head_rollback {
+            self.highest_head = head;
+            (head, true)
+        } else {
+            (self.highest_head, true)
+        }
+    }
+}
+
 pub struct ForcedInclusion {
     ethereum_l1: Arc<EthereumL1<ExecutionLayer>>,
     index: u64,
+    head_guard: ForcedInclusionHeadGuard,
+    metrics: Arc<Metrics>,
 }
 
 impl ForcedInclusion {
-    pub async fn new(ethereum_l1: Arc<EthereumL1<ExecutionLayer>>) -> Result<Self, Error> {
+    pub async fn new(
+        ethereum_l1: Arc<EthereumL1<ExecutionLayer>>,
+        metrics: Arc<Metrics>,
+        allow_head_rollback: bool,
+    ) -> Result<Self, Error> {
         let index = ethereum_l1
             .execution_layer
             .get_forced_inclusion_head()
             .await?;
-        Ok(Self { ethereum_l1, index })
+        Ok(Self {
+            ethereum_l1,
+            index,
+            head_guard: ForcedInclusionHeadGuard::new(index, allow_head_rollback),
+            metrics,
+        })
     }
 
-    pub fn new_with_index(ethereum_l1: Arc<EthereumL1<ExecutionLayer>>, index: u64) -> Self {
-        Self { ethereum_l1, index }
+    pub fn new_with_index(
+        ethereum_l1: Arc<EthereumL1<ExecutionLayer>>,
+        index: u64,
+        metrics: Arc<Metrics>,
+        allow_head_rollback: bool,
+    ) -> Self {
+        // The index can be an unsafe head, so it is not used as the known on-chain head
+        Self {
+            ethereum_l1,
+            index,
+            head_guard: ForcedInclusionHeadGuard::new(0, allow_head_rollback),
+            metrics,
+        }
     }
 
     pub fn set_index(&mut self, index: u64) {
@@ -41,13 +99,73 @@ impl ForcedInclusion {
             .execution_layer
             .get_forced_inclusion_head()
             .await?;
-        self.index = head;
+
+        let (index, moved_backward) = self.head_guard.resolve(head);
+        if moved_backward {
+            self.metrics.inc_forced_inclusion_head_regressions();
+            if self.head_guard.allow_head_rollback {
+                tracing::warn!(
+                    "Forced inclusion head moved backward to {}, current index: {}. Rollback allowed, using the new head",
+                    head,
+                    self.index
+                );
+            } else {
+                tracing::error!(
+                    "Forced inclusion head moved backward to {}, current index: {}. Keeping index at {}",
+                    head,
+                    self.index,
+                    index
+                );
+            }
+        }
+        self.index = index;
 
         tracing::debug!("sync_queue_index_with_head head: {}", head);
-        Ok(head)
+        Ok(index)
+    }
+
+    /// Syncs the queue index with the inbox head after the builder dropped its unsent proposals.
+    ///
+    /// The local index is ahead of the head by the forced inclusions consumed locally. Those in
+    /// dropped proposals have to be consumed again, while those in a proposal still in flight
+    /// will be consumed on L1 once it lands, so they must not be rebuilt.
+    pub async fn reconcile_with_head_after_reset(
+        &mut self,
+        dropped_forced_inclusions: u64,
+        proposal_in_flight: bool,
+    ) -> Result<u64, Error> {
+        let expected_index = self.index.saturating_sub(dropped_forced_inclusions);
+        let head_index = self.sync_queue_index_with_head().await?;
+
+        let index = reconcile_index_after_reset(expected_index, head_index, proposal_in_flight);
+        if expected_index != head_index {
+            tracing::warn!(
+                "Forced inclusion index mismatch after reset: expected {}, inbox head {}, dropped {}, proposal in flight: {}. Continuing from {}",
+                expected_index,
+                head_index,
+                dropped_forced_inclusions,
+                proposal_in_flight,
+                index
+            );
+            self.metrics.inc_forced_inclusion_reset_discrepancies();
+        }
+        self.index = index;
+        Ok(index)
+    }
+
+    pub async fn has_pending_forced_inclusion(&self) -> Result<bool, Error> {
+        let tail = self
+            .ethereum_l1
+            .execution_layer
+            .get_forced_inclusion_tail()
+            .await?;
+        Ok(self.index < tail)
     }
 
-    pub async fn decode_current_forced_inclusion(&self) -> Result<Option<Vec<Transaction>>, Error> {
+    /// Decodes the forced inclusion at the current index. Returns None when the queue is empty.
+    pub async fn decode_current_forced_inclusion(
+        &mut self,
+    ) -> Result<Option<Vec<Transaction>>, Error> {
         let tail = self
             .ethereum_l1
             .execution_layer
@@ -67,42 +185,14 @@ impl ForcedInclusion {
             .get_forced_inclusion(self.index)
             .await?;
 
-        let blob_bytes = get_bytes_from_blobs(
-            self.ethereum_l1.clone(),
-            forced_inclusion.blobSlice.timestamp.to::<u64>(),
-            forced_inclusion.blobSlice.blobHashes,
+        let ethereum_l1 = self.ethereum_l1.clone();
+        let transactions = decode_forced_inclusion(
+            forced_inclusion,
+            self.index,
+            &self.metrics,
+            |timestamp, blob_hashes| get_bytes_from_blobs(ethereum_l1, timestamp, blob_hashes),
         )
         .await?;
-
-        // Extract transactions from the blob bytes. If any step fails, return an empty transaction vector
-        self.extract_transactions_from_blob_bytes(
-            &blob_bytes,
-            forced_inclusion.blobSlice.offset.to::<usize>(),
-        )
-        .await
-        .or_else(|err| {
-            tracing::warn!(
-                error = ?err,
-                "Failed to extract transactions from blob bytes; returning empty transaction vector"
-            );
-            Ok(Some(vec![]))
-        })
-    }
-
-    async fn extract_transactions_from_blob_bytes(
-        &self,
-        blob_bytes: &[u8],
-        offset: usize,
-    ) -> Result<Option<Vec<Transaction>>, Error> {
-        let blocks = DerivationSourceManifest::decompress_and_decode(blob_bytes, offset)?.blocks;
-
-        let [single_block]: [_; 1] = blocks.try_into().map_err(|b: Vec<_>| {
-            anyhow::anyhow!(
-                "Expected exactly one block in forced inclusion manifest, found {}",
-                b.len()
-            )
-        })?;
-        let transactions = convert_tx_envelopes_to_transactions(single_block.transactions)?;
         Ok(Some(transactions))
     }
 
@@ -131,3 +221,223 @@ impl ForcedInclusion {
         }
     }
 }
+
+/// Picks the queue index to continue from after a reset. `head_index` is the index resolved
+/// from the inbox head. Forced inclusions of a proposal still in flight are already taken,
+/// so the index is kept past them to avoid consuming them twice.
+fn reconcile_index_after_reset(
+    expected_index: u64,
+    head_index: u64,
+    proposal_in_flight: bool,
+) -> u64 {
+    if proposal_in_flight && expected_index > head_index {
+        expected_index
+    } else {
+        head_index
+    }
+}
+
+/// Decodes the forced inclusion manifest and returns the transactions of its single block.
+/// The manifest size is recorded before it is validated, so oversized forced inclusions are visible.
+fn extract_transactions_from_blob_bytes(
+    blob_bytes: &[u8],
+    offset: usize,
+    metrics: &Metrics,
+) -> Result<Option<Vec<Transaction>>, Error> {
+    let blocks = DerivationSourceManifest::decompress_and_decode(blob_bytes, offset)?.blocks;
+
+    let tx_count: usize = blocks.iter().map(|block| block.transactions.len()).sum();
+    tracing::debug!(
+        "Decoded forced inclusion manifest with {} blocks and {} transactions",
+        blocks.len(),
+        tx_count
+    );
+    metrics.observe_forced_inclusion_manifest(blocks.len(), tx_count);
+
+    let [single_block]: [_; 1] = blocks.try_into().map_err(|b: Vec<_>| {
+        anyhow::anyhow!(
+            "Expected exactly one block in forced inclusion manifest, found {}",
+            b.len()
+        )
+    })?;
+    let transactions = convert_tx_envelopes_to_transactions(single_block.transactions)?;
+    Ok(Some(transactions))
+}
+
+/// Decodes the transactions of a forced inclusion, fetching its blobs with `get_blob_bytes`.
+/// A malformed entry without blob hashes can't be decoded, so no transactions are returned
+/// without fetching any blob. It is still built as an empty forced inclusion block, keeping
+/// the local index in line with the inbox head.
+async fn decode_forced_inclusion<F, Fut>(
+    forced_inclusion: InboxForcedInclusion,
+    index: u64,
+    metrics: &Metrics,
+    get_blob_bytes: F,
+) -> Result<Vec<Transaction>, Error>
+where
+    F: FnOnce(u64, Vec<B256>) -> Fut,
+    Fut: Future<Output = Result<Vec<u8>, Error>>,
+{
+    if forced_inclusion.blobSlice.blobHashes.is_empty() {
+        tracing::warn!(
+            "Forced inclusion at index {} has no blob hashes; building an empty forced inclusion block",
+            index
+        );
+        metrics.inc_forced_inclusion_empty_blob_hashes();
+        return Ok(vec![]);
+    }
+
+    let blob_bytes = get_blob_bytes(
+        forced_inclusion.blobSlice.timestamp.to::<u64>(),
+        forced_inclusion.blobSlice.blobHashes,
+    )
+    .await?;
+
+    // Extract transactions from the blob bytes. If any step fails, return an empty transaction vector
+    extract_transactions_from_blob_bytes(
+        &blob_bytes,
+        forced_inclusion.blobSlice.offset.to::<usize>(),
+        metrics,
+    )
+    .map(Option::unwrap_or_default)
+    .or_else(|err| {
+        tracing::warn!(
+            error = ?err,
+            "Failed to extract transactions from blob bytes; returning empty transaction vector"
+        );
+        Ok(vec![])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use taiko_protocol::shasta::manifest::BlockManifest;
+
+    #[tokio::test]
+    async fn test_forced_inclusion_with_empty_blob_hashes_is_empty_block() {
+        let metrics = Metrics::new();
+
+        let decoded =
+            decode_forced_inclusion(InboxForcedInclusion::default(), 3, &metrics, |_, _| async {
+                Err::<Vec<u8>, _>(anyhow::anyhow!(
+                    "no blob must be fetched without blob hashes"
+                ))
+            })
+            .await
+            .expect("decoding failed");
+
+        assert!(decoded.is_empty());
+        assert!(
+            metrics
+                .gather()
+                .contains("catalyst_forced_inclusion_empty_blob_hashes_total 1")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_forced_inclusion_with_blob_hashes_is_decoded() {
+        let manifest = DerivationSourceManifest {
+            blocks: vec![block_manifest(2)],
+        };
+        let blob_bytes = manifest
+            .encode_and_compress()
+            .expect("failed to encode manifest");
+        let mut forced_inclusion = InboxForcedInclusion::default();
+        forced_inclusion
+            .blobSlice
+            .blobHashes
+            .push(B256::repeat_byte(1));
+
+        let decoded = decode_forced_inclusion(forced_inclusion, 3, &Metrics::new(), |_, hashes| {
+            assert_eq!(hashes, vec![B256::repeat_byte(1)]);
+            async move { Ok::<_, Error>(blob_bytes) }
+        })
+        .await
+        .expect("decoding failed");
+
+        assert_eq!(decoded.len(), 2);
+    }
+
+    fn block_manifest(tx_count: usize) -> BlockManifest {
+        use alloy::consensus::{Signed, TxEnvelope, TxLegacy};
+        use alloy::primitives::{Address, Signature};
+
+        let tx = TxEnvelope::Legacy(Signed::new_unchecked(
+            TxLegacy::default(),
+            Signature::test_signature(),
+            B256::ZERO,
+        ));
+        BlockManifest {
+            timestamp: 1_000,
+            coinbase: Address::ZERO,
+            anchor_block_number: 100,
+            gas_limit: 30_000_000,
+            transactions: vec![tx; tx_count],
+        }
+    }
+
+    #[test]
+    fn test_manifest_size_metrics() {
+        let manifest = DerivationSourceManifest {
+            blocks: vec![block_manifest(2), block_manifest(3)],
+        };
+        let blob_bytes = manifest
+            .encode_and_compress()
+            .expect("failed to encode manifest");
+        let metrics = Metrics::new();
+
+        // More than one block is rejected, but still recorded
+        assert!(extract_transactions_from_blob_bytes(&blob_bytes, 0, &metrics).is_err());
+
+        let output = metrics.gather();
+        assert!(output.contains("catalyst_forced_inclusion_manifest_block_count_sum 2"));
+        assert!(output.contains("catalyst_forced_inclusion_manifest_tx_count_sum 5"));
+        assert!(output.contains("catalyst_forced_inclusion_manifest_tx_count_count 1"));
+    }
+
+    #[test]
+    fn test_head_guard_moves_forward() {
+        let mut guard = ForcedInclusionHeadGuard::new(5, false);
+        assert_eq!(guard.resolve(5), (5, false));
+        assert_eq!(guard.resolve(8), (8, false));
+    }
+
+    #[test]
+    fn test_head_guard_refuses_backward_head() {
+        let mut guard = ForcedInclusionHeadGuard::new(0, false);
+        assert_eq!(guard.resolve(10), (10, false));
+
+        // Subsequent head read is lower than the current index
+        assert_eq!(guard.resolve(7), (10, true));
+        // Recovered head continues from the highest known head
+        assert_eq!(guard.resolve(10), (10, false));
+        assert_eq!(guard.resolve(11), (11, false));
+    }
+
+    #[test]
+    fn test_head_guard_allows_backward_head_when_configured() {
+        let mut guard = ForcedInclusionHeadGuard::new(10, true);
+        assert_eq!(guard.resolve(7), (7, true));
+        assert_eq!(guard.resolve(8), (8, false));
+    }
+
+    #[test]
+    fn test_reset_with_forced_inclusion_is_not_consumed_twice() {
+        // Head at 5, the dropped proposal consumed forced inclusion 5 locally
+        let local_index = 6;
+        let expected_index = local_index - 1;
+
+        // Nothing in flight, the forced inclusion is rebuilt from the head
+        assert_eq!(reconcile_index_after_reset(expected_index, 5, false), 5);
+
+        // A proposal in flight already took forced inclusion 4, the head doesn't reflect it yet
+        assert_eq!(reconcile_index_after_reset(expected_index, 4, true), 5);
+
+        // The in-flight proposal failed, so forced inclusion 4 is not skipped
+        assert_eq!(reconcile_index_after_reset(expected_index, 4, false), 4);
+
+        // Another proposer consumed further forced inclusions, continue from the head
+        assert_eq!(reconcile_index_after_reset(expected_index, 7, true), 7);
+    }
+}
@@ -7,8 +7,13 @@ pub struct ContractAddresses {
     pub proposer_checker: Address,
 }
 
+/// Operators read from L1 are not trusted for longer than this, in seconds.
+pub const DEFAULT_OPERATOR_STATUS_MAX_AGE_SEC: u64 = 60;
+
 pub struct EthereumL1Config {
     pub shasta_inbox: Address,
+    pub verify_blobs_before_submit: bool,
+    pub operator_status_max_age_sec: u64,
 }
 
 impl TryFrom<ShastaConfig> for EthereumL1Config {
@@ -17,6 +22,8 @@ impl TryFrom<ShastaConfig> for EthereumL1Config {
     fn try_from(config: ShastaConfig) -> Result<Self, Self::Error> {
         Ok(EthereumL1Config {
             shasta_inbox: config.shasta_inbox,
+            verify_blobs_before_submit: config.verify_blobs_before_submit,
+            operator_status_max_age_sec: config.operator_status_max_age_sec,
         })
     }
 }
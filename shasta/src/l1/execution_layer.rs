@@ -10,17 +10,20 @@ use alloy::{
     rpc::client::BatchRequest,
     sol_types::SolCall,
 };
-use anyhow::{Context, Error, anyhow};
+use anyhow::{Context, Error};
 use common::{
     l1::{
+        height_cross_check::L1HeightCrossCheck,
         traits::{ELTrait, PreconferProvider},
         transaction_error::TransactionError,
     },
     metrics::Metrics,
     shared::{
-        alloy_tools, execution_layer::ExecutionLayer as ExecutionLayerCommon,
-        l2_block_v2::L2BlockV2, transaction_monitor::TransactionMonitor,
+        execution_layer::ExecutionLayer as ExecutionLayerCommon,
+        l2_block_v2::L2BlockV2,
+        transaction_monitor::{TransactionMonitor, log_submitted_tx_hash},
     },
+    signer::Signer,
 };
 use pacaya::l1::{
     operators_cache::{OperatorsCache, OperatorsCacheState},
@@ -28,6 +31,7 @@ use pacaya::l1::{
 };
 use serde_json::json;
 use std::sync::{Arc, OnceLock};
+use std::time::Duration;
 use taiko_bindings::inbox::IInbox::Config;
 use taiko_bindings::inbox::{
     IForcedInclusionStore::ForcedInclusion,
@@ -46,6 +50,11 @@ pub struct ExecutionLayer {
     operators_cache: OperatorsCache,
     extra_gas_percentage: u64,
     slot_duration_sec: u64,
+    log_submitted_tx_hash: bool,
+    signer: Arc<Signer>,
+    verify_blobs_before_submit: bool,
+    tx_fee_bump_basis_points: u128,
+    metrics: Arc<Metrics>,
 }
 
 impl ELTrait for ExecutionLayer {
@@ -56,19 +65,15 @@ impl ELTrait for ExecutionLayer {
         transaction_error_channel: Sender<TransactionError>,
         metrics: Arc<Metrics>,
     ) -> Result<Self, Error> {
-        let provider = alloy_tools::construct_alloy_provider(
-            &common_config.signer,
-            common_config
-                .execution_rpc_urls
-                .first()
-                .ok_or_else(|| anyhow!("L1 RPC URL is required"))?,
-        )
-        .await
-        .context("construct_alloy_provider")?;
-        let common =
-            ExecutionLayerCommon::new(provider.clone(), common_config.signer.get_address())
-                .await
-                .context("ExecutionLayerCommon::new")?;
+        let mut common = ExecutionLayerCommon::from_config(&common_config, metrics.clone())
+            .await
+            .context("ExecutionLayerCommon::from_config")?;
+        let provider = common.provider();
+        if let Some(height_cross_check) =
+            L1HeightCrossCheck::from_config(&common_config, metrics.clone()).await?
+        {
+            common.set_height_cross_check(height_cross_check);
+        }
 
         let transaction_monitor = TransactionMonitor::new(
             provider.clone(),
@@ -94,8 +99,11 @@ impl ELTrait for ExecutionLayer {
             proposer_checker: shasta_config.proposerChecker,
         };
 
-        let operators_cache =
-            OperatorsCache::new(provider.clone(), contract_addresses.proposer_checker);
+        let operators_cache = OperatorsCache::new(
+            provider.clone(),
+            contract_addresses.proposer_checker,
+            Duration::from_secs(specific_config.operator_status_max_age_sec),
+        );
 
         Ok(Self {
             common,
@@ -106,6 +114,11 @@ impl ELTrait for ExecutionLayer {
             operators_cache,
             extra_gas_percentage: common_config.extra_gas_percentage,
             slot_duration_sec: common_config.slot_duration_sec,
+            log_submitted_tx_hash: common_config.log_submitted_tx_hash,
+            signer: common_config.signer.clone(),
+            verify_blobs_before_submit: specific_config.verify_blobs_before_submit,
+            tx_fee_bump_basis_points: u128::from(common_config.tx_fee_bump_basis_points),
+            metrics,
         })
     }
 
@@ -185,18 +198,29 @@ impl ExecutionLayer {
         let tx_builder = ProposalTxBuilder::new(
             self.provider.clone(),
             self.extra_gas_percentage,
+            self.transaction_monitor.fee_history_cache(),
             l2_blocks,
             self.common().preconfer_address(),
             self.contract_addresses.shasta_inbox,
             num_forced_inclusion,
             self.slot_duration_sec,
+            self.verify_blobs_before_submit,
+            self.metrics.clone(),
         );
 
-        self.transaction_monitor
+        let handles = self
+            .transaction_monitor
             .monitor_new_transaction_with_builder(tx_builder, pending_nonce)
             .await
-            .map(|_| ()) // ignore transaction result handlers, not needed for shasta
-            .map_err(|e| Error::msg(format!("Sending proposal to L1 failed: {e}")))
+            .map_err(|e| Error::msg(format!("Sending proposal to L1 failed: {e}")))?;
+
+        // The transaction result is not needed for shasta
+        if self.log_submitted_tx_hash {
+            tokio::spawn(async move {
+                log_submitted_tx_hash(handles.tx_hash_receiver, "Proposal").await;
+            });
+        }
+        Ok(())
     }
 
     pub async fn is_transaction_in_progress(&self) -> Result<bool, Error> {
@@ -214,6 +238,20 @@ impl ExecutionLayer {
             .map_err(|e| anyhow::anyhow!("Failed to call getConfig for Inbox: {e}"))
     }
 
+    pub async fn resubmit_pending_transaction(&self, nonce: u64) -> Result<B256, Error> {
+        self.common()
+            .resubmit_pending_transaction(nonce, self.tx_fee_bump_basis_points)
+            .await
+    }
+
+    pub async fn is_signer_reachable(&self) -> bool {
+        self.signer.is_reachable().await
+    }
+
+    pub fn shasta_inbox_address(&self) -> Address {
+        self.contract_addresses.shasta_inbox
+    }
+
     pub async fn get_activation_timestamp(&self) -> Result<u64, Error> {
         let timestamp = self
             .inbox_instance
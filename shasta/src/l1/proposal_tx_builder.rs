@@ -1,10 +1,10 @@
 use alloy::{
-    consensus::SidecarBuilder,
+    consensus::{EnvKzgSettings, SidecarBuilder},
     eips::BlockNumberOrTag,
     eips::eip7594::BlobTransactionSidecarEip7594,
     network::{TransactionBuilder, TransactionBuilder7594},
     primitives::{
-        Address, Bytes,
+        Address, B256, Bytes,
         aliases::{U24, U48},
     },
     providers::{DynProvider, Provider},
@@ -12,15 +12,17 @@ use alloy::{
 };
 use alloy_json_rpc::RpcError;
 use anyhow::{Context, Error};
-use common::l1::{fees_per_gas::FeesPerGas, tools, transaction_error::TransactionError};
+use common::l1::{fee_history_cache::FeeHistoryCache, tools, transaction_error::TransactionError};
+use common::metrics::Metrics;
 use common::shared::l2_block_v2::L2BlockV2;
 use common::shared::transaction_monitor::TransactionRequestBuilder;
+use std::sync::Arc;
 use taiko_bindings::inbox::{IInbox::ProposeInput, Inbox, LibBlobs::BlobReference};
 use taiko_protocol::shasta::{
     BlobCoder,
     manifest::{BlockManifest, DerivationSourceManifest},
 };
-use tracing::{info, warn};
+use tracing::{error, info, warn};
 
 /// Build the EIP-7594 blob sidecar from L2 blocks. This is a CPU-intensive operation
 /// (KZG commitment + cell proof computation).
@@ -68,34 +70,53 @@ fn build_sidecar_from_l2_blocks(
     Ok(sidecar)
 }
 
+/// Verifies the KZG commitments and cell proofs of the sidecar against its blobs,
+/// so a blob encoding bug is caught before paying for a reverting proposal.
+fn verify_blob_sidecar(sidecar: &BlobTransactionSidecarEip7594) -> Result<(), Error> {
+    let versioned_hashes: Vec<B256> = sidecar.versioned_hashes().collect();
+    sidecar
+        .validate(&versioned_hashes, EnvKzgSettings::Default.get())
+        .map_err(|e| anyhow::anyhow!("Blob KZG verification failed: {e}"))
+}
+
 pub struct ProposalTxBuilder {
     provider: DynProvider,
     extra_gas_percentage: u64,
+    fee_history_cache: FeeHistoryCache,
     l2_blocks: Vec<L2BlockV2>,
     from: Address,
     to: Address,
     num_forced_inclusion: u16,
     slot_duration_sec: u64,
+    verify_blobs_before_submit: bool,
+    metrics: Arc<Metrics>,
 }
 
 impl ProposalTxBuilder {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         provider: DynProvider,
         extra_gas_percentage: u64,
+        fee_history_cache: FeeHistoryCache,
         l2_blocks: Vec<L2BlockV2>,
         from: Address,
         to: Address,
         num_forced_inclusion: u16,
         slot_duration_sec: u64,
+        verify_blobs_before_submit: bool,
+        metrics: Arc<Metrics>,
     ) -> Self {
         Self {
             provider,
             extra_gas_percentage,
+            fee_history_cache,
             l2_blocks,
             from,
             to,
             num_forced_inclusion,
             slot_duration_sec,
+            verify_blobs_before_submit,
+            metrics,
         }
     }
 
@@ -149,7 +170,7 @@ impl ProposalTxBuilder {
         let tx_blob_gas = tx_blob_gas + tx_blob_gas * self.extra_gas_percentage / 100;
 
         // Get fees from the network
-        let fees_per_gas = match FeesPerGas::get_fees_per_gas(&self.provider).await {
+        let fees_per_gas = match self.fee_history_cache.get().await {
             Ok(fees_per_gas) => fees_per_gas,
             Err(e) => {
                 warn!("Build proposeBatch: Failed to get fees per gas: {}", e);
@@ -166,6 +187,13 @@ impl ProposalTxBuilder {
 
     async fn build_propose_blob(&self) -> Result<TransactionRequest, Error> {
         let sidecar = build_sidecar_from_l2_blocks(&self.l2_blocks)?;
+        if self.verify_blobs_before_submit
+            && let Err(err) = verify_blob_sidecar(&sidecar)
+        {
+            error!("Aborting proposal submission: {}", err);
+            self.metrics.inc_blob_verification_failures();
+            return Err(err);
+        }
 
         // Build the propose input.
         let input = ProposeInput {
@@ -210,3 +238,20 @@ impl TransactionRequestBuilder for ProposalTxBuilder {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_blob_sidecar_detects_corrupted_blob() {
+        let data: Vec<u8> = vec![0xAB; 13000];
+        let sidecar_builder: SidecarBuilder<BlobCoder> = SidecarBuilder::from_slice(&data);
+        let mut sidecar = sidecar_builder.build_7594().unwrap();
+        assert!(verify_blob_sidecar(&sidecar).is_ok());
+
+        // Flip the lowest bit of the first field element, keeping it canonical
+        sidecar.blobs[0][31] ^= 0x01;
+        assert!(verify_blob_sidecar(&sidecar).is_err());
+    }
+}
@@ -201,18 +201,48 @@ impl L2ExecutionLayer {
             .and_then(|input| Self::decode_anchor_id_from_tx_data(&input))
     }
 
+    /// Decodes the L1 anchor block id from an anchor transaction input.
+    /// The selector picks the ABI version, so inputs from before the Shasta fork
+    /// (anchorV3) are decoded as well.
     pub fn decode_anchor_id_from_tx_data(data: &[u8]) -> Result<u64, Error> {
-        match <Anchor::anchorV4Call as alloy::sol_types::SolCall>::abi_decode_validate(data) {
-            Ok(tx_data) => Ok(tx_data._checkpoint.blockNumber.to::<u64>()),
-            Err(v4_error) => {
-                let tx_data = <pacaya::l2::bindings::TaikoAnchor::anchorV3Call as alloy::sol_types::SolCall>::abi_decode_validate(data)
-                    .map_err(|v3_error| anyhow::anyhow!(
-                        "Failed to decode anchor id from tx data as anchorV4 ({}) or anchorV3 ({}).",
-                        v4_error,
-                        v3_error
-                    ))?;
-                Ok(tx_data._anchorBlockId)
-            }
+        use alloy::sol_types::SolCall;
+        use pacaya::l2::bindings::TaikoAnchor;
+
+        let selector: [u8; 4] = data
+            .get(..4)
+            .and_then(|selector| selector.try_into().ok())
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Failed to decode anchor id: tx input is {} bytes, too short for a function selector",
+                    data.len()
+                )
+            })?;
+
+        if selector == Anchor::anchorV4Call::SELECTOR {
+            let tx_data = Anchor::anchorV4Call::abi_decode_validate(data).map_err(|e| {
+                anyhow::anyhow!(
+                    "Failed to decode anchor id from anchorV4 tx input of {} bytes: {}",
+                    data.len(),
+                    e
+                )
+            })?;
+            Ok(tx_data._checkpoint.blockNumber.to::<u64>())
+        } else if selector == TaikoAnchor::anchorV3Call::SELECTOR {
+            let tx_data = TaikoAnchor::anchorV3Call::abi_decode_validate(data).map_err(|e| {
+                anyhow::anyhow!(
+                    "Failed to decode anchor id from anchorV3 tx input of {} bytes: {}",
+                    data.len(),
+                    e
+                )
+            })?;
+            Ok(tx_data._anchorBlockId)
+        } else {
+            Err(anyhow::anyhow!(
+                "Failed to decode anchor id: unknown selector 0x{}, expected anchorV4 (0x{}) or anchorV3 (0x{})",
+                alloy::hex::encode(selector),
+                alloy::hex::encode(Anchor::anchorV4Call::SELECTOR),
+                alloy::hex::encode(TaikoAnchor::anchorV3Call::SELECTOR)
+            ))
         }
     }
 
@@ -269,3 +299,46 @@ impl L2ExecutionLayer {
         Ok(tx_data._checkpoint)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::sol_types::SolCall;
+
+    #[test]
+    fn test_decode_anchor_id_from_valid_anchor_v4_input() {
+        let call = Anchor::anchorV4Call {
+            _checkpoint: Checkpoint {
+                blockNumber: 1234u64.try_into().expect("block number fits"),
+                blockHash: B256::repeat_byte(0x11),
+                stateRoot: B256::repeat_byte(0x22),
+            },
+        };
+
+        let anchor_id = L2ExecutionLayer::decode_anchor_id_from_tx_data(&call.abi_encode())
+            .expect("valid anchorV4 input should decode");
+        assert_eq!(anchor_id, 1234);
+    }
+
+    #[test]
+    fn test_decode_anchor_id_from_malformed_input() {
+        let too_short = L2ExecutionLayer::decode_anchor_id_from_tx_data(&[0x01, 0x02])
+            .expect_err("short input must fail");
+        assert!(too_short.to_string().contains("too short"));
+
+        let unknown_selector =
+            L2ExecutionLayer::decode_anchor_id_from_tx_data(&[0xde, 0xad, 0xbe, 0xef, 0x00])
+                .expect_err("unknown selector must fail");
+        assert!(
+            unknown_selector
+                .to_string()
+                .contains("unknown selector 0xdeadbeef")
+        );
+
+        let mut truncated = Anchor::anchorV4Call::SELECTOR.to_vec();
+        truncated.extend_from_slice(&[0u8; 8]);
+        let truncated = L2ExecutionLayer::decode_anchor_id_from_tx_data(&truncated)
+            .expect_err("truncated anchorV4 input must fail");
+        assert!(truncated.to_string().contains("anchorV4"));
+    }
+}
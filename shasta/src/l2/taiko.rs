@@ -32,6 +32,7 @@ pub struct Taiko {
     driver: Arc<TaikoDriver>,
     slot_clock: Arc<SlotClock>,
     l2_engine: L2Engine,
+    fixed_block_gas_limit: Option<u64>,
 }
 
 impl Taiko {
@@ -41,6 +42,7 @@ impl Taiko {
         metrics: Arc<Metrics>,
         taiko_config: TaikoConfig,
         l2_engine: L2Engine,
+        fixed_block_gas_limit: Option<u64>,
     ) -> Result<Self, Error> {
         let driver_config: TaikoDriverConfig = TaikoDriverConfig {
             driver_url: taiko_config.driver_url.clone(),
@@ -63,6 +65,7 @@ impl Taiko {
             driver: Arc::new(TaikoDriver::new(&driver_config, metrics).await?),
             slot_clock,
             l2_engine,
+            fixed_block_gas_limit,
         })
     }
 
@@ -214,24 +217,11 @@ impl Taiko {
         let parent_gas_limit = parent_block.header.gas_limit();
         let parent_timestamp = parent_block.header.timestamp();
 
-        let parent_gas_limit_without_anchor = if parent_id != 0 {
-            parent_gas_limit
-                .checked_sub(ANCHOR_V3_V4_GAS_LIMIT)
-                .ok_or_else(|| {
-                    anyhow::anyhow!(
-                        "parent_gas_limit {} is less than ANCHOR_V3_V4_GAS_LIMIT {}",
-                        parent_gas_limit,
-                        ANCHOR_V3_V4_GAS_LIMIT
-                    )
-                })?
-        } else {
-            parent_gas_limit
-        };
-
-        let parent_gas_limit_without_anchor = parent_gas_limit_without_anchor.clamp(
-            taiko_protocol::shasta::constants::MIN_BLOCK_GAS_LIMIT,
-            taiko_protocol::shasta::constants::MAX_BLOCK_GAS_LIMIT,
-        );
+        let parent_gas_limit_without_anchor = block_gas_limit_without_anchor(
+            parent_id,
+            parent_gas_limit,
+            self.fixed_block_gas_limit,
+        )?;
 
         let base_fee: u64 = self.get_base_fee(parent_block).await?;
 
@@ -326,3 +316,55 @@ impl Bridgeable for Taiko {
             .await
     }
 }
+
+/// Gas limit for the next block without the anchor transaction. Derived from the parent block
+/// unless a fixed gas limit is configured.
+pub fn block_gas_limit_without_anchor(
+    parent_id: u64,
+    parent_gas_limit: u64,
+    fixed_block_gas_limit: Option<u64>,
+) -> Result<u64, Error> {
+    if let Some(fixed_block_gas_limit) = fixed_block_gas_limit {
+        return Ok(fixed_block_gas_limit);
+    }
+
+    let parent_gas_limit_without_anchor = if parent_id != 0 {
+        parent_gas_limit
+            .checked_sub(ANCHOR_V3_V4_GAS_LIMIT)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "parent_gas_limit {} is less than ANCHOR_V3_V4_GAS_LIMIT {}",
+                    parent_gas_limit,
+                    ANCHOR_V3_V4_GAS_LIMIT
+                )
+            })?
+    } else {
+        parent_gas_limit
+    };
+
+    Ok(parent_gas_limit_without_anchor.clamp(
+        taiko_protocol::shasta::constants::MIN_BLOCK_GAS_LIMIT,
+        taiko_protocol::shasta::constants::MAX_BLOCK_GAS_LIMIT,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_block_gas_limit_overrides_parent() {
+        use taiko_protocol::shasta::constants::{MAX_BLOCK_GAS_LIMIT, MIN_BLOCK_GAS_LIMIT};
+
+        let parent_gas_limit = MIN_BLOCK_GAS_LIMIT + ANCHOR_V3_V4_GAS_LIMIT;
+        assert_eq!(
+            block_gas_limit_without_anchor(10, parent_gas_limit, None).unwrap(),
+            MIN_BLOCK_GAS_LIMIT
+        );
+        assert_eq!(
+            block_gas_limit_without_anchor(10, parent_gas_limit, Some(MAX_BLOCK_GAS_LIMIT))
+                .unwrap(),
+            MAX_BLOCK_GAS_LIMIT
+        );
+    }
+}
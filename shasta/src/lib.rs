@@ -9,14 +9,17 @@ pub use node::proposal_manager::l2_block_payload::L2BlockV2Payload;
 
 pub use node::proposal_manager::ProposalManager;
 
-use anyhow::Error;
+use anyhow::{Context, Error};
 use axum::Router;
 use common::{
     batch_builder::BatchBuilderConfig,
     config::{Config, ConfigTrait},
     fork_info::ForkInfo,
     funds_controller::FundsController,
-    l1::{self as common_l1, traits::PreconferProvider},
+    l1::{
+        self as common_l1,
+        traits::{ELTrait, PreconferProvider},
+    },
     l2::engine::{L2Engine, L2EngineConfig},
     metrics, shared,
     utils::cancellation_token::CancellationToken,
@@ -26,7 +29,7 @@ use l1::execution_layer::ExecutionLayer;
 use node::Node;
 use std::sync::Arc;
 use tokio::sync::mpsc;
-use tracing::info;
+use tracing::{info, warn};
 
 pub async fn create_shasta_node(
     config: Config,
@@ -39,6 +42,14 @@ pub async fn create_shasta_node(
     let shasta_config = ShastaConfig::read_env_variables()
         .map_err(|e| anyhow::anyhow!("Failed to read Shasta configuration: {}", e))?;
     info!("Shasta config: {}", shasta_config);
+    if let Some(warning) = forced_inclusion_warning(shasta_config.disable_forced_inclusion) {
+        warn!("{}", warning);
+    }
+    if shasta_config.disable_verifier {
+        warn!(
+            "⚠️ Verifier is disabled, proposals are submitted without verification. This is unsafe and meant for debugging only"
+        );
+    }
 
     let (transaction_error_sender, transaction_error_receiver) = mpsc::channel(100);
     let ethereum_l1 = common_l1::ethereum_l1::EthereumL1::<ExecutionLayer>::new(
@@ -52,14 +63,23 @@ pub async fn create_shasta_node(
 
     let ethereum_l1 = Arc::new(ethereum_l1);
 
+    common_l1::clock_skew::check_l1_clock_skew(
+        ethereum_l1.execution_layer.common(),
+        &common_l1::slot_clock::RealClock,
+        config.max_l1_clock_skew_sec,
+        config.refuse_start_on_clock_skew,
+        &metrics,
+    )
+    .await?;
+
     let taiko_config = pacaya::l2::config::TaikoConfig::new(&config)
         .await
         .map_err(|e| anyhow::anyhow!("Failed to create TaikoConfig: {}", e))?;
 
-    let l2_engine = L2Engine::new(L2EngineConfig::new(
-        &config,
-        taiko_config.signer.get_address(),
-    )?)
+    let l2_engine = L2Engine::new(
+        L2EngineConfig::new(&config, taiko_config.signer.get_address())?,
+        metrics.clone(),
+    )
     .map_err(|e| anyhow::anyhow!("Failed to create L2Engine: {}", e))?;
     let inbox_config = ethereum_l1.execution_layer.fetch_inbox_config().await?;
 
@@ -69,6 +89,7 @@ pub async fn create_shasta_node(
         metrics.clone(),
         taiko_config,
         l2_engine,
+        shasta_config.fixed_block_gas_limit,
     )
     .await?;
     let taiko = Arc::new(taiko);
@@ -86,14 +107,43 @@ pub async fn create_shasta_node(
         preconf_heartbeat_ms: config.preconf_heartbeat_ms,
         handover_window_slots: shasta_config.handover_window_slots,
         handover_start_buffer_ms: shasta_config.handover_start_buffer_ms,
+        driver_sync_tolerance_blocks: config.driver_sync_tolerance_blocks,
         ejection_grace_period_sec: shasta_config.ejection_grace_period_sec,
         l1_height_lag: shasta_config.l1_height_lag,
         min_anchor_offset: config.min_anchor_offset,
+        anchor_confirmation_depth: config.anchor_confirmation_depth,
         propose_forced_inclusion: shasta_config.propose_forced_inclusion,
+        allow_forced_inclusion_head_rollback: shasta_config.allow_forced_inclusion_head_rollback,
+        disable_forced_inclusion: shasta_config.disable_forced_inclusion,
         simulate_not_submitting_at_the_end_of_epoch: shasta_config
             .simulate_not_submitting_at_the_end_of_epoch,
         max_blocks_to_reanchor: shasta_config.max_blocks_to_reanchor,
+        driver_transient_error_max_retries: shasta_config.driver_transient_error_max_retries,
+        anchor_timestamp_fetch_max_retries: shasta_config.anchor_timestamp_fetch_max_retries,
+        max_startup_recovery_blocks: shasta_config.max_startup_recovery_blocks,
+        reanchor_alert_threshold: shasta_config.reanchor_alert_threshold,
+        verify_produced_block: shasta_config.verify_produced_block,
+        disable_verifier: shasta_config.disable_verifier,
+        log_status_transitions: shasta_config.log_status_transitions,
         watchdog_max_counter: config.watchdog_max_counter,
+        heartbeat_step_max_retries: config.heartbeat_step_max_retries,
+        warmup_retry_delay_sec: config.warmup_retry_delay_sec,
+        safe_mode_max_head_errors: shasta_config.safe_mode_max_head_errors,
+        safe_mode_window_sec: shasta_config.safe_mode_window_min * 60,
+        safe_mode_state_path: shasta_config.safe_mode_state_path.clone(),
+        kzg_eager_warmup: config.kzg_eager_warmup,
+        pending_tx_base_fee_min: shasta_config.pending_tx_base_fee_min,
+        pending_tx_base_fee_max: shasta_config.pending_tx_base_fee_max,
+        inbox_activation_max_wait_sec: shasta_config.inbox_activation_max_wait_sec,
+        reanchor_lazy_tx_fetch: shasta_config.reanchor_lazy_tx_fetch,
+        nonce_gap_self_heal_timeout_sec: shasta_config.nonce_gap_self_heal_timeout_sec,
+        role_loss_reset_cooldown_heartbeats: shasta_config.role_loss_reset_cooldown_heartbeats,
+        pending_nonce_settle_max_wait_sec: shasta_config.pending_nonce_settle_max_wait_sec,
+        submitter_grace_window_sec: shasta_config.submitter_grace_window_sec,
+        anchor_offset_check_interval_heartbeats: shasta_config
+            .anchor_offset_check_interval_heartbeats,
+        operator_stall_max_epochs: shasta_config.operator_stall_max_epochs,
+        threshold_eth: config.threshold_eth,
     };
 
     let max_blocks_per_batch = if config.max_blocks_per_batch == 0 {
@@ -103,6 +153,14 @@ pub async fn create_shasta_node(
     };
 
     let max_anchor_height_offset = taiko.get_protocol_config().get_max_anchor_height_offset();
+    shared::anchor_block_info::AnchorBlockInfo::validate_min_anchor_offset(
+        config.min_anchor_offset,
+        max_anchor_height_offset - config.max_anchor_height_offset_reduction,
+    )?;
+    shared::anchor_block_info::AnchorBlockInfo::validate_anchor_confirmation_depth(
+        config.anchor_confirmation_depth,
+        max_anchor_height_offset - config.max_anchor_height_offset_reduction,
+    )?;
 
     let proposal_builder_config = BatchBuilderConfig {
         max_bytes_size_of_batch: config.max_bytes_size_of_batch,
@@ -113,23 +171,37 @@ pub async fn create_shasta_node(
             - config.max_anchor_height_offset_reduction,
         default_coinbase: ethereum_l1.execution_layer.get_preconfer_address(),
         preconf_min_txs: config.preconf_min_txs,
+        preconf_min_txs_adaptive: config.preconf_min_txs_adaptive.clone(),
         preconf_max_skipped_l2_slots: config.preconf_max_skipped_l2_slots,
+        force_empty_block_after_skipped_l2_slots: config.force_empty_block_after_skipped_l2_slots,
         proposal_max_time_sec: config.proposal_max_time_sec,
         max_forced_inclusions: config.max_forced_inclusions_per_proposal,
+        max_batch_age_sec: config.max_batch_age_sec,
+        fork_switch_grace: None,
     };
 
+    // Our preconfer address always counts as our own proposer
+    let mut own_proposers = shasta_config.own_proposer_addresses.clone();
+    own_proposers.push(ethereum_l1.execution_layer.get_preconfer_address());
+    let proposal_conflict_detector = Arc::new(chain_monitor::ProposalConflictDetector::new(
+        own_proposers,
+        metrics.clone(),
+    ));
+
     let chain_monitor = Arc::new(
         chain_monitor::ShastaChainMonitor::new(
-            config
-                .l1_rpc_urls
-                .first()
-                .expect("L1 RPC URL is required")
-                .clone(),
+            config.chain_monitor_l1_url()?,
             config.l2_rpc_url.clone(),
             shasta_config.shasta_inbox,
             cancel_token.clone(),
             "Proposed",
-            chain_monitor::print_proposed_info,
+            {
+                let proposal_conflict_detector = proposal_conflict_detector.clone();
+                move |event: &taiko_bindings::inbox::Inbox::Proposed| {
+                    proposal_conflict_detector.handle_proposed(event)
+                }
+            },
+            config.chain_monitor_log_chunk_blocks,
             metrics.clone(),
         )
         .map_err(|e| anyhow::anyhow!("Failed to create ShastaChainMonitor: {}", e))?,
@@ -149,13 +221,18 @@ pub async fn create_shasta_node(
         transaction_error_receiver,
         fork_info,
         chain_monitor.clone(),
+        proposal_conflict_detector,
     )
     .await
     .map_err(|e| anyhow::anyhow!("Failed to create Node: {}", e))?;
 
-    node.entrypoint()
-        .await
-        .map_err(|e| anyhow::anyhow!("Failed to start Node: {}", e))?;
+    let role_router = node::role::role_router(node.role_state());
+    let admin_router = shasta_config
+        .admin_api_token
+        .clone()
+        .map(|token| node::current_batch::current_batch_router(node.current_batch_state(), token));
+
+    node.entrypoint().await.context("Failed to start Node")?;
 
     let status_router = node::status_router::status_router(
         ethereum_l1.execution_layer.clone(),
@@ -179,5 +256,36 @@ pub async fn create_shasta_node(
     );
     whitelist_monitor.run();
 
-    Ok(vec![status_router])
+    common::utils::rpc_health::RpcHealthMonitor::new(
+        config.l1_rpc_urls.clone(),
+        vec![config.l2_rpc_url.clone()],
+        cancel_token.clone(),
+        metrics.clone(),
+        config.rpc_health_check_interval_sec,
+    )
+    .run();
+
+    let mut routers = vec![status_router, role_router];
+    routers.extend(admin_router);
+    Ok(routers)
+}
+
+fn forced_inclusion_warning(disable_forced_inclusion: bool) -> Option<&'static str> {
+    disable_forced_inclusion.then_some(
+        "DISABLE_FORCED_INCLUSION is set, forced inclusions are skipped when building proposals but the inbox forced inclusion head is still tracked",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_forced_inclusion_warning() {
+        assert_eq!(forced_inclusion_warning(false), None);
+        let warning = forced_inclusion_warning(true).unwrap();
+        assert!(warning.contains("DISABLE_FORCED_INCLUSION"));
+        assert!(warning.contains("skipped when building proposals"));
+        assert!(warning.contains("still tracked"));
+    }
 }
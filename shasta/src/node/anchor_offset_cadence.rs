@@ -0,0 +1,48 @@
+/// Limits the proactive anchor offset check to every `interval_heartbeats` heartbeats, as every
+/// check fetches the L2 height from L1 and the anchor block of the unsafe L2 blocks.
+pub struct AnchorOffsetCheckCadence {
+    interval_heartbeats: u64,
+    heartbeats_since_check: u64,
+}
+
+impl AnchorOffsetCheckCadence {
+    /// An interval of zero or one heartbeat runs the check on every heartbeat.
+    pub fn new(interval_heartbeats: u64) -> Self {
+        let interval_heartbeats = interval_heartbeats.max(1);
+        Self {
+            interval_heartbeats,
+            // the first eligible heartbeat always runs the check
+            heartbeats_since_check: interval_heartbeats - 1,
+        }
+    }
+
+    /// Records an eligible heartbeat and returns true when the check is due in it.
+    pub fn should_check(&mut self) -> bool {
+        self.heartbeats_since_check += 1;
+        if self.heartbeats_since_check >= self.interval_heartbeats {
+            self.heartbeats_since_check = 0;
+            return true;
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_runs_on_configured_cadence() {
+        let mut cadence = AnchorOffsetCheckCadence::new(3);
+        let checks: Vec<bool> = (0..7).map(|_| cadence.should_check()).collect();
+        assert_eq!(checks, vec![true, false, false, true, false, false, true]);
+    }
+
+    #[test]
+    fn test_check_runs_every_heartbeat_by_default() {
+        for interval in [0, 1] {
+            let mut cadence = AnchorOffsetCheckCadence::new(interval);
+            assert!((0..3).all(|_| cadence.should_check()));
+        }
+    }
+}
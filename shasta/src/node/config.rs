@@ -3,11 +3,39 @@ pub struct NodeConfig {
     pub preconf_heartbeat_ms: u64,
     pub handover_window_slots: u64,
     pub handover_start_buffer_ms: u64,
+    pub driver_sync_tolerance_blocks: u64,
     pub ejection_grace_period_sec: u64,
     pub l1_height_lag: u64,
     pub min_anchor_offset: u64,
+    pub anchor_confirmation_depth: u64,
     pub propose_forced_inclusion: bool,
+    pub allow_forced_inclusion_head_rollback: bool,
+    pub disable_forced_inclusion: bool,
     pub simulate_not_submitting_at_the_end_of_epoch: bool,
     pub max_blocks_to_reanchor: u64,
+    pub driver_transient_error_max_retries: u64,
+    pub anchor_timestamp_fetch_max_retries: u64,
+    pub max_startup_recovery_blocks: Option<u64>,
+    pub reanchor_alert_threshold: Option<u64>,
+    pub verify_produced_block: bool,
+    pub disable_verifier: bool,
+    pub log_status_transitions: bool,
     pub watchdog_max_counter: u64,
+    pub heartbeat_step_max_retries: u64,
+    pub warmup_retry_delay_sec: u64,
+    pub safe_mode_max_head_errors: Option<u64>,
+    pub safe_mode_window_sec: u64,
+    pub safe_mode_state_path: String,
+    pub kzg_eager_warmup: bool,
+    pub pending_tx_base_fee_min: Option<u64>,
+    pub pending_tx_base_fee_max: Option<u64>,
+    pub inbox_activation_max_wait_sec: Option<u64>,
+    pub reanchor_lazy_tx_fetch: bool,
+    pub nonce_gap_self_heal_timeout_sec: Option<u64>,
+    pub role_loss_reset_cooldown_heartbeats: u64,
+    pub pending_nonce_settle_max_wait_sec: u64,
+    pub submitter_grace_window_sec: u64,
+    pub anchor_offset_check_interval_heartbeats: u64,
+    pub operator_stall_max_epochs: u64,
+    pub threshold_eth: u128,
 }
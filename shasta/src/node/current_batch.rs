@@ -0,0 +1,149 @@
+use axum::{
+    Router,
+    extract::State,
+    http::{HeaderMap, StatusCode, header},
+    response::IntoResponse,
+    routing::get,
+};
+use serde_json::{Value, json};
+use std::sync::Arc;
+use subtle::ConstantTimeEq;
+use tokio::sync::RwLock;
+
+/// Summary of a proposal that is built but not yet confirmed on L1.
+pub struct BatchSummary {
+    pub proposal_id: u64,
+    pub anchor_block_id: u64,
+    pub block_count: usize,
+    pub total_bytes: u64,
+    pub has_forced_inclusion: bool,
+    pub pending_confirmation: bool,
+}
+
+/// Latest in-progress proposals observed by the node, refreshed every heartbeat.
+#[derive(Clone, Default)]
+pub struct CurrentBatchState {
+    batches: Arc<RwLock<Value>>,
+}
+
+impl CurrentBatchState {
+    pub async fn update(&self, batches: &[BatchSummary]) {
+        let batches = batches
+            .iter()
+            .map(|batch| {
+                json!({
+                    "proposal_id": batch.proposal_id,
+                    "anchor_block_id": batch.anchor_block_id,
+                    "block_count": batch.block_count,
+                    "total_bytes": batch.total_bytes,
+                    "has_forced_inclusion": batch.has_forced_inclusion,
+                    "pending_confirmation": batch.pending_confirmation,
+                })
+            })
+            .collect();
+        *self.batches.write().await = Value::Array(batches);
+    }
+
+    pub async fn current(&self) -> Value {
+        self.batches.read().await.clone()
+    }
+}
+
+#[derive(Clone)]
+struct AdminState {
+    current_batch: CurrentBatchState,
+    token: Arc<String>,
+}
+
+/// Token-guarded admin route exposing the in-progress proposals.
+pub fn current_batch_router(current_batch: CurrentBatchState, token: String) -> Router {
+    Router::new()
+        .route("/admin/current-batch", get(current_batch_handler))
+        .with_state(AdminState {
+            current_batch,
+            token: Arc::new(token),
+        })
+}
+
+async fn current_batch_handler(
+    State(state): State<AdminState>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let authorized = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|token| bool::from(token.as_bytes().ct_eq(state.token.as_bytes())));
+    if !authorized {
+        return StatusCode::UNAUTHORIZED.into_response();
+    }
+
+    let response = json!({ "batches": state.current_batch.current().await });
+    (
+        [(header::CONTENT_TYPE, "application/json")],
+        response.to_string(),
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+
+    #[tokio::test]
+    async fn test_current_batch_endpoint_after_building_a_block() {
+        let state = CurrentBatchState::default();
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("failed to bind listener");
+        let url = format!(
+            "http://{}/admin/current-batch",
+            listener.local_addr().expect("failed to get local addr")
+        );
+        let app = current_batch_router(state.clone(), "secret".to_string());
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        // One block built into proposal 7
+        state
+            .update(&[BatchSummary {
+                proposal_id: 7,
+                anchor_block_id: 100,
+                block_count: 1,
+                total_bytes: 250,
+                has_forced_inclusion: true,
+                pending_confirmation: false,
+            }])
+            .await;
+
+        let client = reqwest::Client::new();
+        let unauthorized = client.get(&url).send().await.expect("request failed");
+        assert_eq!(unauthorized.status(), reqwest::StatusCode::UNAUTHORIZED);
+
+        for wrong_token in ["wrong", "secreT", "secret2"] {
+            let response = client
+                .get(&url)
+                .bearer_auth(wrong_token)
+                .send()
+                .await
+                .expect("request failed");
+            assert_eq!(response.status(), reqwest::StatusCode::UNAUTHORIZED);
+        }
+
+        let response: Value = client
+            .get(&url)
+            .bearer_auth("secret")
+            .send()
+            .await
+            .expect("request failed")
+            .json()
+            .await
+            .expect("invalid json");
+        let batch = &response["batches"][0];
+        assert_eq!(batch["proposal_id"], 7);
+        assert_eq!(batch["anchor_block_id"], 100);
+        assert_eq!(batch["block_count"], 1);
+        assert_eq!(batch["total_bytes"], 250);
+        assert_eq!(batch["has_forced_inclusion"], true);
+    }
+}
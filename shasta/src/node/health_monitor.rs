@@ -0,0 +1,140 @@
+use crate::metrics::Metrics;
+use crate::{l1::execution_layer::ExecutionLayer, l2::taiko::Taiko};
+use alloy::primitives::U256;
+use common::{
+    l1::{ethereum_l1::EthereumL1, traits::PreconferProvider},
+    utils::{cancellation_token::CancellationToken, health::ComponentHealth},
+};
+use std::{
+    future::Future,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+use tokio::time::{sleep, timeout};
+use tracing::{debug, info, warn};
+
+const HEALTH_PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Probes the components the node depends on in a background task and publishes the composite
+/// health, so a slow RPC does not delay the preconfirmation heartbeat.
+pub struct HealthMonitor {
+    ethereum_l1: Arc<EthereumL1<ExecutionLayer>>,
+    taiko: Arc<Taiko>,
+    metrics: Arc<Metrics>,
+    cancel_token: CancellationToken,
+    driver_synced: Arc<AtomicBool>,
+    threshold_eth: u128,
+    interval: Duration,
+}
+
+impl HealthMonitor {
+    /// `driver_synced` is updated by the preconfirmation heartbeat.
+    pub fn new(
+        ethereum_l1: Arc<EthereumL1<ExecutionLayer>>,
+        taiko: Arc<Taiko>,
+        metrics: Arc<Metrics>,
+        cancel_token: CancellationToken,
+        driver_synced: Arc<AtomicBool>,
+        threshold_eth: u128,
+        interval: Duration,
+    ) -> Self {
+        Self {
+            ethereum_l1,
+            taiko,
+            metrics,
+            cancel_token,
+            driver_synced,
+            threshold_eth,
+            interval,
+        }
+    }
+
+    pub fn run(self) {
+        tokio::spawn(async move {
+            self.monitor_health().await;
+        });
+    }
+
+    async fn monitor_health(self) {
+        loop {
+            let health = self.probe().await;
+            debug!("Component health: {:?}", health);
+            self.metrics.set_overall_health(health.overall());
+            tokio::select! {
+                _ = sleep(self.interval) => {},
+                _ = self.cancel_token.cancelled() => {
+                    info!("Shutdown signal received, exiting health monitor loop...");
+                    return;
+                }
+            }
+        }
+    }
+
+    async fn probe(&self) -> ComponentHealth {
+        let execution_layer = &self.ethereum_l1.execution_layer;
+        let (balance, geth_reachable, signer_reachable) = tokio::join!(
+            probe_with_timeout("L1 RPC", execution_layer.get_preconfer_wallet_eth()),
+            probe_with_timeout("Geth", self.taiko.get_latest_l2_block_id()),
+            probe_with_timeout("signer", async {
+                Ok::<_, anyhow::Error>(execution_layer.is_signer_reachable().await)
+            }),
+        );
+        ComponentHealth {
+            driver_synced: self.driver_synced.load(Ordering::Relaxed),
+            geth_reachable: geth_reachable.is_some(),
+            l1_rpc_reachable: balance.is_some(),
+            signer_reachable: signer_reachable.unwrap_or(false),
+            funds_above_threshold: balance
+                .is_some_and(|balance| balance >= U256::from(self.threshold_eth)),
+        }
+    }
+}
+
+/// Returns the probe result, or None if it failed or did not complete within the timeout.
+async fn probe_with_timeout<T, E: std::fmt::Display>(
+    component: &str,
+    probe: impl Future<Output = Result<T, E>>,
+) -> Option<T> {
+    match timeout(HEALTH_PROBE_TIMEOUT, probe).await {
+        Ok(Ok(value)) => Some(value),
+        Ok(Err(err)) => {
+            debug!("Health check: {} unreachable: {}", component, err);
+            None
+        }
+        Err(_) => {
+            warn!(
+                "Health check: {} timed out after {:?}",
+                component, HEALTH_PROBE_TIMEOUT
+            );
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_probe_with_timeout() {
+        assert_eq!(
+            probe_with_timeout("Geth", async { Ok::<_, anyhow::Error>(7) }).await,
+            Some(7)
+        );
+        assert_eq!(
+            probe_with_timeout("Geth", async {
+                Err::<u64, _>(anyhow::anyhow!("connection refused"))
+            })
+            .await,
+            None
+        );
+        // A hanging probe is cut off by the timeout
+        assert_eq!(
+            probe_with_timeout("Geth", std::future::pending::<Result<u64, anyhow::Error>>()).await,
+            None
+        );
+    }
+}
@@ -0,0 +1,83 @@
+/// Number of consecutive regressed observations after which the lower height is accepted,
+/// since a lagging node catches up within a few heartbeats while a reorg persists.
+const MAX_CONSECUTIVE_REGRESSIONS: u32 = 3;
+
+/// Tracks the highest L2 height reported by Geth.
+///
+/// A height lower than one already seen means Geth is re-syncing, a load balancer routed
+/// the call to a lagging node, or the chain reorged. Regressions are only reported for
+/// alerting. The tracker is reset on reanchor, and a regression that persists is taken as
+/// the new height.
+#[derive(Default)]
+pub struct L2HeightTracker {
+    highest_height: Option<u64>,
+    consecutive_regressions: u32,
+}
+
+impl L2HeightTracker {
+    /// Records the height and returns true if it regressed below the highest one seen.
+    pub fn observe(&mut self, height: u64) -> bool {
+        match self.highest_height {
+            Some(highest)
+                if height < highest
+                    && self.consecutive_regressions + 1 < MAX_CONSECUTIVE_REGRESSIONS =>
+            {
+                self.consecutive_regressions += 1;
+                true
+            }
+            _ => {
+                self.highest_height = Some(height);
+                self.consecutive_regressions = 0;
+                false
+            }
+        }
+    }
+
+    pub fn highest_height(&self) -> Option<u64> {
+        self.highest_height
+    }
+
+    /// Forgets the heights seen so far, to be called when the L2 chain is rebuilt.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decreasing_height_is_detected() {
+        let mut tracker = L2HeightTracker::default();
+        let regressions: Vec<bool> = [100, 101, 99, 95, 101, 102]
+            .into_iter()
+            .map(|height| tracker.observe(height))
+            .collect();
+
+        assert_eq!(regressions, vec![false, false, true, true, false, false]);
+        assert_eq!(tracker.highest_height(), Some(102));
+    }
+
+    #[test]
+    fn test_persistent_regression_is_accepted() {
+        let mut tracker = L2HeightTracker::default();
+        let regressions: Vec<bool> = [100, 90, 90, 90, 91]
+            .into_iter()
+            .map(|height| tracker.observe(height))
+            .collect();
+
+        assert_eq!(regressions, vec![false, true, true, false, false]);
+        assert_eq!(tracker.highest_height(), Some(91));
+    }
+
+    #[test]
+    fn test_reset_forgets_highest_height() {
+        let mut tracker = L2HeightTracker::default();
+        tracker.observe(100);
+        tracker.reset();
+
+        assert!(!tracker.observe(90));
+        assert_eq!(tracker.highest_height(), Some(90));
+    }
+}
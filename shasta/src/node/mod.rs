@@ -1,27 +1,44 @@
+mod anchor_offset_cadence;
 pub mod block_advancer;
 pub mod config;
+pub mod current_batch;
+mod health_monitor;
+mod l2_height_tracker;
 mod last_safe_l2_block_finder;
+mod operator_stall;
 pub mod proposal_manager;
+pub mod role;
+mod role_loss_cooldown;
+mod slot_block_guard;
 pub mod status_router;
+mod status_transitions;
+mod submitter_grace;
 use anyhow::Error;
 use common::{
     fork_info::ForkInfo,
     l1::{ethereum_l1::EthereumL1, transaction_error::TransactionError},
     l2::taiko_driver::{TaikoDriver, models::BuildPreconfBlockResponse},
     shared::{l2_slot_info_v2::L2SlotContext, l2_tx_lists::PreBuiltTxList},
-    utils::{self as common_utils, cancellation_token::CancellationToken},
+    utils::{
+        self as common_utils, cancellation_token::CancellationToken,
+        transient_error::is_transient_error, warmup_error::WarmupError,
+    },
 };
 use config::NodeConfig;
 use pacaya::node::operator::{Operator, Status as OperatorStatus};
-use std::sync::Arc;
-use tracing::{debug, error, info, warn};
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
+use tracing::{Instrument, debug, error, info, warn};
 
 use crate::metrics::Metrics;
 use crate::{l1::execution_layer::ExecutionLayer, l2::taiko::Taiko};
 use common::batch_builder::BatchBuilderConfig;
 use common::l1::traits::PreconferProvider;
-use common::shared::head_verifier::HeadVerifier;
+use common::shared::head_verifier::{HeadVerifier, verify_produced_block_stored_in_geth};
 use common::shared::l2_slot_info_v2::L2SlotInfoV2;
+use common::shared::safe_mode::{SafeModeBreaker, unix_timestamp_sec};
 use proposal_manager::ProposalManager;
 
 use tokio::{
@@ -30,10 +47,22 @@ use tokio::{
 };
 
 mod verifier;
-use verifier::{VerificationResult, Verifier};
+use verifier::{VerificationOutcome, Verifier};
 
-use crate::chain_monitor::ShastaChainMonitor;
+use crate::chain_monitor::{ProposalConflictDetector, ShastaChainMonitor};
+use anchor_offset_cadence::AnchorOffsetCheckCadence;
+use current_batch::CurrentBatchState;
+use health_monitor::HealthMonitor;
+use l2_height_tracker::L2HeightTracker;
 pub use last_safe_l2_block_finder::LastSafeL2BlockFinder;
+use operator_stall::OperatorStallDetector;
+use role::NodeRoleState;
+use role_loss_cooldown::RoleLossCooldown;
+use slot_block_guard::SlotBlockGuard;
+use status_transitions::StatusTransitionTracker;
+use submitter_grace::SubmitterGraceWindow;
+
+const HEARTBEAT_READ_RETRY_DELAY: Duration = Duration::from_millis(200);
 
 pub struct Node {
     config: NodeConfig,
@@ -46,9 +75,21 @@ pub struct Node {
     proposal_manager: ProposalManager,
     verifier: Option<Verifier>,
     head_verifier: HeadVerifier,
+    safe_mode: SafeModeBreaker,
     transaction_error_channel: Receiver<TransactionError>,
     chain_monitor: Arc<ShastaChainMonitor>,
+    proposal_conflict_detector: Arc<ProposalConflictDetector>,
     last_safe_l2_block_finder: Arc<LastSafeL2BlockFinder>,
+    role: NodeRoleState,
+    slot_block_guard: SlotBlockGuard,
+    status_transitions: StatusTransitionTracker,
+    role_loss_cooldown: RoleLossCooldown,
+    submitter_grace_window: SubmitterGraceWindow,
+    anchor_offset_cadence: AnchorOffsetCheckCadence,
+    operator_stall: OperatorStallDetector,
+    driver_synced: Arc<AtomicBool>,
+    l2_height_tracker: L2HeightTracker,
+    current_batch: CurrentBatchState,
 }
 
 impl Node {
@@ -63,6 +104,7 @@ impl Node {
         transaction_error_channel: Receiver<TransactionError>,
         fork_info: ForkInfo,
         chain_monitor: Arc<ShastaChainMonitor>,
+        proposal_conflict_detector: Arc<ProposalConflictDetector>,
     ) -> Result<Self, Error> {
         let last_safe_l2_block_finder = Arc::new(LastSafeL2BlockFinder::new(
             ethereum_l1.clone(),
@@ -79,6 +121,8 @@ impl Node {
             cancel_token.clone(),
             fork_info.clone(),
             config.ejection_grace_period_sec,
+            config.driver_sync_tolerance_blocks,
+            metrics.clone(),
         )
         .map_err(|e| anyhow::anyhow!("Failed to create Operator: {}", e))?;
         let watchdog = common_utils::watchdog::Watchdog::new(
@@ -86,6 +130,18 @@ impl Node {
             config.watchdog_max_counter,
         );
         let head_verifier = HeadVerifier::default();
+        let safe_mode = SafeModeBreaker::load(
+            config.safe_mode_state_path.clone().into(),
+            config.safe_mode_max_head_errors,
+            config.safe_mode_window_sec,
+        );
+        let in_safe_mode = safe_mode.is_tripped(unix_timestamp_sec());
+        metrics.set_safe_mode(in_safe_mode);
+        if in_safe_mode {
+            warn!(
+                "Safe mode is active after repeated unexpected L2 head errors, the node will not preconfirm"
+            );
+        }
 
         let block_advancer = Arc::new(block_advancer::ShastaBlockAdvancer::new(
             taiko.l2_execution_layer(),
@@ -96,6 +152,7 @@ impl Node {
         let proposal_manager = ProposalManager::new(
             config.l1_height_lag,
             config.min_anchor_offset,
+            config.anchor_confirmation_depth,
             proposal_builder_config,
             ethereum_l1.clone(),
             taiko.clone(),
@@ -104,18 +161,35 @@ impl Node {
             cancel_token.clone(),
             config.max_blocks_to_reanchor,
             config.propose_forced_inclusion,
+            config.allow_forced_inclusion_head_rollback,
+            config.disable_forced_inclusion,
+            config.driver_transient_error_max_retries,
+            config.anchor_timestamp_fetch_max_retries,
         )
         .await
         .map_err(|e| anyhow::anyhow!("Failed to create ProposalManager: {}", e))?;
 
         // Workaround for the issue: https://github.com/NethermindEth/Catalyst/issues/611
         // e2e-test to reproduce issue: test_preconfirmation_after_restart
-        let start = std::time::Instant::now();
-        common::blob::build_default_kzg_settings();
-        info!(
-            "Setup build_default_kzg_settings in {} milliseconds",
-            start.elapsed().as_millis()
-        );
+        match common::blob::warmup_kzg_settings(
+            config.kzg_eager_warmup,
+            common::blob::build_default_kzg_settings,
+        ) {
+            Some(duration) => {
+                info!(
+                    "Setup build_default_kzg_settings in {} milliseconds",
+                    duration.as_millis()
+                );
+                metrics.set_kzg_setup_ms(duration);
+            }
+            None => info!("Skipping eager build_default_kzg_settings"),
+        }
+
+        let role_loss_cooldown = RoleLossCooldown::new(config.role_loss_reset_cooldown_heartbeats);
+        let submitter_grace_window = SubmitterGraceWindow::new(config.submitter_grace_window_sec);
+        let anchor_offset_cadence =
+            AnchorOffsetCheckCadence::new(config.anchor_offset_check_interval_heartbeats);
+        let operator_stall = OperatorStallDetector::new(config.operator_stall_max_epochs);
 
         Ok(Self {
             config,
@@ -128,22 +202,63 @@ impl Node {
             proposal_manager,
             verifier: None,
             head_verifier,
+            safe_mode,
             transaction_error_channel,
             chain_monitor,
+            proposal_conflict_detector,
             last_safe_l2_block_finder,
+            role: NodeRoleState::default(),
+            slot_block_guard: SlotBlockGuard::default(),
+            status_transitions: StatusTransitionTracker::default(),
+            role_loss_cooldown,
+            submitter_grace_window,
+            anchor_offset_cadence,
+            operator_stall,
+            driver_synced: Arc::new(AtomicBool::new(false)),
+            l2_height_tracker: L2HeightTracker::default(),
+            current_batch: CurrentBatchState::default(),
         })
     }
 
+    /// Shared handle to the in-progress proposals, updated on every heartbeat.
+    pub fn current_batch_state(&self) -> CurrentBatchState {
+        self.current_batch.clone()
+    }
+
+    /// Shared handle to the node role, updated on every heartbeat.
+    pub fn role_state(&self) -> NodeRoleState {
+        self.role.clone()
+    }
+
+    /// Current operator status as JSON, `None` until the first heartbeat.
+    #[allow(dead_code)] // Read-only API for embedders, the HTTP server reads `role_state` instead
+    pub async fn current_role(&self) -> Option<serde_json::Value> {
+        self.role.current().await
+    }
+
     pub async fn entrypoint(mut self) -> Result<(), Error> {
         info!("Starting node");
         if let Err(err) = self.warmup().await {
             error!("Failed to warm up node: {}. Shutting down.", err);
             self.cancel_token.cancel_on_critical_error();
-            return Err(anyhow::anyhow!(err));
+            return Err(err.context(WarmupError {
+                retry_delay: Duration::from_secs(self.config.warmup_retry_delay_sec),
+            }));
         }
 
         info!("Node warmup successful");
 
+        HealthMonitor::new(
+            self.ethereum_l1.clone(),
+            self.taiko.clone(),
+            self.metrics.clone(),
+            self.cancel_token.clone(),
+            self.driver_synced.clone(),
+            self.config.threshold_eth,
+            Duration::from_millis(self.config.preconf_heartbeat_ms),
+        )
+        .run();
+
         // Run preconfirmation loop in background
         tokio::spawn(async move {
             self.preconfirmation_loop().await;
@@ -168,21 +283,52 @@ impl Node {
                 return;
             }
 
-            if let Err(err) = self.main_block_preconfirmation_step().await {
-                error!("Failed to execute main block preconfirmation step: {}", err);
-                self.watchdog.increment();
-            } else {
-                self.watchdog.reset();
-            }
+            let heartbeat_span =
+                common_utils::logging::heartbeat_span(&self.ethereum_l1.slot_clock);
+            let result = self
+                .main_block_preconfirmation_step()
+                .instrument(heartbeat_span)
+                .await;
+            record_heartbeat_result(&mut self.watchdog, result);
         }
     }
 
     async fn main_block_preconfirmation_step(&mut self) -> Result<(), Error> {
-        let (l2_slot_info, current_status, pending_tx_list) =
-            self.get_slot_info_and_status().await?;
+        // Only the reads are retried, the rest of the step produces blocks and sends transactions
+        let max_retries = self.config.heartbeat_step_max_retries;
+        let cancel_token = self.cancel_token.clone();
+        let (l2_slot_info, current_status, pending_tx_list) = retry_transient_read(
+            max_retries,
+            HEARTBEAT_READ_RETRY_DELAY,
+            &cancel_token,
+            async || self.get_slot_info_and_status().await,
+        )
+        .await?;
+
+        self.role.update(&current_status).await;
+        if self.config.log_status_transitions {
+            for transition in self.status_transitions.observe(&current_status) {
+                info!("🔀 Operator status transition: {}", transition.as_str());
+                self.metrics
+                    .inc_operator_status_transition(transition.as_str());
+            }
+        }
 
+        let has_pending_txs = pending_tx_list
+            .as_ref()
+            .is_some_and(|tx_list| !tx_list.get_tx_list().is_empty());
+        // A preconfer that is not synced or in safe mode is not expected to produce blocks
+        let can_preconfirm = current_status.is_preconfer()
+            && current_status.is_driver_synced()
+            && !self.safe_mode.is_tripped(unix_timestamp_sec());
+        self.check_operator_stall(can_preconfirm, has_pending_txs)?;
+
+        self.driver_synced
+            .store(current_status.is_driver_synced(), Ordering::Relaxed);
         self.metrics
             .set_is_geth_and_driver_synced(current_status.is_driver_synced());
+        self.proposal_conflict_detector
+            .set_in_our_window(current_status.is_preconfer() || current_status.is_submitter());
 
         let l2_slot_ctx = L2SlotContext {
             info: l2_slot_info,
@@ -253,7 +399,7 @@ impl Node {
                     self.taiko.clone(),
                     self.proposal_manager
                         .clone_without_proposals(inbox_forced_inclusion_state.head),
-                    verification_timestamp,
+                    Some(verification_timestamp),
                     self.cancel_token.clone(),
                     self.last_safe_l2_block_finder.clone(),
                 )
@@ -283,9 +429,16 @@ impl Node {
             self.proposal_manager.set_fi_head(current_fi_head);
         }
 
-        if current_status.is_preconfer() && current_status.is_driver_synced() {
+        let in_safe_mode = self.safe_mode.is_tripped(unix_timestamp_sec());
+        self.metrics.set_safe_mode(in_safe_mode);
+        if in_safe_mode && current_status.is_preconfer() {
+            warn!("Safe mode is active, skipping preconfirmation");
+        }
+
+        if current_status.is_preconfer() && current_status.is_driver_synced() && !in_safe_mode {
             // do not trigger fast reanchor on submitter window to prevent from double reanchor
             if !current_status.is_submitter()
+                && self.anchor_offset_cadence.should_check()
                 && self
                     .check_and_handle_anchor_offset_for_unsafe_l2_blocks(&l2_slot_ctx.info)
                     .await?
@@ -300,13 +453,22 @@ impl Node {
                 .await
             {
                 self.head_verifier.log_error().await;
+                self.record_unexpected_head_error();
                 self.cancel_token.cancel_on_critical_error();
                 return Err(anyhow::anyhow!(
                     "Unexpected L2 head detected. Restarting node..."
                 ));
             }
 
-            if self
+            let slot_timestamp = l2_slot_ctx.info.slot_timestamp();
+            if !self.slot_block_guard.can_produce(slot_timestamp) {
+                warn!(
+                    "Block already produced for L2 slot timestamp {:?}, skipping slot timestamp {}",
+                    self.slot_block_guard.last_produced_slot_timestamp(),
+                    slot_timestamp
+                );
+                self.metrics.inc_duplicate_slot_blocks_prevented();
+            } else if self
                 .proposal_manager
                 .should_new_block_be_created(&pending_tx_list, &l2_slot_ctx)
             {
@@ -314,31 +476,53 @@ impl Node {
                     .proposal_manager
                     .preconfirm_block(pending_tx_list, &l2_slot_ctx)
                     .await?;
+                self.slot_block_guard.record(slot_timestamp);
+                self.operator_stall.record_block();
 
                 self.verify_preconfed_block(preconfed_block).await?;
             }
         }
+        self.current_batch
+            .update(&self.proposal_manager.batch_summaries())
+            .await;
 
-        if current_status.is_submitter() && !transaction_in_progress {
+        let in_submitter_grace_window = self.submitter_grace_window.is_in_grace_window(
+            current_status.is_submitter(),
+            self.proposal_manager.has_proposals(),
+            l2_slot_ctx.info.slot_timestamp(),
+        );
+        if (current_status.is_submitter() || in_submitter_grace_window) && !transaction_in_progress
+        {
             // first check verifier
-            if self.has_verified_unsent_proposals().await?
-                && let Err(err) = self
+            if self.has_verified_unsent_proposals().await? {
+                if in_submitter_grace_window {
+                    warn!(
+                        "Not the submitter anymore, submitting unsent proposals in the grace window"
+                    );
+                    self.metrics.inc_submitter_grace_submissions();
+                }
+                if let Err(err) = self
                     .proposal_manager
                     .try_submit_oldest_proposal(
                         current_status.is_preconfer(),
                         l2_slot_ctx.info.slot_timestamp(),
                     )
                     .await
-            {
-                if let Some(transaction_error) = err.downcast_ref::<TransactionError>() {
-                    self.handle_transaction_error(transaction_error).await?;
-                } else {
-                    return Err(err);
+                {
+                    if let Some(transaction_error) = err.downcast_ref::<TransactionError>() {
+                        self.handle_transaction_error(transaction_error).await?;
+                    } else {
+                        return Err(err);
+                    }
                 }
             }
         }
 
-        if !current_status.is_submitter() && !current_status.is_preconfer() {
+        if self.role_loss_cooldown.should_reset(
+            current_status.is_submitter()
+                || current_status.is_preconfer()
+                || in_submitter_grace_window,
+        ) {
             if self.proposal_manager.has_proposals()
                 || self.proposal_manager.has_current_forced_inclusion()
             {
@@ -358,6 +542,29 @@ impl Node {
         Ok(())
     }
 
+    fn check_operator_stall(
+        &mut self,
+        can_preconfirm: bool,
+        has_pending_txs: bool,
+    ) -> Result<(), Error> {
+        let epoch = self.ethereum_l1.slot_clock.get_current_epoch()?;
+        let stalled_epochs = self
+            .operator_stall
+            .observe(epoch, can_preconfirm, has_pending_txs);
+        self.metrics.set_operator_stall_epochs(stalled_epochs);
+        if self.operator_stall.is_stalled() {
+            error!(
+                "No block produced for {} consecutive epochs as preconfer with pending transactions, shutting down",
+                stalled_epochs
+            );
+            self.cancel_token.cancel_on_critical_error();
+            return Err(anyhow::anyhow!(
+                "No block produced for {stalled_epochs} consecutive epochs as preconfer with pending transactions"
+            ));
+        }
+        Ok(())
+    }
+
     async fn check_for_missing_sent_proposals(&mut self) -> Result<(), Error> {
         let (taiko_inbox_height, taiko_geth_height) = self.get_current_protocol_height().await?;
 
@@ -365,36 +572,74 @@ impl Node {
             "📨 Taiko Inbox Height: {taiko_inbox_height}, Taiko Geth Height: {taiko_geth_height}"
         );
 
+        // Alert only, the height can drop on a reorg or after another operator's blocks
+        if self.l2_height_tracker.observe(taiko_geth_height) {
+            warn!(
+                "Taiko Geth height regressed to {} from {:?}",
+                taiko_geth_height,
+                self.l2_height_tracker.highest_height()
+            );
+            self.metrics.inc_l2_height_regression();
+        }
+
         if taiko_inbox_height == taiko_geth_height {
             return Ok(());
         } else {
-            let nonce_latest: u64 = self
-                .ethereum_l1
-                .execution_layer
-                .get_preconfer_nonce_latest()
-                .await?;
-            let nonce_pending: u64 = self
-                .ethereum_l1
-                .execution_layer
-                .get_preconfer_nonce_pending()
-                .await?;
+            // Bounded to one slot, as the wait delays the first preconfirmation heartbeat
+            let max_wait = Duration::from_secs(self.config.pending_nonce_settle_max_wait_sec)
+                .min(self.ethereum_l1.slot_clock.get_slot_duration());
+            let execution_layer = &self.ethereum_l1.execution_layer;
+            let (nonce_latest, nonce_pending) = wait_for_pending_nonce_to_settle(
+                || async move {
+                    let nonce_latest = execution_layer.get_preconfer_nonce_latest().await?;
+                    let nonce_pending = execution_layer.get_preconfer_nonce_pending().await?;
+                    Ok((nonce_latest, nonce_pending))
+                },
+                Duration::from_secs(2),
+                max_wait,
+            )
+            .await?;
             debug!("Nonce Latest: {nonce_latest}, Nonce Pending: {nonce_pending}");
-            if nonce_latest == nonce_pending {
+
+            // An in-flight proposal may have landed while waiting
+            let (taiko_inbox_height, taiko_geth_height) =
+                self.get_current_protocol_height().await?;
+            if taiko_inbox_height == taiko_geth_height {
+                info!("All L2 blocks proposed after the pending nonce settled");
+                return Ok(());
+            }
+            if nonce_latest == nonce_pending
+                && exceeds_startup_recovery_cap(
+                    taiko_inbox_height,
+                    taiko_geth_height,
+                    self.config.max_startup_recovery_blocks,
+                )
+            {
+                warn!(
+                    "Taiko Geth is {} blocks ahead of the inbox, exceeding the startup recovery cap of {} blocks. Reanchoring from inbox height.",
+                    taiko_geth_height.saturating_sub(taiko_inbox_height),
+                    self.config.max_startup_recovery_blocks.unwrap_or_default()
+                );
+                self.reanchor_blocks(taiko_inbox_height, "too many blocks to recover on startup")
+                    .await?;
+            } else if nonce_latest == nonce_pending {
                 // Just create a new verifier, we will check it in preconfirmation loop
                 self.verifier = Some(
                     Verifier::new_with_taiko_height(
                         taiko_geth_height,
                         self.taiko.clone(),
                         self.proposal_manager.clone_without_proposals(0), // it does not matter here, we will update it in Verifier.handle_unprocessed_blocks
-                        0,
+                        None,                                             // verify immediately
                         self.cancel_token.clone(),
                         self.last_safe_l2_block_finder.clone(),
                     )
                     .await?,
                 );
             } else {
+                self.metrics.inc_pending_nonce_gap_shutdowns();
                 error!(
-                    "Error: Pending nonce is not equal to latest nonce. Nonce Latest: {nonce_latest}, Nonce Pending: {nonce_pending}"
+                    "Error: Pending nonce is not equal to latest nonce after waiting {}s. Nonce Latest: {nonce_latest}, Nonce Pending: {nonce_pending}",
+                    max_wait.as_secs()
                 );
                 return Err(Error::msg("Pending nonce is not equal to latest nonce"));
             }
@@ -405,39 +650,20 @@ impl Node {
 
     /// Returns true if the operation succeeds
     async fn has_verified_unsent_proposals(&mut self) -> Result<bool, Error> {
-        if let Some(mut verifier) = self.verifier.take() {
-            match verifier.verify(self.metrics.clone()).await {
-                Ok(res) => match res {
-                    VerificationResult::SlotNotValid => {
-                        self.verifier = Some(verifier);
-                        return Ok(false);
-                    }
-                    VerificationResult::ReanchorNeeded(block, reason) => {
-                        if let Err(err) = self.reanchor_blocks(block, &reason).await {
-                            error!("Failed to reanchor blocks: {}", err);
-                            self.cancel_token.cancel_on_critical_error();
-                            return Err(err);
-                        }
-                    }
-                    VerificationResult::SuccessWithProposals(proposals) => {
-                        self.proposal_manager.prepend_proposals(proposals);
-                    }
-                    VerificationResult::SuccessNoProposals => {}
-                    VerificationResult::VerificationInProgress => {
-                        self.verifier = Some(verifier);
-                        return Ok(false);
-                    }
-                },
-                Err(err) => {
-                    self.verifier = Some(verifier);
-                    return Err(err);
-                }
-            }
+        if bypass_verifier(&mut self.verifier, self.config.disable_verifier) {
+            return Ok(true);
         }
-        Ok(true)
+        let mut verifier = self.verifier.take();
+        let metrics = self.metrics.clone();
+        let result = verifier::has_verified_unsent_proposals(&mut verifier, self, metrics).await;
+        if verifier.is_some() {
+            self.verifier = verifier;
+        }
+        result
     }
 
     async fn handle_transaction_error(&mut self, error: &TransactionError) -> Result<(), Error> {
+        self.metrics.inc_transaction_error(error);
         match error {
             TransactionError::ReanchorRequired => {
                 warn!("Unexpected ReanchorRequired error received");
@@ -509,6 +735,21 @@ impl Node {
                 warn!("Proposal transaction executed too late.");
                 Ok(())
             }
+            TransactionError::ContractRevert(contract_error) => {
+                if contract_error.is_not_the_operator() {
+                    warn!(
+                        "Proposal transaction executed too late, reverted with {:?}",
+                        contract_error
+                    );
+                    return Ok(());
+                }
+                self.cancel_token.cancel_on_critical_error();
+                Err(anyhow::anyhow!(
+                    "Transaction reverted with {:?} ({}), exiting",
+                    contract_error,
+                    contract_error.selector()
+                ))
+            }
             TransactionError::BuildFailed => {
                 self.cancel_token.cancel_on_critical_error();
                 Err(anyhow::anyhow!("Transaction build failed, exiting"))
@@ -541,7 +782,11 @@ impl Node {
                 Ok(info) => {
                     self.taiko
                         .get_pending_l2_tx_list_from_l2_engine(
-                            info.base_fee(),
+                            clamp_base_fee(
+                                info.base_fee(),
+                                self.config.pending_tx_base_fee_min,
+                                self.config.pending_tx_base_fee_max,
+                            ),
                             proposals_ready_to_send,
                             gas_limit_without_anchor,
                         )
@@ -563,16 +808,44 @@ impl Node {
         Ok((l2_slot_info?, current_status?, pending_tx_list?))
     }
 
+    fn record_unexpected_head_error(&mut self) {
+        match self.safe_mode.record_error(unix_timestamp_sec()) {
+            Ok(true) => {
+                error!(
+                    "Too many unexpected L2 head errors, entering safe mode: preconfirmation is disabled"
+                );
+                self.metrics.set_safe_mode(true);
+            }
+            Ok(false) => {}
+            Err(err) => error!("Failed to record unexpected L2 head error: {}", err),
+        }
+    }
+
     async fn verify_preconfed_block(
-        &self,
+        &mut self,
         l2_block: BuildPreconfBlockResponse,
     ) -> Result<(), Error> {
+        if self.config.verify_produced_block
+            && !verify_produced_block_stored_in_geth(
+                self.taiko.l2_execution_layer().common(),
+                &l2_block,
+                &self.metrics,
+            )
+            .await?
+        {
+            self.cancel_token.cancel_on_critical_error();
+            return Err(anyhow::anyhow!(
+                "Produced block hash mismatch with Geth. Restarting node..."
+            ));
+        }
+
         if !self
             .head_verifier
             .verify_next_and_set(l2_block.number, l2_block.hash, l2_block.parent_hash)
             .await
         {
             self.head_verifier.log_error().await;
+            self.record_unexpected_head_error();
             self.cancel_token.cancel_on_critical_error();
             return Err(anyhow::anyhow!(
                 "Unexpected L2 head after preconfirmation. Restarting node..."
@@ -591,10 +864,15 @@ impl Node {
         let taiko_inbox_height = self.last_safe_l2_block_finder.get().await?;
         if taiko_inbox_height < l2_slot_info.parent_id() {
             let l2_block_id = taiko_inbox_height + 1;
-            let (anchor_offset, timestamp_offset) = self
-                .proposal_manager
-                .get_l1_anchor_block_and_timestamp_offset_for_l2_block(l2_block_id)
-                .await?;
+            let Some((anchor_offset, timestamp_offset)) = offsets_for_fast_reanchor_check(
+                l2_block_id,
+                self.proposal_manager
+                    .get_l1_anchor_block_and_timestamp_offset_for_l2_block(l2_block_id),
+            )
+            .await
+            else {
+                return Ok(false);
+            };
 
             if !self
                 .proposal_manager
@@ -712,28 +990,47 @@ impl Node {
 
     async fn warmup(&mut self) -> Result<(), Error> {
         info!("Warmup node");
+        let warmup_start = std::time::Instant::now();
 
-        // Wait for Inbox activation
-        let mut activation_timestamp = self
-            .ethereum_l1
-            .execution_layer
-            .get_activation_timestamp()
-            .await?;
+        let ((), inbox_activation) = measure_phase(self.wait_for_inbox_activation()).await?;
+        self.metrics
+            .set_warmup_phase_duration("inbox_activation", inbox_activation);
 
-        while activation_timestamp == 0 {
-            warn!(
-                "Shasta Inbox is not activated yet. Waiting {} seconds...",
-                self.ethereum_l1.slot_clock.get_slot_duration().as_secs()
-            );
-            sleep(self.ethereum_l1.slot_clock.get_slot_duration()).await;
-            activation_timestamp = self
-                .ethereum_l1
-                .execution_layer
-                .get_activation_timestamp()
-                .await?;
-        }
+        let ((), geth_catch_up) = measure_phase(self.wait_for_geth_catch_up()).await?;
+        self.metrics
+            .set_warmup_phase_duration("geth_catch_up", geth_catch_up);
+
+        let ((), sent_transactions) = measure_phase(self.wait_for_sent_transactions()).await?;
+        self.metrics
+            .set_warmup_phase_duration("sent_transactions", sent_transactions);
+
+        let warmup_duration = warmup_start.elapsed();
+        self.metrics.set_warmup_duration(warmup_duration);
+        info!(
+            "Warmup finished in {} ms (inbox activation: {} ms, geth catch-up: {} ms, sent transactions: {} ms)",
+            warmup_duration.as_millis(),
+            inbox_activation.as_millis(),
+            geth_catch_up.as_millis(),
+            sent_transactions.as_millis()
+        );
+
+        Ok(())
+    }
+
+    async fn wait_for_inbox_activation(&self) -> Result<(), Error> {
+        let execution_layer = &self.ethereum_l1.execution_layer;
+        wait_for_activation(
+            || execution_layer.get_activation_timestamp(),
+            execution_layer.shasta_inbox_address(),
+            self.ethereum_l1.slot_clock.get_slot_duration(),
+            self.config
+                .inbox_activation_max_wait_sec
+                .map(Duration::from_secs),
+        )
+        .await
+    }
 
-        // Wait for Taiko Geth to synchronize with L1
+    async fn wait_for_geth_catch_up(&self) -> Result<(), Error> {
         loop {
             let (l1_proposal_id, l2_proposal_id) = self.get_next_proposal_id().await?;
             info!(
@@ -741,7 +1038,7 @@ impl Node {
             );
 
             if l1_proposal_id <= l2_proposal_id {
-                break;
+                return Ok(());
             }
 
             warn!(
@@ -749,35 +1046,28 @@ impl Node {
             );
             sleep(Duration::from_secs(5)).await;
         }
-
-        // Wait for the last sent transaction to be executed
-        self.wait_for_sent_transactions().await?;
-
-        Ok(())
     }
 
     async fn wait_for_sent_transactions(&self) -> Result<(), Error> {
-        loop {
-            let nonce_latest: u64 = self
-                .ethereum_l1
-                .execution_layer
-                .get_preconfer_nonce_latest()
-                .await?;
-            let nonce_pending: u64 = self
-                .ethereum_l1
-                .execution_layer
-                .get_preconfer_nonce_pending()
-                .await?;
-            if nonce_pending == nonce_latest {
-                break;
-            }
-            debug!(
-                "Waiting for sent transactions to be executed. Nonce Latest: {nonce_latest}, Nonce Pending: {nonce_pending}"
-            );
-            sleep(Duration::from_secs(6)).await;
-        }
-
-        Ok(())
+        let execution_layer = &self.ethereum_l1.execution_layer;
+        wait_for_nonce_gap_to_close(
+            || async move {
+                let nonce_latest = execution_layer.get_preconfer_nonce_latest().await?;
+                let nonce_pending = execution_layer.get_preconfer_nonce_pending().await?;
+                Ok((nonce_latest, nonce_pending))
+            },
+            |nonce| async move {
+                let tx_hash = execution_layer.resubmit_pending_transaction(nonce).await?;
+                self.metrics.inc_nonce_gap_self_heals();
+                warn!("Replaced stuck transaction with nonce {nonce} by {tx_hash}");
+                Ok(())
+            },
+            Duration::from_secs(6),
+            self.config
+                .nonce_gap_self_heal_timeout_sec
+                .map(Duration::from_secs),
+        )
+        .await
     }
 
     async fn reanchor_blocks(&mut self, parent_block_id: u64, reason: &str) -> Result<(), Error> {
@@ -791,12 +1081,16 @@ impl Node {
         // Update self state
         self.verifier = None;
         self.proposal_manager.reset_builder().await?;
+        // The L2 head goes back to the parent block, which is not a regression
+        self.l2_height_tracker.reset();
 
         self.chain_monitor.set_expected_reorg(parent_block_id).await;
 
+        // With lazy fetching only the transaction hashes are fetched here, the full
+        // transactions of the non-empty blocks are fetched before the first one is rebuilt
         let blocks = self
             .taiko
-            .fetch_l2_blocks_until_latest(parent_block_id + 1, true)
+            .fetch_l2_blocks_until_latest(parent_block_id + 1, !self.config.reanchor_lazy_tx_fetch)
             .await?;
 
         let mut forced_inclusion_flags: Vec<bool> = Vec::with_capacity(blocks.len());
@@ -822,6 +1116,17 @@ impl Node {
             .await;
 
         self.metrics.inc_by_blocks_reanchored(blocks_reanchored);
+        self.metrics.set_last_reanchor_size(blocks_reanchored);
+        if exceeds_reanchor_alert_threshold(blocks_reanchored, self.config.reanchor_alert_threshold)
+        {
+            error!(
+                "Reanchored {} blocks for parent block {} (reason: {}), exceeding the alert threshold of {} blocks",
+                blocks_reanchored,
+                parent_block_id,
+                reason,
+                self.config.reanchor_alert_threshold.unwrap_or_default()
+            );
+        }
 
         debug!(
             "Finished reanchoring blocks for parent block {} in {} ms",
@@ -831,3 +1136,455 @@ impl Node {
         Ok(())
     }
 }
+
+impl VerificationOutcome for Node {
+    async fn reanchor(&mut self, parent_block_id: u64, reason: &str) -> Result<(), Error> {
+        if let Err(err) = self.reanchor_blocks(parent_block_id, reason).await {
+            error!("Failed to reanchor blocks: {}", err);
+            self.cancel_token.cancel_on_critical_error();
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    fn prepend_proposals(&mut self, proposals: proposal_manager::proposal::Proposals) {
+        self.proposal_manager.prepend_proposals(proposals);
+    }
+}
+
+/// Gives in-flight transactions up to `max_wait` to be included before the pending and latest
+/// preconfer nonces are compared. Returns the last observed nonces.
+async fn wait_for_pending_nonce_to_settle<N, NFut>(
+    mut get_nonces: N,
+    poll_interval: Duration,
+    max_wait: Duration,
+) -> Result<(u64, u64), Error>
+where
+    N: FnMut() -> NFut,
+    NFut: Future<Output = Result<(u64, u64), Error>>,
+{
+    let start = tokio::time::Instant::now();
+    let mut gap_observed = false;
+    loop {
+        let (nonce_latest, nonce_pending) = get_nonces().await?;
+        if nonce_latest == nonce_pending {
+            if gap_observed {
+                info!(
+                    "Pending nonce settled to latest nonce {} after {} seconds",
+                    nonce_latest,
+                    start.elapsed().as_secs()
+                );
+            }
+            return Ok((nonce_latest, nonce_pending));
+        }
+        if start.elapsed() >= max_wait {
+            return Ok((nonce_latest, nonce_pending));
+        }
+        gap_observed = true;
+        debug!(
+            "Waiting for in-flight transactions. Nonce Latest: {nonce_latest}, Nonce Pending: {nonce_pending}"
+        );
+        sleep(poll_interval).await;
+    }
+}
+
+/// Waits until the pending and latest preconfer nonces match. When `self_heal_timeout` is set,
+/// the stuck transaction at the latest nonce is replaced each time the timeout elapses.
+async fn wait_for_nonce_gap_to_close<N, NFut, C, CFut>(
+    mut get_nonces: N,
+    mut replace_stuck_transaction: C,
+    poll_interval: Duration,
+    self_heal_timeout: Option<Duration>,
+) -> Result<(), Error>
+where
+    N: FnMut() -> NFut,
+    NFut: Future<Output = Result<(u64, u64), Error>>,
+    C: FnMut(u64) -> CFut,
+    CFut: Future<Output = Result<(), Error>>,
+{
+    let mut gap_since = tokio::time::Instant::now();
+    loop {
+        let (nonce_latest, nonce_pending) = get_nonces().await?;
+        if nonce_pending == nonce_latest {
+            return Ok(());
+        }
+        debug!(
+            "Waiting for sent transactions to be executed. Nonce Latest: {nonce_latest}, Nonce Pending: {nonce_pending}"
+        );
+        if let Some(self_heal_timeout) = self_heal_timeout
+            && gap_since.elapsed() >= self_heal_timeout
+        {
+            warn!(
+                "Nonce gap not closed after {} seconds, replacing stuck transaction with nonce {}",
+                self_heal_timeout.as_secs(),
+                nonce_latest
+            );
+            if let Err(err) = replace_stuck_transaction(nonce_latest).await {
+                warn!("Failed to replace stuck transaction with nonce {nonce_latest}: {err}");
+            }
+            gap_since = tokio::time::Instant::now();
+        }
+        sleep(poll_interval).await;
+    }
+}
+
+/// Polls the Inbox activation timestamp until it is set. Fails once `max_wait` elapses,
+/// so a misconfigured Inbox address does not keep the node in warmup forever.
+async fn wait_for_activation<F, Fut>(
+    mut get_activation_timestamp: F,
+    inbox_address: alloy::primitives::Address,
+    poll_interval: Duration,
+    max_wait: Option<Duration>,
+) -> Result<(), Error>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<u64, Error>>,
+{
+    let start = tokio::time::Instant::now();
+    while get_activation_timestamp().await? == 0 {
+        if let Some(max_wait) = max_wait
+            && start.elapsed() >= max_wait
+        {
+            return Err(anyhow::anyhow!(
+                "Shasta Inbox {} is not activated after {} seconds. Check SHASTA_INBOX_ADDRESS",
+                inbox_address,
+                max_wait.as_secs()
+            ));
+        }
+        warn!(
+            "Shasta Inbox {} is not activated yet. Waiting {} seconds...",
+            inbox_address,
+            poll_interval.as_secs()
+        );
+        sleep(poll_interval).await;
+    }
+    Ok(())
+}
+
+/// Runs a warmup phase and returns its result together with the time it took.
+async fn measure_phase<T>(
+    phase: impl Future<Output = Result<T, Error>>,
+) -> Result<(T, Duration), Error> {
+    let start = tokio::time::Instant::now();
+    let result = phase.await?;
+    Ok((result, start.elapsed()))
+}
+
+/// Keeps the base fee used to fetch pending transactions within the configured bounds,
+/// guarding against base fee anomalies which would select no or all transactions.
+fn clamp_base_fee(base_fee: u64, min: Option<u64>, max: Option<u64>) -> u64 {
+    let clamped = base_fee
+        .max(min.unwrap_or(u64::MIN))
+        .min(max.unwrap_or(u64::MAX));
+    if clamped != base_fee {
+        warn!(
+            "Clamping base fee for pending tx list from {} to {} wei",
+            base_fee, clamped
+        );
+    }
+    clamped
+}
+
+/// Anchor and timestamp offsets of the given L2 block, or None when the lookup failed. The fast
+/// reanchor check is proactive only, so a failed lookup skips it instead of failing the heartbeat.
+async fn offsets_for_fast_reanchor_check(
+    l2_block_id: u64,
+    lookup: impl Future<Output = Result<(u64, u64), Error>>,
+) -> Option<(u64, u64)> {
+    match lookup.await {
+        Ok(offsets) => Some(offsets),
+        Err(err) => {
+            warn!(
+                "Failed to get anchor offset for l2 block id {}, skipping fast reanchor check: {}",
+                l2_block_id, err
+            );
+            None
+        }
+    }
+}
+
+fn should_retry_read(err: &Error, retries: u64, max_retries: u64, cancelled: bool) -> bool {
+    retries < max_retries && !cancelled && is_transient_error(err)
+}
+
+/// Retries an idempotent heartbeat read on transient (RPC/timeout) errors, up to the configured
+/// number of retries with a delay in between. Fatal errors are returned immediately.
+async fn retry_transient_read<T>(
+    max_retries: u64,
+    retry_delay: Duration,
+    cancel_token: &CancellationToken,
+    mut read: impl AsyncFnMut() -> Result<T, Error>,
+) -> Result<T, Error> {
+    let mut retries = 0;
+    loop {
+        match read().await {
+            Err(err)
+                if should_retry_read(&err, retries, max_retries, cancel_token.is_cancelled()) =>
+            {
+                retries += 1;
+                warn!(
+                    "Transient error reading the heartbeat state, retrying ({}/{}): {}",
+                    retries, max_retries, err
+                );
+                sleep(retry_delay).await;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Feeds the heartbeat result to the watchdog, which is only incremented once the step failed.
+fn record_heartbeat_result(
+    watchdog: &mut common_utils::watchdog::Watchdog,
+    result: Result<(), Error>,
+) {
+    if let Err(err) = result {
+        error!("Failed to execute main block preconfirmation step: {}", err);
+        watchdog.increment();
+    } else {
+        watchdog.reset();
+    }
+}
+
+/// Returns true if recovering every unproposed block on startup would exceed the configured cap.
+fn exceeds_startup_recovery_cap(
+    taiko_inbox_height: u64,
+    taiko_geth_height: u64,
+    max_startup_recovery_blocks: Option<u64>,
+) -> bool {
+    max_startup_recovery_blocks
+        .is_some_and(|max_blocks| taiko_geth_height.saturating_sub(taiko_inbox_height) > max_blocks)
+}
+
+/// With the verifier disabled any pending verification is dropped,
+/// so proposals are submitted without being verified. Debugging only.
+fn bypass_verifier<V>(verifier: &mut Option<V>, disable_verifier: bool) -> bool {
+    if disable_verifier && verifier.take().is_some() {
+        warn!("Verifier is disabled, submitting proposals without verification");
+    }
+    disable_verifier
+}
+
+fn exceeds_reanchor_alert_threshold(blocks_reanchored: u64, threshold: Option<u64>) -> bool {
+    threshold.is_some_and(|threshold| blocks_reanchored > threshold)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_large_reanchor_exceeds_alert_threshold() {
+        assert!(exceeds_reanchor_alert_threshold(500, Some(64)));
+        assert!(!exceeds_reanchor_alert_threshold(64, Some(64)));
+        assert!(!exceeds_reanchor_alert_threshold(3, Some(64)));
+        assert!(!exceeds_reanchor_alert_threshold(500, None));
+    }
+
+    #[test]
+    fn test_startup_recovery_cap() {
+        // Geth thousands of blocks ahead of the inbox
+        assert!(exceeds_startup_recovery_cap(1_000, 6_000, Some(768)));
+        assert!(!exceeds_startup_recovery_cap(1_000, 1_500, Some(768)));
+        assert!(!exceeds_startup_recovery_cap(1_000, 1_768, Some(768)));
+        assert!(!exceeds_startup_recovery_cap(1_000, 6_000, None));
+    }
+
+    #[test]
+    fn test_clamp_base_fee() {
+        assert_eq!(
+            clamp_base_fee(0, Some(10_000_000), Some(1_000_000_000)),
+            10_000_000
+        );
+        assert_eq!(
+            clamp_base_fee(u64::MAX, Some(10_000_000), Some(1_000_000_000)),
+            1_000_000_000
+        );
+        assert_eq!(
+            clamp_base_fee(25_000_000, Some(10_000_000), Some(1_000_000_000)),
+            25_000_000
+        );
+        assert_eq!(clamp_base_fee(0, None, None), 0);
+        assert_eq!(clamp_base_fee(u64::MAX, None, None), u64::MAX);
+    }
+
+    #[test]
+    fn test_disabled_verifier_is_bypassed() {
+        let mut verifier = Some(());
+        assert!(!bypass_verifier(&mut verifier, false));
+        assert!(verifier.is_some());
+
+        assert!(bypass_verifier(&mut verifier, true));
+        assert!(verifier.is_none());
+        assert!(bypass_verifier(&mut verifier, true));
+    }
+
+    #[tokio::test]
+    async fn test_fast_reanchor_check_skipped_on_failed_lookup() {
+        let skipped = offsets_for_fast_reanchor_check(11, async {
+            Err(Error::from(
+                alloy::transports::TransportErrorKind::backend_gone(),
+            ))
+        })
+        .await;
+        assert!(skipped.is_none());
+
+        let offsets = offsets_for_fast_reanchor_check(11, async { Ok((3, 36)) }).await;
+        assert_eq!(offsets, Some((3, 36)));
+    }
+
+    #[test]
+    fn test_retry_read_only_on_transient_errors() {
+        let transient = Error::from(alloy::transports::TransportErrorKind::backend_gone());
+        assert!(should_retry_read(&transient, 0, 2, false));
+        assert!(should_retry_read(&transient, 1, 2, false));
+        assert!(!should_retry_read(&transient, 2, 2, false));
+        assert!(!should_retry_read(&transient, 0, 2, true));
+
+        let fatal = anyhow::anyhow!("Unexpected L2 head detected. Restarting node...");
+        assert!(!should_retry_read(&fatal, 0, 2, false));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_heartbeat_retries_transient_read_before_tripping_watchdog() {
+        let cancel_token = CancellationToken::new(Arc::new(Metrics::new()));
+        // Trips on the first failed heartbeat
+        let mut watchdog = common_utils::watchdog::Watchdog::new(cancel_token.clone(), 0);
+
+        // The slot info read fails once with an RPC error, then succeeds after a delay
+        let mut reads = 0;
+        let start = tokio::time::Instant::now();
+        let result =
+            retry_transient_read(2, HEARTBEAT_READ_RETRY_DELAY, &cancel_token, async || {
+                reads += 1;
+                if reads == 1 {
+                    return Err(Error::from(
+                        alloy::transports::TransportErrorKind::backend_gone(),
+                    ));
+                }
+                Ok(())
+            })
+            .await;
+        assert_eq!(reads, 2);
+        assert_eq!(start.elapsed(), HEARTBEAT_READ_RETRY_DELAY);
+        record_heartbeat_result(&mut watchdog, result);
+        assert!(!cancel_token.is_cancelled());
+
+        // A fatal error is not retried and reaches the watchdog
+        let mut reads = 0;
+        let result =
+            retry_transient_read(2, HEARTBEAT_READ_RETRY_DELAY, &cancel_token, async || {
+                reads += 1;
+                Err::<(), _>(anyhow::anyhow!("Unexpected L2 head detected"))
+            })
+            .await;
+        assert_eq!(reads, 1);
+        record_heartbeat_result(&mut watchdog, result);
+        assert!(cancel_token.is_cancelled());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_measure_phase_durations() {
+        let ((), inbox_activation) = measure_phase(async {
+            sleep(Duration::from_secs(12)).await;
+            Ok(())
+        })
+        .await
+        .expect("phase should succeed");
+        let (value, geth_catch_up) = measure_phase(async {
+            sleep(Duration::from_secs(5)).await;
+            Ok(7)
+        })
+        .await
+        .expect("phase should succeed");
+
+        assert_eq!(inbox_activation, Duration::from_secs(12));
+        assert_eq!(geth_catch_up, Duration::from_secs(5));
+        assert_eq!(value, 7);
+
+        assert!(
+            measure_phase::<()>(async { Err(anyhow::anyhow!("phase failed")) })
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_inbox_activation_times_out() {
+        let polls = std::cell::Cell::new(0);
+        let start = tokio::time::Instant::now();
+        let err = wait_for_activation(
+            || {
+                polls.set(polls.get() + 1);
+                async { Ok(0) }
+            },
+            alloy::primitives::Address::ZERO,
+            Duration::from_secs(12),
+            Some(Duration::from_secs(60)),
+        )
+        .await
+        .expect_err("activation never occurs");
+
+        assert!(
+            err.to_string()
+                .contains("is not activated after 60 seconds")
+        );
+        assert_eq!(start.elapsed(), Duration::from_secs(60));
+        assert_eq!(polls.get(), 6);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_nonce_gap_self_heal_at_startup() {
+        // A transaction with nonce 5 is stuck from before the restart
+        let replaced = &std::cell::Cell::new(None);
+        let start = tokio::time::Instant::now();
+        wait_for_nonce_gap_to_close(
+            || async move {
+                let nonce_latest = if replaced.get().is_some() { 6 } else { 5 };
+                Ok((nonce_latest, 6))
+            },
+            |nonce| {
+                replaced.set(Some(nonce));
+                async { Ok(()) }
+            },
+            Duration::from_secs(6),
+            Some(Duration::from_secs(30)),
+        )
+        .await
+        .expect("nonce gap should close");
+
+        assert_eq!(replaced.get(), Some(5));
+        assert_eq!(start.elapsed(), Duration::from_secs(36));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_pending_nonce_gap_closes_within_wait() {
+        // The in-flight transaction is included on the third poll
+        let polls = &std::cell::Cell::new(0);
+        let start = tokio::time::Instant::now();
+        let nonces = wait_for_pending_nonce_to_settle(
+            || async move {
+                polls.set(polls.get() + 1);
+                let nonce_latest = if polls.get() < 3 { 5 } else { 6 };
+                Ok((nonce_latest, 6))
+            },
+            Duration::from_secs(2),
+            Duration::from_secs(12),
+        )
+        .await
+        .expect("nonces should be read");
+
+        assert_eq!(nonces, (6, 6));
+        assert_eq!(start.elapsed(), Duration::from_secs(4));
+
+        // A persisting gap is returned once the wait elapses
+        let nonces = wait_for_pending_nonce_to_settle(
+            || async { Ok((5, 6)) },
+            Duration::from_secs(2),
+            Duration::from_secs(12),
+        )
+        .await
+        .expect("nonces should be read");
+        assert_eq!(nonces, (5, 6));
+    }
+}
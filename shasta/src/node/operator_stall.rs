@@ -0,0 +1,108 @@
+/// Counts consecutive epochs in which the node was the preconfer with pending transactions but
+/// produced no block, a silent stall which the watchdog does not catch as long as the heartbeat
+/// keeps running. An idle epoch without pending transactions is not a stall.
+pub struct OperatorStallDetector {
+    max_epochs: u64,
+    epoch: Option<u64>,
+    pending_txs_as_operator: bool,
+    blocks_in_epoch: u64,
+    stalled_epochs: u64,
+}
+
+impl OperatorStallDetector {
+    /// A limit of zero epochs disables the detection.
+    pub fn new(max_epochs: u64) -> Self {
+        Self {
+            max_epochs,
+            epoch: None,
+            pending_txs_as_operator: false,
+            blocks_in_epoch: 0,
+            stalled_epochs: 0,
+        }
+    }
+
+    /// Records the operator role and whether transactions were pending in the current heartbeat.
+    /// Returns the number of consecutive completed epochs with pending transactions as operator
+    /// without a produced block.
+    pub fn observe(&mut self, epoch: u64, is_operator: bool, has_pending_txs: bool) -> u64 {
+        if self.epoch != Some(epoch) {
+            if self.epoch.is_some() {
+                self.stalled_epochs = if self.pending_txs_as_operator && self.blocks_in_epoch == 0 {
+                    self.stalled_epochs + 1
+                } else {
+                    0
+                };
+            }
+            self.epoch = Some(epoch);
+            self.pending_txs_as_operator = false;
+            self.blocks_in_epoch = 0;
+        }
+        self.pending_txs_as_operator |= is_operator && has_pending_txs;
+        self.stalled_epochs
+    }
+
+    pub fn record_block(&mut self) {
+        self.blocks_in_epoch += 1;
+    }
+
+    pub fn is_stalled(&self) -> bool {
+        self.max_epochs > 0 && self.stalled_epochs >= self.max_epochs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operator_epochs_without_blocks() {
+        let mut detector = OperatorStallDetector::new(3);
+        // Epoch 10 with a block, 11 and 12 without
+        detector.observe(10, true, true);
+        detector.record_block();
+        assert_eq!(detector.observe(11, true, true), 0);
+        assert_eq!(detector.observe(12, true, true), 1);
+
+        // A block produced resets the streak
+        detector.record_block();
+        assert_eq!(detector.observe(13, true, true), 0);
+
+        // Epochs as operator with pending transactions but without any block
+        assert_eq!(detector.observe(14, true, true), 1);
+        assert_eq!(detector.observe(15, true, true), 2);
+        assert!(!detector.is_stalled());
+        assert_eq!(detector.observe(16, true, true), 3);
+        assert!(detector.is_stalled());
+    }
+
+    #[test]
+    fn test_epoch_without_operator_role_resets_streak() {
+        let mut detector = OperatorStallDetector::new(2);
+        detector.observe(1, true, true);
+        assert_eq!(detector.observe(2, false, true), 1);
+        assert_eq!(detector.observe(3, false, true), 0);
+
+        let mut disabled = OperatorStallDetector::new(0);
+        for epoch in 0..5 {
+            disabled.observe(epoch, true, true);
+        }
+        assert!(!disabled.is_stalled());
+    }
+
+    #[test]
+    fn test_idle_epochs_are_not_stalls() {
+        let mut detector = OperatorStallDetector::new(2);
+        // Operator for several epochs without any pending transaction
+        for epoch in 0..5 {
+            assert_eq!(detector.observe(epoch, true, false), 0);
+        }
+        assert!(!detector.is_stalled());
+
+        // Pending transactions in one heartbeat of the epoch are enough to expect a block
+        detector.observe(5, true, false);
+        detector.observe(5, true, true);
+        assert_eq!(detector.observe(6, true, false), 1);
+        // The idle epoch 6 ends the streak
+        assert_eq!(detector.observe(7, true, false), 0);
+    }
+}
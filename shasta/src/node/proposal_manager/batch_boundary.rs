@@ -0,0 +1,35 @@
+/// Reason why a proposal was closed and moved to the submission queue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchBoundaryReason {
+    /// The next block would exceed the blob bytes limit
+    BytesLimit,
+    /// The proposal reached the maximum number of blocks
+    BlocksLimit,
+    /// The anchor block is too far behind the current L1 slot
+    AnchorOffset,
+    /// The proposal was built for longer than the proposal max time
+    ProposalTime,
+    /// The proposal reached the max batch age without being full
+    BatchAge,
+    /// The proposal was submitted without waiting for it to be full
+    Submission,
+    /// A block of another proposal was found during recovery
+    Recovery,
+    /// The proposal was closed explicitly, e.g. after reanchoring
+    Flush,
+}
+
+impl BatchBoundaryReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BatchBoundaryReason::BytesLimit => "bytes_limit",
+            BatchBoundaryReason::BlocksLimit => "blocks_limit",
+            BatchBoundaryReason::AnchorOffset => "anchor_offset",
+            BatchBoundaryReason::ProposalTime => "proposal_time",
+            BatchBoundaryReason::BatchAge => "batch_age",
+            BatchBoundaryReason::Submission => "submission",
+            BatchBoundaryReason::Recovery => "recovery",
+            BatchBoundaryReason::Flush => "flush",
+        }
+    }
+}
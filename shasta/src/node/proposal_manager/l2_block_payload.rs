@@ -1,6 +1,7 @@
 use alloy::primitives::B256;
 use alloy::rpc::types::Transaction;
 
+#[derive(Clone)]
 pub struct L2BlockV2Payload {
     pub proposal_id: u64,
     pub coinbase: alloy::primitives::Address,
@@ -1,8 +1,10 @@
+mod batch_boundary;
 pub mod block_advancer;
 pub mod l2_block_payload;
 pub mod proposal;
 mod proposal_builder;
 mod proposal_queue;
+mod reanchor;
 
 use crate::{
     l1::execution_layer::ExecutionLayer,
@@ -10,24 +12,30 @@ use crate::{
     metrics::Metrics,
     shared::{l2_block_v2::L2BlockV2Draft, l2_tx_lists::PreBuiltTxList},
 };
-use alloy::{consensus::BlockHeader, consensus::Transaction};
+use alloy::{consensus::BlockHeader, consensus::Transaction, sol_types::SolCall};
 use anyhow::Error;
 use common::{batch_builder::BatchBuilderConfig, shared::l2_slot_info_v2::L2SlotContext};
 use common::{
+    crypto::GOLDEN_TOUCH_ADDRESS,
     l1::{ethereum_l1::EthereumL1, traits::ELTrait},
     l2::taiko_driver::{OperationType, models::BuildPreconfBlockResponse},
     shared::anchor_block_info::AnchorBlockInfo,
-    utils::cancellation_token::CancellationToken,
+    utils::{cancellation_token::CancellationToken, transient_error::is_transient_error},
 };
 use proposal_builder::ProposalBuilder;
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
+use taiko_bindings::anchor::Anchor;
 use tracing::{debug, error, info, warn};
 
 use crate::forced_inclusion::ForcedInclusion;
 use crate::node::L2SlotInfoV2;
+use crate::node::current_batch::BatchSummary;
+use batch_boundary::BatchBoundaryReason;
 use block_advancer::BlockAdvancer;
 use proposal::Proposals;
 
+const ANCHOR_TIMESTAMP_FETCH_RETRY_DELAY: Duration = Duration::from_millis(200);
+
 pub struct ProposalManager {
     proposal_builder: ProposalBuilder,
     ethereum_l1: Arc<EthereumL1<ExecutionLayer>>,
@@ -35,11 +43,16 @@ pub struct ProposalManager {
     block_advancer: Arc<dyn BlockAdvancer>,
     l1_height_lag: u64,
     min_anchor_offset: u64,
+    anchor_confirmation_depth: u64,
     forced_inclusion: ForcedInclusion,
     metrics: Arc<Metrics>,
     cancel_token: CancellationToken,
     max_blocks_to_reanchor: u64,
     propose_forced_inclusion: bool,
+    allow_forced_inclusion_head_rollback: bool,
+    disable_forced_inclusion: bool,
+    driver_transient_error_max_retries: u64,
+    anchor_timestamp_fetch_max_retries: u64,
 }
 
 impl ProposalManager {
@@ -47,6 +60,7 @@ impl ProposalManager {
     pub async fn new(
         l1_height_lag: u64,
         min_anchor_offset: u64,
+        anchor_confirmation_depth: u64,
         config: BatchBuilderConfig,
         ethereum_l1: Arc<EthereumL1<ExecutionLayer>>,
         taiko: Arc<Taiko>,
@@ -55,6 +69,10 @@ impl ProposalManager {
         cancel_token: CancellationToken,
         max_blocks_to_reanchor: u64,
         propose_forced_inclusion: bool,
+        allow_forced_inclusion_head_rollback: bool,
+        disable_forced_inclusion: bool,
+        driver_transient_error_max_retries: u64,
+        anchor_timestamp_fetch_max_retries: u64,
     ) -> Result<Self, Error> {
         info!(
             "Proposal builder config:\n\
@@ -72,7 +90,12 @@ impl ProposalManager {
             config.proposal_max_time_sec,
         );
 
-        let forced_inclusion = ForcedInclusion::new(ethereum_l1.clone()).await?;
+        let forced_inclusion = ForcedInclusion::new(
+            ethereum_l1.clone(),
+            metrics.clone(),
+            allow_forced_inclusion_head_rollback,
+        )
+        .await?;
 
         Ok(Self {
             proposal_builder: ProposalBuilder::new(
@@ -85,11 +108,16 @@ impl ProposalManager {
             block_advancer,
             l1_height_lag,
             min_anchor_offset,
+            anchor_confirmation_depth,
             forced_inclusion,
             metrics,
             cancel_token,
             max_blocks_to_reanchor,
             propose_forced_inclusion,
+            allow_forced_inclusion_head_rollback,
+            disable_forced_inclusion,
+            driver_transient_error_max_retries,
+            anchor_timestamp_fetch_max_retries,
         })
     }
 
@@ -122,10 +150,11 @@ impl ProposalManager {
     }
 
     pub fn should_new_block_be_created(
-        &self,
+        &mut self,
         pending_tx_list: &Option<PreBuiltTxList>,
         l2_slot_context: &L2SlotContext,
     ) -> bool {
+        self.proposal_builder.observe_pending_txs(pending_tx_list);
         self.proposal_builder.should_new_block_be_created(
             pending_tx_list,
             l2_slot_context.info.slot_timestamp(),
@@ -152,7 +181,8 @@ impl ProposalManager {
         {
             // Handle max anchor height offset exceeded
             info!("📈 Maximum allowed anchor height offset exceeded, finalizing current proposal.");
-            self.proposal_builder.finalize_current_proposal();
+            self.proposal_builder
+                .close_current_proposal(BatchBoundaryReason::AnchorOffset);
         }
 
         Ok(preconfed_block)
@@ -163,7 +193,21 @@ impl ProposalManager {
         l2_slot_context: &L2SlotContext,
         operation_type: OperationType,
     ) -> Result<Option<BuildPreconfBlockResponse>, Error> {
+        if self.disable_forced_inclusion {
+            return Ok(None);
+        }
         if !self.proposal_builder.can_add_forced_inclusion() {
+            if self.proposal_builder.should_report_forced_inclusion_cap() {
+                match self.forced_inclusion.has_pending_forced_inclusion().await {
+                    Ok(true) => {
+                        info!("Forced inclusion cap reached for the current proposal");
+                        self.proposal_builder.mark_forced_inclusion_cap_reported();
+                        self.metrics.inc_forced_inclusion_cap_reached();
+                    }
+                    Ok(false) => {}
+                    Err(err) => warn!("Failed to check pending forced inclusions: {}", err),
+                }
+            }
             return Ok(None);
         }
         // get next forced inclusion
@@ -228,6 +272,15 @@ impl ProposalManager {
         allow_forced_inclusion: bool,
     ) -> Result<BuildPreconfBlockResponse, Error> {
         let timestamp = l2_slot_context.info.slot_timestamp();
+        // Reanchored blocks carry historic timestamps, only live blocks tell the skew
+        if matches!(operation_type, OperationType::Preconfirm) {
+            match std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH) {
+                Ok(now) => self
+                    .metrics
+                    .set_block_timestamp_skew(block_timestamp_skew_seconds(timestamp, now)),
+                Err(err) => warn!("Failed to get wall clock time for block timestamp skew: {err}"),
+            }
+        }
         if let Some(last_block_timestamp) = self
             .proposal_builder
             .get_current_proposal_last_block_timestamp()
@@ -238,9 +291,12 @@ impl ProposalManager {
             ));
         }
 
-        let allow_forced_inclusion = self.propose_forced_inclusion
-            && allow_forced_inclusion
-            && !l2_slot_context.end_of_sequencing;
+        let allow_forced_inclusion = is_forced_inclusion_allowed(
+            self.disable_forced_inclusion,
+            self.propose_forced_inclusion,
+            allow_forced_inclusion,
+            l2_slot_context.end_of_sequencing,
+        );
         info!(
             "Adding new L2 block id: {}, timestamp: {}, allow_forced_inclusion: {}",
             l2_slot_context.info.parent_id() + 1,
@@ -254,7 +310,10 @@ impl ProposalManager {
             gas_limit_without_anchor: l2_slot_context.info.parent_gas_limit_without_anchor(),
         };
 
-        if !self.proposal_builder.can_consume_l2_block(&l2_draft_block) {
+        if let Some(reason) = self.proposal_builder.batch_boundary_reason(&l2_draft_block) {
+            self.proposal_builder.close_current_proposal(reason);
+        }
+        if self.proposal_builder.get_current_proposal_id().is_none() {
             // Create new proposal
             let _ = self
                 .create_new_proposal(
@@ -288,10 +347,21 @@ impl ProposalManager {
     ) -> Result<BuildPreconfBlockResponse, Error> {
         let payload = self.proposal_builder.add_l2_draft_block(l2_draft_block)?;
 
-        match self
-            .block_advancer
-            .advance_head_to_new_l2_block(payload, l2_slot_context, operation_type)
-            .await
+        let block_advancer = self.block_advancer.clone();
+        let taiko = self.taiko.clone();
+        let parent_id = l2_slot_context.info.parent_id();
+        match retry_driver_call_while_head_unchanged(
+            self.driver_transient_error_max_retries,
+            || {
+                block_advancer.advance_head_to_new_l2_block(
+                    payload.clone(),
+                    l2_slot_context,
+                    operation_type,
+                )
+            },
+            || async { Ok::<_, Error>(taiko.get_latest_l2_block_id().await? == parent_id) },
+        )
+        .await
         {
             Ok(preconfed_block) => Ok(preconfed_block),
             Err(err) => {
@@ -306,34 +376,18 @@ impl ProposalManager {
     }
 
     async fn get_next_proposal_id(&self, parent_block_id: u64) -> Result<u64, Error> {
-        if let Some(current_proposal_id) = self.proposal_builder.get_current_proposal_id() {
-            return Ok(current_proposal_id + 1);
-        }
-
-        // Try fetching from L2 execution layer
-        match self
-            .taiko
-            .l2_execution_layer()
-            .get_proposal_id_from_geth_by_block_id(parent_block_id)
-            .await
-        {
-            Ok(id) => Ok(id + 1),
-            Err(_) => {
-                // We can't retrieve the proposal ID from the latest L2 anchor block.
-                // This can occur when there are no L2 blocks in Shasta yet.
-                // Therefore, we verify it using the inbox state.
-                warn!("Failed to get last synced proposal id from Taiko Geth");
-                let inbox_state = self.ethereum_l1.execution_layer.get_inbox_state().await?;
-                if inbox_state.nextProposalId == 1 {
-                    Ok(1)
-                } else {
-                    Err(anyhow::anyhow!(
-                        "Failed to get last synced proposal id from Taiko Geth, next_proposal_id = {}",
-                        inbox_state.nextProposalId
-                    ))
-                }
-            }
-        }
+        let (proposal_id, source) = resolve_next_proposal_id(
+            self.proposal_builder.get_current_proposal_id(),
+            self.taiko
+                .l2_execution_layer()
+                .get_proposal_id_from_geth_by_block_id(parent_block_id),
+            self.ethereum_l1
+                .execution_layer
+                .get_inbox_next_proposal_id(),
+        )
+        .await?;
+        self.metrics.inc_proposal_id_source(source.as_str());
+        Ok(proposal_id)
     }
 
     async fn create_new_proposal(
@@ -353,6 +407,7 @@ impl ProposalManager {
             self.l1_height_lag,
             last_anchor_id,
             self.min_anchor_offset,
+            self.anchor_confirmation_depth,
         )
         .await?;
 
@@ -375,7 +430,15 @@ impl ProposalManager {
 
     pub async fn reset_builder(&mut self) -> Result<(), Error> {
         warn!("Resetting proposal builder");
-        self.forced_inclusion.sync_queue_index_with_head().await?;
+        let dropped_forced_inclusions = self.proposal_builder.num_unsent_forced_inclusions();
+        let proposal_in_flight = self
+            .ethereum_l1
+            .execution_layer
+            .is_transaction_in_progress()
+            .await?;
+        self.forced_inclusion
+            .reconcile_with_head_after_reset(dropped_forced_inclusions, proposal_in_flight)
+            .await?;
 
         self.proposal_builder = proposal_builder::ProposalBuilder::new(
             self.proposal_builder.get_config().clone(),
@@ -390,8 +453,12 @@ impl ProposalManager {
         !self.proposal_builder.is_empty()
     }
 
+    pub fn batch_summaries(&self) -> Vec<BatchSummary> {
+        self.proposal_builder.batch_summaries()
+    }
+
     pub fn has_current_forced_inclusion(&self) -> bool {
-        self.proposal_builder.has_current_forced_inclusion()
+        !self.disable_forced_inclusion && self.proposal_builder.has_current_forced_inclusion()
     }
 
     pub fn get_number_of_proposals(&self) -> u64 {
@@ -444,19 +511,28 @@ impl ProposalManager {
             .ok_or_else(|| anyhow::anyhow!("get_anchor_block_offset: No transactions in block"))?;
 
         let l2_anchor_tx = self.taiko.get_transaction_by_hash(*anchor_tx_hash).await?;
-        let l1_anchor_block_id = Taiko::decode_anchor_id_from_tx_data(l2_anchor_tx.input())?;
+        let l1_anchor_block_id = Taiko::decode_anchor_id_from_tx_data(l2_anchor_tx.input())
+            .inspect_err(|_| self.metrics.inc_anchor_decode_failures())?;
 
         debug!(
             "get_l1_anchor_block_and_timestamp_offset_for_l2_block: L2 block {l2_block_height} has L1 anchor block id {l1_anchor_block_id} and  timestamp {block_timestamp}",
         );
 
-        let anchor_offset = self.ethereum_l1.slot_clock.slots_since_l1_block(
-            self.ethereum_l1
-                .execution_layer
-                .common()
-                .get_block_timestamp_by_number(l1_anchor_block_id)
-                .await?,
-        )?;
+        let l1_anchor_block_timestamp = retry_on_transient_error(
+            self.anchor_timestamp_fetch_max_retries,
+            ANCHOR_TIMESTAMP_FETCH_RETRY_DELAY,
+            || {
+                self.ethereum_l1
+                    .execution_layer
+                    .common()
+                    .get_block_timestamp_by_number(l1_anchor_block_id)
+            },
+        )
+        .await?;
+        let anchor_offset = self
+            .ethereum_l1
+            .slot_clock
+            .slots_since_l1_block(l1_anchor_block_timestamp)?;
         let timestamp_offset = self.ethereum_l1.slot_clock.seconds_since(block_timestamp);
         Ok((anchor_offset, timestamp_offset))
     }
@@ -491,16 +567,8 @@ impl ProposalManager {
 
         self.validate_block_timestamp(block_height, block.header.timestamp(), parent_timestamp)?;
 
-        let (anchor_tx, txs) = match block.transactions.as_transactions() {
-            Some(txs) => txs.split_first().ok_or_else(|| {
-                anyhow::anyhow!("recover_from_l2_block: Cannot get anchor transaction from block")
-            })?,
-            None => {
-                return Err(anyhow::anyhow!(
-                    "recover_from_l2_block: No transactions in block"
-                ));
-            }
-        };
+        let (anchor_tx, txs) = split_anchor_transaction(&block)
+            .map_err(|e| anyhow::anyhow!("recover_from_l2_block: {}", e))?;
 
         use taiko_alethia_reth::validation::ANCHOR_V3_V4_GAS_LIMIT;
         let gas_limit = block
@@ -597,11 +665,21 @@ impl ProposalManager {
             block_advancer: self.block_advancer.clone(),
             l1_height_lag: self.l1_height_lag,
             min_anchor_offset: self.min_anchor_offset,
-            forced_inclusion: ForcedInclusion::new_with_index(self.ethereum_l1.clone(), fi_head),
+            anchor_confirmation_depth: self.anchor_confirmation_depth,
+            forced_inclusion: ForcedInclusion::new_with_index(
+                self.ethereum_l1.clone(),
+                fi_head,
+                self.metrics.clone(),
+                self.allow_forced_inclusion_head_rollback,
+            ),
             metrics: self.metrics.clone(),
             cancel_token: self.cancel_token.clone(),
             max_blocks_to_reanchor: self.max_blocks_to_reanchor,
             propose_forced_inclusion: self.propose_forced_inclusion,
+            allow_forced_inclusion_head_rollback: self.allow_forced_inclusion_head_rollback,
+            disable_forced_inclusion: self.disable_forced_inclusion,
+            driver_transient_error_max_retries: self.driver_transient_error_max_retries,
+            anchor_timestamp_fetch_max_retries: self.anchor_timestamp_fetch_max_retries,
         }
     }
 
@@ -651,87 +729,20 @@ impl ProposalManager {
         forced_inclusion_flags: &[bool],
         parent_block_id: u64,
     ) -> Result<u64, Error> {
-        let mut current_block_pos = 0;
-        let mut processed_blocks = 0;
-        let mut is_common_block_processed = false;
-
         // calculate slot info for the first block
         let (first_l2_slot_info, max_blocks_to_reanchor) =
             self.prepare_reanchor_slot_info(parent_block_id).await?;
 
-        while current_block_pos < blocks.len() && processed_blocks < max_blocks_to_reanchor {
-            debug!(
-                "Reanchoring block position {}/{}, processed: {}/{}",
-                current_block_pos,
-                blocks.len(),
-                processed_blocks,
-                max_blocks_to_reanchor
-            );
-
-            if forced_inclusion_flags[current_block_pos] {
-                debug!(
-                    "Skipping forced inclusion block {}",
-                    blocks[current_block_pos].header.number,
-                );
-                current_block_pos += 1;
-                continue;
-            }
-
-            let block = &blocks[current_block_pos];
-            let txs = self.extract_block_transactions(block)?;
-
-            // Skip empty blocks, except the first one
-            if txs.is_empty() && is_common_block_processed {
-                debug!("Skipping empty block {}", block.header.number);
-                current_block_pos += 1;
-                continue;
-            }
-
-            let l2_slot_info = self
-                .get_l2_slot_info_for_reanchor(&first_l2_slot_info, processed_blocks)
-                .await?;
-            debug!(
-                "Reanchoring block {} with {} txs, parent: {}, timestamp: {}",
-                block.header.number,
-                txs.len(),
-                l2_slot_info.parent_id(),
-                l2_slot_info.slot_timestamp(),
-            );
-
-            let pending_tx_list = PreBuiltTxList::new(txs);
-
-            let is_last_reanchored_block = current_block_pos + 1 == blocks.len()
-                || processed_blocks + 1 == max_blocks_to_reanchor;
-            let allow_forced_inclusion = !is_last_reanchored_block;
-
-            match self
-                .reanchor_block(pending_tx_list, l2_slot_info, allow_forced_inclusion)
-                .await
-            {
-                Ok(preconfed_block) => {
-                    debug!(
-                        "Reanchored block {} hash {}, is_forced_inclusion: {}",
-                        preconfed_block.number,
-                        preconfed_block.hash,
-                        preconfed_block.is_forced_inclusion,
-                    );
-                    processed_blocks += 1;
-                    if !preconfed_block.is_forced_inclusion {
-                        is_common_block_processed = true;
-                        current_block_pos += 1;
-                    }
-                }
-                Err(err) => {
-                    error!("Failed to reanchor block {}: {}", block.header.number, err);
-                    self.cancel_token.cancel_on_critical_error();
-                    return Err(anyhow::anyhow!(
-                        "Failed to reanchor block {}: {}",
-                        block.header.number,
-                        err
-                    ));
-                }
-            }
-        }
+        let processed_blocks = reanchor::reanchor_blocks(
+            &mut ProposalManagerReanchor {
+                manager: self,
+                first_l2_slot_info,
+            },
+            blocks,
+            forced_inclusion_flags,
+            max_blocks_to_reanchor,
+        )
+        .await?;
         // finalize the current proposal to avoid anchor and timestamp checks during preconfirmation
         self.try_finalize_current_proposal()?;
         Ok(processed_blocks)
@@ -767,21 +778,470 @@ impl ProposalManager {
             Ok(L2SlotInfoV2::new_from_other(info, timestamp))
         }
     }
+}
 
-    fn extract_block_transactions(
-        &self,
-        block: &alloy::rpc::types::Block,
-    ) -> Result<Vec<alloy::rpc::types::Transaction>, Error> {
-        let (_, txs) = block
-            .transactions
-            .as_transactions()
-            .and_then(|txs| txs.split_first())
-            .ok_or_else(|| {
-                anyhow::anyhow!(
-                    "Cannot extract transactions from block {}",
-                    block.header.number
-                )
-            })?;
-        Ok(txs.to_vec())
+/// Reanchors through the proposal manager, the first block taking the slot info prepared for it.
+struct ProposalManagerReanchor<'a> {
+    manager: &'a mut ProposalManager,
+    first_l2_slot_info: L2SlotInfoV2,
+}
+
+impl reanchor::ReanchorL2 for ProposalManagerReanchor<'_> {
+    async fn get_block_with_txs(&self, number: u64) -> Result<alloy::rpc::types::Block, Error> {
+        self.manager
+            .taiko
+            .get_l2_block_by_number(number, true)
+            .await
+    }
+
+    async fn reanchor_block(
+        &mut self,
+        pending_tx_list: PreBuiltTxList,
+        processed_blocks: u64,
+        allow_forced_inclusion: bool,
+    ) -> Result<BuildPreconfBlockResponse, Error> {
+        let l2_slot_info = self
+            .manager
+            .get_l2_slot_info_for_reanchor(&self.first_l2_slot_info, processed_blocks)
+            .await?;
+        debug!(
+            "Reanchoring {} txs, parent: {}, timestamp: {}",
+            pending_tx_list.get_tx_list().len(),
+            l2_slot_info.parent_id(),
+            l2_slot_info.slot_timestamp(),
+        );
+        self.manager
+            .reanchor_block(pending_tx_list, l2_slot_info, allow_forced_inclusion)
+            .await
+            .inspect_err(|_| self.manager.cancel_token.cancel_on_critical_error())
+    }
+}
+
+/// Splits the block transactions into the anchor transaction and the remaining ones.
+/// Fails unless the first transaction is an anchorV4 call sent by the golden touch address.
+fn split_anchor_transaction(
+    block: &alloy::rpc::types::Block,
+) -> Result<
+    (
+        &alloy::rpc::types::Transaction,
+        &[alloy::rpc::types::Transaction],
+    ),
+    Error,
+> {
+    let Some(txs) = block.transactions.as_transactions() else {
+        if block.transactions.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Block {} is empty, missing anchor transaction",
+                block.header.number
+            ));
+        }
+        return Err(anyhow::anyhow!(
+            "Block {} was fetched without full transactions, cannot get anchor transaction",
+            block.header.number
+        ));
+    };
+    let (anchor_tx, txs) = txs.split_first().ok_or_else(|| {
+        anyhow::anyhow!(
+            "Block {} is empty, missing anchor transaction",
+            block.header.number
+        )
+    })?;
+    if anchor_tx.inner.signer() != GOLDEN_TOUCH_ADDRESS {
+        return Err(anyhow::anyhow!(
+            "Block {} first transaction is not an anchor transaction, sent by {} instead of the golden touch address",
+            block.header.number,
+            anchor_tx.inner.signer()
+        ));
+    }
+    if !anchor_tx
+        .input()
+        .starts_with(&Anchor::anchorV4Call::SELECTOR)
+    {
+        return Err(anyhow::anyhow!(
+            "Block {} first transaction is not an anchor transaction, it does not call anchorV4",
+            block.header.number
+        ));
+    }
+    Ok((anchor_tx, txs))
+}
+
+fn is_forced_inclusion_allowed(
+    disable_forced_inclusion: bool,
+    propose_forced_inclusion: bool,
+    allow_forced_inclusion: bool,
+    end_of_sequencing: bool,
+) -> bool {
+    !disable_forced_inclusion
+        && propose_forced_inclusion
+        && allow_forced_inclusion
+        && !end_of_sequencing
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ProposalIdSource {
+    Builder,
+    Geth,
+    Inbox,
+}
+
+impl ProposalIdSource {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ProposalIdSource::Builder => "builder",
+            ProposalIdSource::Geth => "geth",
+            ProposalIdSource::Inbox => "inbox",
+        }
+    }
+}
+
+/// Difference between the block timestamp and the wall clock in seconds, positive when the block
+/// is ahead of the wall clock.
+#[allow(clippy::cast_precision_loss)]
+fn block_timestamp_skew_seconds(block_timestamp_sec: u64, now: std::time::Duration) -> f64 {
+    block_timestamp_sec as f64 - now.as_secs_f64()
+}
+
+/// Runs the operation again after `retry_delay` while it fails with a transient error,
+/// at most `max_retries` times. Permanent errors are returned immediately.
+async fn retry_on_transient_error<T, F, Fut>(
+    max_retries: u64,
+    retry_delay: Duration,
+    mut operation: F,
+) -> Result<T, Error>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, Error>>,
+{
+    let mut retries = 0;
+    loop {
+        match operation().await {
+            Ok(value) => return Ok(value),
+            Err(err) if retries < max_retries && is_transient_error(&err) => {
+                retries += 1;
+                warn!(
+                    "Transient error, retrying in {:?} ({}/{}): {}",
+                    retry_delay, retries, max_retries, err
+                );
+                tokio::time::sleep(retry_delay).await;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+/// Retries a driver call building a block after a transient error, at most `max_retries` times,
+/// and only while `is_head_unchanged` confirms the L2 head is still at the parent block. A call
+/// failing after it reached the driver may have built the block, which must not be built twice.
+async fn retry_driver_call_while_head_unchanged<T, F, Fut, H, HFut>(
+    max_retries: u64,
+    mut operation: F,
+    mut is_head_unchanged: H,
+) -> Result<T, Error>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, Error>>,
+    H: FnMut() -> HFut,
+    HFut: Future<Output = Result<bool, Error>>,
+{
+    let mut retries = 0;
+    loop {
+        let err = match operation().await {
+            Ok(value) => return Ok(value),
+            Err(err) => err,
+        };
+        if retries >= max_retries || !is_transient_error(&err) {
+            return Err(err);
+        }
+        match is_head_unchanged().await {
+            Ok(true) => {}
+            Ok(false) => {
+                warn!("L2 head moved after a failed driver call, not retrying: {err}");
+                return Err(err);
+            }
+            Err(head_err) => {
+                warn!("Failed to check the L2 head, not retrying the driver call: {head_err}");
+                return Err(err);
+            }
+        }
+        retries += 1;
+        warn!(
+            "Transient driver error, retrying ({}/{}): {}",
+            retries, max_retries, err
+        );
+    }
+}
+
+/// Resolves the next proposal id from the current proposal, then Geth, then the inbox state.
+/// The Geth and inbox futures are only awaited when the previous source is unavailable.
+async fn resolve_next_proposal_id(
+    current_proposal_id: Option<u64>,
+    geth_proposal_id: impl Future<Output = Result<u64, Error>>,
+    inbox_next_proposal_id: impl Future<Output = Result<u64, Error>>,
+) -> Result<(u64, ProposalIdSource), Error> {
+    if let Some(current_proposal_id) = current_proposal_id {
+        return Ok((current_proposal_id + 1, ProposalIdSource::Builder));
+    }
+
+    match geth_proposal_id.await {
+        Ok(id) => Ok((id + 1, ProposalIdSource::Geth)),
+        Err(_) => {
+            // We can't retrieve the proposal ID from the latest L2 anchor block.
+            // This can occur when there are no L2 blocks in Shasta yet.
+            // Therefore, we verify it using the inbox state.
+            warn!("Failed to get last synced proposal id from Taiko Geth");
+            let next_proposal_id = inbox_next_proposal_id.await?;
+            if next_proposal_id == 1 {
+                Ok((1, ProposalIdSource::Inbox))
+            } else {
+                Err(anyhow::anyhow!(
+                    "Failed to get last synced proposal id from Taiko Geth, next_proposal_id = {}",
+                    next_proposal_id
+                ))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_forced_inclusion_allowed() {
+        assert!(is_forced_inclusion_allowed(false, true, true, false));
+        assert!(!is_forced_inclusion_allowed(false, false, true, false));
+        assert!(!is_forced_inclusion_allowed(false, true, false, false));
+        assert!(!is_forced_inclusion_allowed(false, true, true, true));
+    }
+
+    #[test]
+    fn test_no_forced_inclusion_blocks_when_disabled() {
+        for propose_forced_inclusion in [false, true] {
+            for allow_forced_inclusion in [false, true] {
+                for end_of_sequencing in [false, true] {
+                    assert!(!is_forced_inclusion_allowed(
+                        true,
+                        propose_forced_inclusion,
+                        allow_forced_inclusion,
+                        end_of_sequencing,
+                    ));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_block_timestamp_skew() {
+        let now = std::time::Duration::from_millis(1_000_500);
+        assert_eq!(block_timestamp_skew_seconds(1_000, now), -0.5);
+        assert_eq!(block_timestamp_skew_seconds(1_003, now), 2.5);
+
+        let metrics = Metrics::new();
+        metrics.set_block_timestamp_skew(block_timestamp_skew_seconds(1_003, now));
+        assert!(
+            metrics
+                .gather()
+                .contains("catalyst_block_timestamp_skew_seconds 2.5")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retry_on_transient_driver_error() {
+        use common::utils::transient_error::TransientHttpError;
+        use std::sync::atomic::{AtomicU64, Ordering};
+
+        let transient_once = |calls: &AtomicU64| {
+            if calls.fetch_add(1, Ordering::Relaxed) == 0 {
+                return Err(Error::new(TransientHttpError(
+                    "HTTP request failed with status: 502 Bad Gateway".to_string(),
+                )));
+            }
+            Ok(42)
+        };
+
+        // The head is still at the parent, so the block is built again
+        let calls = AtomicU64::new(0);
+        let result = retry_driver_call_while_head_unchanged(
+            2,
+            || async { transient_once(&calls) },
+            || async { Ok::<_, Error>(true) },
+        )
+        .await;
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(calls.load(Ordering::Relaxed), 2);
+
+        // The failed call built the block, so it is not built a second time
+        let calls = AtomicU64::new(0);
+        let result = retry_driver_call_while_head_unchanged(
+            2,
+            || async { transient_once(&calls) },
+            || async { Ok::<_, Error>(false) },
+        )
+        .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+
+        // Permanent errors are not retried
+        let calls = AtomicU64::new(0);
+        let result: Result<u64, Error> = retry_driver_call_while_head_unchanged(
+            2,
+            || async {
+                calls.fetch_add(1, Ordering::Relaxed);
+                Err(anyhow::anyhow!("invalid block"))
+            },
+            || async { Ok::<_, Error>(true) },
+        )
+        .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_anchor_timestamp_fetch_retries_transient_failure() {
+        use alloy::transports::TransportErrorKind;
+
+        let transient_failure = || Error::from(TransportErrorKind::backend_gone());
+
+        // A single transient failure is recovered within the retry budget
+        let calls = std::sync::atomic::AtomicU64::new(0);
+        let timestamp = retry_on_transient_error(2, Duration::from_millis(1), || async {
+            if calls.fetch_add(1, std::sync::atomic::Ordering::Relaxed) == 0 {
+                return Err(transient_failure());
+            }
+            Ok(1_700_000_000u64)
+        })
+        .await;
+        assert_eq!(timestamp.unwrap(), 1_700_000_000);
+        assert_eq!(calls.load(std::sync::atomic::Ordering::Relaxed), 2);
+
+        // The retry is bounded and delayed, the error is returned once the budget is exhausted
+        let calls = std::sync::atomic::AtomicU64::new(0);
+        let start = tokio::time::Instant::now();
+        let timestamp: Result<u64, Error> =
+            retry_on_transient_error(2, Duration::from_millis(200), || async {
+                calls.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                Err(transient_failure())
+            })
+            .await;
+        assert!(timestamp.is_err());
+        assert_eq!(calls.load(std::sync::atomic::Ordering::Relaxed), 3);
+        assert_eq!(start.elapsed(), Duration::from_millis(400));
+    }
+
+    #[tokio::test]
+    async fn test_next_proposal_id_from_builder() {
+        let result = resolve_next_proposal_id(
+            Some(7),
+            async { panic!("Geth must not be queried") },
+            async { panic!("inbox must not be queried") },
+        )
+        .await
+        .expect("builder source should resolve");
+        assert_eq!(result, (8, ProposalIdSource::Builder));
+    }
+
+    #[tokio::test]
+    async fn test_next_proposal_id_from_geth() {
+        let result = resolve_next_proposal_id(None, async { Ok(41) }, async {
+            panic!("inbox must not be queried")
+        })
+        .await
+        .expect("geth source should resolve");
+        assert_eq!(result, (42, ProposalIdSource::Geth));
+    }
+
+    #[tokio::test]
+    async fn test_next_proposal_id_from_inbox_fallback() {
+        let result = resolve_next_proposal_id(
+            None,
+            async { Err(anyhow::anyhow!("no Shasta blocks yet")) },
+            async { Ok(1) },
+        )
+        .await
+        .expect("inbox fallback should resolve");
+        assert_eq!(result, (1, ProposalIdSource::Inbox));
+
+        assert!(
+            resolve_next_proposal_id(
+                None,
+                async { Err(anyhow::anyhow!("geth unavailable")) },
+                async { Ok(5) },
+            )
+            .await
+            .is_err()
+        );
+    }
+
+    fn test_transaction(
+        sender: alloy::primitives::Address,
+        input: &[u8],
+    ) -> alloy::rpc::types::Transaction {
+        use alloy::consensus::{Signed, TxEnvelope, TxLegacy, transaction::Recovered};
+        use alloy::primitives::{B256, Bytes, Signature};
+
+        let tx = TxEnvelope::Legacy(Signed::new_unchecked(
+            TxLegacy {
+                input: Bytes::copy_from_slice(input),
+                ..Default::default()
+            },
+            Signature::test_signature(),
+            B256::ZERO,
+        ));
+        alloy::rpc::types::Transaction {
+            inner: Recovered::new_unchecked(tx, sender),
+            block_hash: None,
+            block_number: None,
+            transaction_index: None,
+            effective_gas_price: None,
+        }
+    }
+
+    #[test]
+    fn test_split_anchor_transaction_empty_block() {
+        let mut block = alloy::rpc::types::Block::default();
+        block.transactions = alloy::rpc::types::BlockTransactions::Full(vec![]);
+
+        let err = split_anchor_transaction(&block).expect_err("empty block must fail");
+        assert!(err.to_string().contains("is empty"));
+    }
+
+    #[test]
+    fn test_split_anchor_transaction_without_full_transactions() {
+        let mut block = alloy::rpc::types::Block::default();
+        block.transactions =
+            alloy::rpc::types::BlockTransactions::Hashes(vec![alloy::primitives::B256::ZERO]);
+
+        let err = split_anchor_transaction(&block).expect_err("hashes only block must fail");
+        assert!(err.to_string().contains("without full transactions"));
+    }
+
+    #[test]
+    fn test_split_anchor_transaction_only_anchor() {
+        let mut block = alloy::rpc::types::Block::default();
+        block.transactions = alloy::rpc::types::BlockTransactions::Full(vec![test_transaction(
+            GOLDEN_TOUCH_ADDRESS,
+            &Anchor::anchorV4Call::SELECTOR,
+        )]);
+
+        let (_, txs) = split_anchor_transaction(&block).expect("anchor only block must split");
+        assert!(txs.is_empty());
+    }
+
+    #[test]
+    fn test_split_anchor_transaction_rejects_non_anchor_first_transaction() {
+        let mut block = alloy::rpc::types::Block::default();
+        block.transactions = alloy::rpc::types::BlockTransactions::Full(vec![test_transaction(
+            alloy::primitives::Address::repeat_byte(1),
+            &Anchor::anchorV4Call::SELECTOR,
+        )]);
+        let err = split_anchor_transaction(&block).expect_err("wrong sender must fail");
+        assert!(err.to_string().contains("golden touch address"));
+
+        block.transactions = alloy::rpc::types::BlockTransactions::Full(vec![test_transaction(
+            GOLDEN_TOUCH_ADDRESS,
+            &[0xde, 0xad, 0xbe, 0xef],
+        )]);
+        let err = split_anchor_transaction(&block).expect_err("wrong selector must fail");
+        assert!(err.to_string().contains("does not call anchorV4"));
     }
 }
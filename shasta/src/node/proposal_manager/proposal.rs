@@ -1,3 +1,4 @@
+use crate::node::current_batch::BatchSummary;
 use crate::node::proposal_manager::l2_block_payload::L2BlockV2Payload;
 use alloy::primitives::{Address, B256};
 use common::shared::l2_block_v2::{L2BlockV2, L2BlockV2Draft};
@@ -27,6 +28,17 @@ pub struct Proposal {
 }
 
 impl Proposal {
+    pub fn summary(&self) -> BatchSummary {
+        BatchSummary {
+            proposal_id: self.id,
+            anchor_block_id: self.anchor_block_id,
+            block_count: self.l2_blocks.len(),
+            total_bytes: self.total_bytes,
+            has_forced_inclusion: self.has_forced_inclusion(),
+            pending_confirmation: self.pending_confirmation,
+        }
+    }
+
     pub fn compress(&mut self) {
         let start = Instant::now();
 
@@ -152,6 +164,25 @@ impl Proposal {
     pub fn has_forced_inclusion(&self) -> bool {
         self.num_forced_inclusion > 0
     }
+
+    /// Ratio of the estimated gas used to the gas limit summed over the proposal blocks.
+    /// Returns None when the blocks have no gas limit.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn gas_utilization(&self) -> Option<f64> {
+        let (gas_used, gas_limit) =
+            self.l2_blocks
+                .iter()
+                .fold((0u64, 0u64), |(gas_used, gas_limit), block| {
+                    (
+                        gas_used.saturating_add(block.prebuilt_tx_list.get_estimated_gas_used()),
+                        gas_limit.saturating_add(block.gas_limit_without_anchor),
+                    )
+                });
+        if gas_limit == 0 {
+            return None;
+        }
+        Some(gas_used as f64 / gas_limit as f64)
+    }
 }
 
 #[cfg(test)]
@@ -217,4 +248,22 @@ mod test {
         assert!(proposal.total_bytes == 316);
         assert!(buffer.len() > proposal.total_bytes as usize);
     }
+
+    #[test]
+    fn test_gas_utilization() {
+        let block = |estimated_gas_used| L2BlockV2 {
+            prebuilt_tx_list: PreBuiltTxList::empty_with_estimated_gas_used(estimated_gas_used),
+            timestamp_sec: 0,
+            coinbase: Address::ZERO,
+            anchor_block_number: 0,
+            gas_limit_without_anchor: 1_000_000,
+        };
+
+        let mut proposal = Proposal::default();
+        assert_eq!(proposal.gas_utilization(), None);
+
+        proposal.l2_blocks = vec![block(600_000), block(200_000), block(100_000), block(0)];
+        // 900_000 gas used out of 4 blocks x 1_000_000
+        assert_eq!(proposal.gas_utilization(), Some(0.225));
+    }
 }
@@ -1,7 +1,9 @@
-use std::{collections::VecDeque, sync::Arc};
+use std::{collections::VecDeque, sync::Arc, time::Duration};
 
+use super::batch_boundary::BatchBoundaryReason;
 use super::proposal::Proposals;
 use super::proposal_queue::ProposalQueue;
+use crate::node::current_batch::BatchSummary;
 use crate::node::proposal_manager::l2_block_payload::L2BlockV2Payload;
 use crate::{
     l1::execution_layer::ExecutionLayer, metrics::Metrics,
@@ -10,7 +12,7 @@ use crate::{
 use alloy::primitives::Address;
 use anyhow::Error;
 use common::{
-    batch_builder::BatchBuilderConfig,
+    batch_builder::{BatchBuilderConfig, MinTxsController},
     shared::l2_block_v2::{L2BlockV2, L2BlockV2Draft},
 };
 use common::{
@@ -18,7 +20,7 @@ use common::{
     shared::anchor_block_info::AnchorBlockInfo,
 };
 use taiko_bindings::anchor::ICheckpointStore::Checkpoint;
-use tracing::{debug, trace, warn};
+use tracing::{debug, info, trace, warn};
 
 pub struct ProposalBuilder {
     config: BatchBuilderConfig,
@@ -26,6 +28,8 @@ pub struct ProposalBuilder {
     current_proposal: Option<Proposal>,
     slot_clock: Arc<SlotClock>,
     metrics: Arc<Metrics>,
+    min_txs_controller: MinTxsController,
+    forced_inclusion_cap_reported: bool,
 }
 
 impl ProposalBuilder {
@@ -34,12 +38,18 @@ impl ProposalBuilder {
         slot_clock: Arc<SlotClock>,
         metrics: Arc<Metrics>,
     ) -> Self {
+        let min_txs_controller = MinTxsController::new(
+            config.preconf_min_txs,
+            config.preconf_min_txs_adaptive.clone(),
+        );
         Self {
             config,
             queue: ProposalQueue::new(),
             current_proposal: None,
             slot_clock,
             metrics,
+            min_txs_controller,
+            forced_inclusion_cap_reported: false,
         }
     }
 
@@ -48,39 +58,51 @@ impl ProposalBuilder {
     }
 
     pub fn can_consume_l2_block(&mut self, l2_draft_block: &L2BlockV2Draft) -> bool {
-        self.current_proposal.as_mut().is_some_and(|proposal| {
-            let new_block_count = match u16::try_from(proposal.l2_blocks.len() + 1) {
-                Ok(n) => n,
-                Err(_) => return false,
-            };
+        self.current_proposal.is_some() && self.batch_boundary_reason(l2_draft_block).is_none()
+    }
 
-            let mut new_total_bytes =
+    /// Returns the limit that prevents the current proposal from consuming the given block,
+    /// or None when the block fits or there is no current proposal.
+    pub fn batch_boundary_reason(
+        &mut self,
+        l2_draft_block: &L2BlockV2Draft,
+    ) -> Option<BatchBoundaryReason> {
+        let proposal = self.current_proposal.as_mut()?;
+        let Ok(new_block_count) = u16::try_from(proposal.l2_blocks.len() + 1) else {
+            return Some(BatchBoundaryReason::BlocksLimit);
+        };
+
+        let mut new_total_bytes =
+            proposal.total_bytes + l2_draft_block.prebuilt_tx_list.get_bytes_length();
+
+        if !self.config.is_within_bytes_limit(new_total_bytes) {
+            // first compression, compressing the proposal without the new L2 block
+            proposal.compress();
+            new_total_bytes =
                 proposal.total_bytes + l2_draft_block.prebuilt_tx_list.get_bytes_length();
-
             if !self.config.is_within_bytes_limit(new_total_bytes) {
-                // first compression, compressing the proposal without the new L2 block
-                proposal.compress();
-                new_total_bytes =
-                    proposal.total_bytes + l2_draft_block.prebuilt_tx_list.get_bytes_length();
-                if !self.config.is_within_bytes_limit(new_total_bytes) {
-                    // second compression, compressing the proposal with the new L2 block
-                    // we can tolerate the processing overhead as it's a very rare case
-                    let start = std::time::Instant::now();
-                    let mut proposal_clone = proposal.clone();
-                    proposal_clone.add_l2_draft_block(l2_draft_block.clone());
-                    proposal_clone.compress();
-                    new_total_bytes = proposal_clone.total_bytes;
-                    debug!(
-                        "can_consume_l2_block: Second compression took {} ms, new total bytes: {}",
-                        start.elapsed().as_millis(),
-                        new_total_bytes
-                    );
-                }
+                // second compression, compressing the proposal with the new L2 block
+                // we can tolerate the processing overhead as it's a very rare case
+                let start = std::time::Instant::now();
+                let mut proposal_clone = proposal.clone();
+                proposal_clone.add_l2_draft_block(l2_draft_block.clone());
+                proposal_clone.compress();
+                new_total_bytes = proposal_clone.total_bytes;
+                debug!(
+                    "can_consume_l2_block: Second compression took {} ms, new total bytes: {}",
+                    start.elapsed().as_millis(),
+                    new_total_bytes
+                );
             }
+        }
 
-            self.config.is_within_bytes_limit(new_total_bytes)
-                && self.config.is_within_block_limit(new_block_count)
-        })
+        if !self.config.is_within_bytes_limit(new_total_bytes) {
+            return Some(BatchBoundaryReason::BytesLimit);
+        }
+        if !self.config.is_within_block_limit(new_block_count) {
+            return Some(BatchBoundaryReason::BlocksLimit);
+        }
+        None
     }
 
     /// Returns true if the current proposal exists, has no common block and
@@ -91,9 +113,37 @@ impl ProposalBuilder {
         })
     }
 
+    /// Returns true if the current proposal has no common block yet, but can't accept
+    /// more forced inclusion blocks because of the configured maximum.
+    pub fn is_forced_inclusion_cap_reached(&self) -> bool {
+        self.current_proposal.as_ref().is_some_and(|p| {
+            p.l2_blocks.is_empty() && p.num_forced_inclusion >= self.config.max_forced_inclusions
+        })
+    }
+
+    /// Returns true if the forced inclusion cap is reached and not yet reported for the
+    /// current proposal.
+    pub fn should_report_forced_inclusion_cap(&self) -> bool {
+        !self.forced_inclusion_cap_reported && self.is_forced_inclusion_cap_reached()
+    }
+
+    pub fn mark_forced_inclusion_cap_reported(&mut self) {
+        self.forced_inclusion_cap_reported = true;
+    }
+
     pub fn create_new_proposal(&mut self, id: u64, anchor_block: AnchorBlockInfo, timestamp: u64) {
         self.finalize_current_proposal();
 
+        if self
+            .config
+            .end_fork_switch_grace_if_over(Duration::from_secs(timestamp))
+        {
+            info!(
+                "Fork switch grace period is over, using fork batch limits: max blocks per batch {}, max anchor height offset {}",
+                self.config.max_blocks_per_batch, self.config.max_anchor_height_offset
+            );
+        }
+
         self.current_proposal = Some(Proposal {
             id,
             l2_blocks: vec![],
@@ -107,6 +157,7 @@ impl ProposalBuilder {
             created_at_sec: timestamp,
             pending_confirmation: false,
         });
+        self.forced_inclusion_cap_reported = false;
     }
 
     pub fn add_l2_draft_block(
@@ -196,7 +247,7 @@ impl ProposalBuilder {
         // We have a new proposal when proposal ID differs
         // Otherwise we continue with the current proposal
         if !self.is_same_proposal_id(proposal_id) {
-            self.finalize_current_proposal();
+            self.close_current_proposal(BatchBoundaryReason::Recovery);
             debug!(
                 "Creating new proposal during recovery: proposal_id {}, anchor_block_id {} coinbase {}",
                 proposal_id,
@@ -216,6 +267,7 @@ impl ProposalBuilder {
                 created_at_sec: l2_block_timestamp_sec,
                 pending_confirmation: false,
             });
+            self.forced_inclusion_cap_reported = false;
         }
 
         if is_forced_inclusion {
@@ -309,17 +361,7 @@ impl ProposalBuilder {
         submit_only_full_proposals: bool,
         l2_slot_timestamp: u64,
     ) -> Result<(), Error> {
-        if let Some(current_proposal) = self.current_proposal.as_ref() {
-            let block_count = u16::try_from(current_proposal.l2_blocks.len()).unwrap_or(0);
-            let is_full = !self.config.is_within_block_limit(block_count + 1);
-            let is_expired = !self
-                .config
-                .is_within_time_limit(current_proposal.created_at_sec, l2_slot_timestamp);
-
-            if !submit_only_full_proposals || is_full || is_expired {
-                self.finalize_current_proposal();
-            }
-        }
+        self.finalize_current_proposal_if_ready(submit_only_full_proposals, l2_slot_timestamp);
 
         let proposals_number = self.queue.len();
         if let Some(proposal) = self.queue.front_mut() {
@@ -355,6 +397,55 @@ impl ProposalBuilder {
         Ok(())
     }
 
+    /// Moves the current proposal to the submission queue when it is ready to be sent to L1.
+    fn finalize_current_proposal_if_ready(
+        &mut self,
+        submit_only_full_proposals: bool,
+        l2_slot_timestamp: u64,
+    ) {
+        if let Some(reason) =
+            self.submission_boundary_reason(submit_only_full_proposals, l2_slot_timestamp)
+        {
+            self.close_current_proposal(reason);
+        }
+    }
+
+    /// Returns why the current proposal is ready to be submitted, or None when it is kept open.
+    fn submission_boundary_reason(
+        &self,
+        submit_only_full_proposals: bool,
+        l2_slot_timestamp: u64,
+    ) -> Option<BatchBoundaryReason> {
+        let current_proposal = self.current_proposal.as_ref()?;
+
+        let block_count = u16::try_from(current_proposal.l2_blocks.len()).unwrap_or(0);
+        if !self.config.is_within_block_limit(block_count + 1) {
+            return Some(BatchBoundaryReason::BlocksLimit);
+        }
+        if !self
+            .config
+            .is_within_time_limit(current_proposal.created_at_sec, l2_slot_timestamp)
+        {
+            return Some(BatchBoundaryReason::ProposalTime);
+        }
+        if !submit_only_full_proposals {
+            return Some(BatchBoundaryReason::Submission);
+        }
+        if self
+            .config
+            .is_older_than_max_batch_age(current_proposal.created_at_sec, l2_slot_timestamp)
+        {
+            info!(
+                "Proposal {} reached max batch age with {} blocks, submitting it",
+                current_proposal.id,
+                current_proposal.l2_blocks.len()
+            );
+            self.metrics.inc_batch_age_forced_submissions();
+            return Some(BatchBoundaryReason::BatchAge);
+        }
+        None
+    }
+
     // TODO do we have that check in SC?
     pub fn is_time_shift_between_blocks_expiring(&self, current_l2_slot_timestamp: u64) -> bool {
         if let Some(current_proposal) = self.current_proposal.as_ref()
@@ -395,6 +486,7 @@ impl ProposalBuilder {
             current_proposal: None,
             slot_clock: self.slot_clock.clone(),
             metrics: self.metrics.clone(),
+            min_txs_controller: self.min_txs_controller.clone(),
         }
     }
 
@@ -425,7 +517,7 @@ impl ProposalBuilder {
 
     pub fn try_finalize_current_proposal(&mut self) -> Result<(), Error> {
         // TODO handle forced inclusion
-        self.finalize_current_proposal();
+        self.close_current_proposal(BatchBoundaryReason::Flush);
         Ok(())
     }
 
@@ -443,14 +535,38 @@ impl ProposalBuilder {
         self.current_proposal = None;
     }
 
+    /// Finalizes the current proposal and records why it was closed.
+    pub fn close_current_proposal(&mut self, reason: BatchBoundaryReason) {
+        if let Some(proposal) = self.current_proposal.as_ref()
+            && !proposal.l2_blocks.is_empty()
+        {
+            debug!("Closing proposal {}: {}", proposal.id, reason.as_str());
+            self.metrics.inc_batch_boundary(reason.as_str());
+        }
+        self.finalize_current_proposal();
+    }
+
     pub fn finalize_current_proposal(&mut self) {
         if let Some(proposal) = self.current_proposal.take()
             && !proposal.l2_blocks.is_empty()
         {
+            if let Some(gas_utilization) = proposal.gas_utilization() {
+                self.metrics.set_batch_gas_utilization(gas_utilization);
+            }
             self.queue.push(proposal);
         }
     }
 
+    /// Feeds the number of pending transactions into the `preconf_min_txs` controller.
+    pub fn observe_pending_txs(&mut self, pending_tx_list: &Option<PreBuiltTxList>) {
+        let number_of_pending_txs = pending_tx_list
+            .as_ref()
+            .map(|tx_list| tx_list.get_tx_list().len())
+            .unwrap_or(0) as u64;
+        self.min_txs_controller
+            .observe_mempool_depth(number_of_pending_txs);
+    }
+
     pub fn should_new_block_be_created(
         &self,
         pending_tx_list: &Option<PreBuiltTxList>,
@@ -466,7 +582,7 @@ impl ProposalBuilder {
             return true;
         }
 
-        if number_of_pending_txs >= self.config.preconf_min_txs {
+        if number_of_pending_txs >= self.min_txs_controller.min_txs() {
             return true;
         }
 
@@ -476,7 +592,7 @@ impl ProposalBuilder {
             let number_of_l2_slots =
                 (current_l2_slot_timestamp.saturating_sub(last_block.timestamp_sec)) * 1000
                     / self.slot_clock.get_preconf_heartbeat_ms();
-            return number_of_l2_slots > self.config.preconf_max_skipped_l2_slots;
+            return number_of_l2_slots > self.config.max_skipped_l2_slots(number_of_pending_txs);
         }
 
         true
@@ -487,12 +603,32 @@ impl ProposalBuilder {
             .as_ref()
             .is_some_and(|p| p.has_forced_inclusion())
     }
+
+    /// Summaries of the queued proposals followed by the one being built.
+    pub fn batch_summaries(&self) -> Vec<BatchSummary> {
+        self.queue
+            .iter()
+            .chain(self.current_proposal.as_ref())
+            .map(Proposal::summary)
+            .collect()
+    }
+
+    /// Forced inclusions consumed by proposals that were not sent to L1 yet.
+    /// These are dropped together with the proposals when the builder is reset.
+    pub fn num_unsent_forced_inclusions(&self) -> u64 {
+        self.queue.num_unsent_forced_inclusions()
+            + self
+                .current_proposal
+                .as_ref()
+                .map_or(0, |p| u64::from(p.num_forced_inclusion))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use alloy::primitives::{B256, Uint};
+    use common::batch_builder::{ForkBatchLimits, ForkSwitchGrace};
     use common::l1::slot_clock::SlotClock;
     use common::metrics::Metrics;
     use rand::RngExt;
@@ -568,9 +704,13 @@ mod tests {
             max_anchor_height_offset: 64,
             default_coinbase: COINBASE,
             preconf_min_txs: 3,
+            preconf_min_txs_adaptive: None,
             preconf_max_skipped_l2_slots: 5,
+            force_empty_block_after_skipped_l2_slots: None,
             proposal_max_time_sec: 120,
             max_forced_inclusions: 10,
+            max_batch_age_sec: None,
+            fork_switch_grace: None,
         }
     }
 
@@ -593,9 +733,13 @@ mod tests {
             max_anchor_height_offset: 64,
             default_coinbase: COINBASE,
             preconf_min_txs: 3,
+            preconf_min_txs_adaptive: None,
             preconf_max_skipped_l2_slots: 5,
+            force_empty_block_after_skipped_l2_slots: None,
             proposal_max_time_sec: 120,
             max_forced_inclusions: 10,
+            max_batch_age_sec: None,
+            fork_switch_grace: None,
         }
     }
 
@@ -728,6 +872,72 @@ mod tests {
         assert!(builder.has_current_forced_inclusion());
     }
 
+    #[test]
+    fn test_forced_inclusion_cap() {
+        let mut config = make_config();
+        config.max_forced_inclusions = 2;
+        let mut builder = make_builder_with_config(config);
+        create_proposal(&mut builder, 1, 100, 1000);
+
+        for timestamp in [1001, 1002] {
+            assert!(builder.can_add_forced_inclusion());
+            builder
+                .add_fi_block(make_draft_block(timestamp, 50), make_checkpoint())
+                .expect("should add FI block");
+        }
+
+        assert!(!builder.can_add_forced_inclusion());
+        assert!(builder.is_forced_inclusion_cap_reached());
+    }
+
+    #[test]
+    fn test_forced_inclusion_cap_reported_once_per_proposal() {
+        let mut config = make_config();
+        config.max_forced_inclusions = 1;
+        let mut builder = make_builder_with_config(config);
+        create_proposal(&mut builder, 1, 100, 1000);
+        builder
+            .add_fi_block(make_draft_block(1001, 50), make_checkpoint())
+            .expect("should add FI block");
+
+        assert!(builder.should_report_forced_inclusion_cap());
+        builder.mark_forced_inclusion_cap_reported();
+        // Later slots of the same proposal don't report it again
+        assert!(builder.is_forced_inclusion_cap_reached());
+        assert!(!builder.should_report_forced_inclusion_cap());
+
+        create_proposal(&mut builder, 2, 100, 1002);
+        assert!(!builder.should_report_forced_inclusion_cap());
+        builder
+            .add_fi_block(make_draft_block(1003, 50), make_checkpoint())
+            .expect("should add FI block");
+        assert!(builder.should_report_forced_inclusion_cap());
+    }
+
+    #[test]
+    fn test_num_unsent_forced_inclusions() {
+        let mut builder = make_builder();
+        create_proposal(&mut builder, 1, 100, 1000);
+        builder
+            .add_fi_block(make_draft_block(1001, 50), make_checkpoint())
+            .expect("should add FI block");
+        let _ = builder.add_l2_draft_block(make_draft_block(1002, 100));
+        builder.finalize_current_proposal();
+        create_proposal(&mut builder, 2, 100, 1003);
+        builder
+            .add_fi_block(make_draft_block(1004, 50), make_checkpoint())
+            .expect("should add FI block");
+        assert_eq!(builder.num_unsent_forced_inclusions(), 2);
+
+        // The first proposal was dispatched to L1
+        builder
+            .queue
+            .front_mut()
+            .expect("queued proposal")
+            .pending_confirmation = true;
+        assert_eq!(builder.num_unsent_forced_inclusions(), 1);
+    }
+
     #[test]
     fn test_add_fi_block_without_proposal_errors() {
         let mut builder = make_builder();
@@ -770,6 +980,33 @@ mod tests {
         assert!(!builder.can_consume_l2_block(&make_draft_block(1003, 100)));
     }
 
+    #[test]
+    fn test_fork_limits_apply_from_the_first_proposal_after_the_grace_period() {
+        let mut config = make_config();
+        config.max_blocks_per_batch = 2;
+        config.fork_switch_grace = Some(ForkSwitchGrace {
+            grace_period_end: Duration::from_secs(1010),
+            fork_limits: ForkBatchLimits {
+                max_blocks_per_batch: 10,
+                max_anchor_height_offset: 64,
+            },
+        });
+        let mut builder = make_builder_with_config(config);
+
+        // The grace period ends while the proposal is built, its limits stay conservative
+        create_proposal(&mut builder, 1, 100, 1000);
+        let _ = builder.add_l2_draft_block(make_draft_block(1009, 100));
+        let _ = builder.add_l2_draft_block(make_draft_block(1010, 100));
+        assert!(!builder.can_consume_l2_block(&make_draft_block(1011, 100)));
+
+        create_proposal(&mut builder, 2, 100, 1012);
+        assert_eq!(builder.get_config().max_blocks_per_batch, 10);
+        assert!(builder.get_config().fork_switch_grace.is_none());
+        let _ = builder.add_l2_draft_block(make_draft_block(1012, 100));
+        let _ = builder.add_l2_draft_block(make_draft_block(1013, 100));
+        assert!(builder.can_consume_l2_block(&make_draft_block(1014, 100)));
+    }
+
     #[test]
     fn test_can_consume_l2_block_no_proposal() {
         let mut builder = make_builder();
@@ -855,12 +1092,95 @@ mod tests {
         assert!(builder.should_new_block_be_created(&None, 1018, false));
     }
 
+    #[test]
+    fn test_force_empty_block_after_skipped_slots_with_empty_mempool() {
+        let mut config = make_config();
+        config.force_empty_block_after_skipped_l2_slots = Some(2);
+        let mut builder = make_builder_with_config(config);
+        create_proposal(&mut builder, 1, 100, 1000);
+        let _ = builder.add_l2_draft_block(make_draft_block(1000, 100));
+
+        // Empty mempool, a block is forced once more than 2 slots are skipped
+        for (timestamp, expected) in [(1003, false), (1006, false), (1009, true), (1012, true)] {
+            assert_eq!(
+                builder.should_new_block_be_created(&None, timestamp, false),
+                expected,
+                "timestamp {timestamp}"
+            );
+        }
+
+        // Pending transactions below the minimum keep using preconf_max_skipped_l2_slots
+        let one_tx = Some(PreBuiltTxList::empty_with_tx_list(vec![make_tx()]));
+        assert!(!builder.should_new_block_be_created(&one_tx, 1009, false));
+        assert!(builder.should_new_block_be_created(&one_tx, 1018, false));
+    }
+
     #[test]
     fn test_should_new_block_be_created_no_proposal_no_txs() {
         let builder = make_builder();
         assert!(builder.should_new_block_be_created(&None, 1000, false));
     }
 
+    #[test]
+    fn test_should_new_block_be_created_adaptive_min_txs() {
+        let mut config = make_config();
+        config.preconf_min_txs_adaptive = Some(common::batch_builder::AdaptiveMinTxsConfig {
+            lower_min_txs: 1,
+            upper_min_txs: 5,
+            busy_mempool_depth: 4,
+            window: 3,
+        });
+        let mut builder = make_builder_with_config(config);
+        create_proposal(&mut builder, 1, 100, 1000);
+        let _ = builder.add_l2_draft_block(make_draft_block(1000, 100));
+
+        let two_txs = Some(PreBuiltTxList::empty_with_tx_list(vec![
+            make_tx(),
+            make_tx(),
+        ]));
+        let busy = Some(PreBuiltTxList::empty_with_tx_list(vec![make_tx(); 4]));
+
+        // Idle mempool raises the threshold above two transactions
+        for _ in 0..3 {
+            builder.observe_pending_txs(&None);
+        }
+        assert!(!builder.should_new_block_be_created(&two_txs, 1001, false));
+
+        // Busy mempool lowers the threshold so two transactions are enough
+        for _ in 0..3 {
+            builder.observe_pending_txs(&busy);
+        }
+        assert!(builder.should_new_block_be_created(&two_txs, 1001, false));
+    }
+
+    // --- Submission decision ---
+
+    #[test]
+    fn test_partial_proposal_kept_until_max_batch_age() {
+        let mut config = make_config();
+        config.max_batch_age_sec = Some(30);
+        let mut builder = make_builder_with_config(config);
+        create_proposal(&mut builder, 1, 100, 1000);
+        let _ = builder.add_l2_draft_block(make_draft_block(1001, 100));
+
+        builder.finalize_current_proposal_if_ready(true, 1029);
+        assert_eq!(builder.get_number_of_proposals_ready_to_send(), 0);
+
+        builder.finalize_current_proposal_if_ready(true, 1030);
+        assert_eq!(builder.get_current_proposal_id(), None);
+        assert_eq!(builder.get_number_of_proposals_ready_to_send(), 1);
+    }
+
+    #[test]
+    fn test_partial_proposal_kept_without_max_batch_age() {
+        let mut builder = make_builder();
+        create_proposal(&mut builder, 1, 100, 1000);
+        let _ = builder.add_l2_draft_block(make_draft_block(1001, 100));
+
+        builder.finalize_current_proposal_if_ready(true, 1100);
+        assert_eq!(builder.get_number_of_proposals_ready_to_send(), 0);
+    }
+
     // --- Time shift ---
 
     #[test]
@@ -1278,4 +1598,159 @@ mod tests {
 
         assert!(builder.has_current_forced_inclusion());
     }
+
+    // --- Batch boundary reasons ---
+
+    fn is_boundary_recorded(builder: &ProposalBuilder, reason: BatchBoundaryReason) -> bool {
+        builder.metrics.gather().contains(&format!(
+            "catalyst_batch_boundaries_total{{reason=\"{}\"}} 1",
+            reason.as_str()
+        ))
+    }
+
+    #[test]
+    fn test_batch_boundary_bytes_limit() {
+        let mut config = make_config();
+        config.max_bytes_size_of_batch = 1;
+        let mut builder = make_builder_with_config(config);
+        create_proposal(&mut builder, 1, 100, 1000);
+        let _ = builder.add_l2_draft_block(make_draft_block(1001, 50));
+
+        let reason = builder.batch_boundary_reason(&make_draft_block(1002, 50));
+        assert_eq!(reason, Some(BatchBoundaryReason::BytesLimit));
+        builder.close_current_proposal(BatchBoundaryReason::BytesLimit);
+        assert!(is_boundary_recorded(
+            &builder,
+            BatchBoundaryReason::BytesLimit
+        ));
+    }
+
+    #[test]
+    fn test_batch_boundary_blocks_limit() {
+        let mut config = make_config();
+        config.max_blocks_per_batch = 2;
+        let mut builder = make_builder_with_config(config);
+        create_proposal(&mut builder, 1, 100, 1000);
+        let _ = builder.add_l2_draft_block(make_draft_block(1001, 100));
+        assert_eq!(
+            builder.batch_boundary_reason(&make_draft_block(1002, 100)),
+            None
+        );
+        let _ = builder.add_l2_draft_block(make_draft_block(1002, 100));
+
+        assert_eq!(
+            builder.batch_boundary_reason(&make_draft_block(1003, 100)),
+            Some(BatchBoundaryReason::BlocksLimit)
+        );
+        builder.finalize_current_proposal_if_ready(true, 1003);
+        assert!(is_boundary_recorded(
+            &builder,
+            BatchBoundaryReason::BlocksLimit
+        ));
+    }
+
+    #[test]
+    fn test_batch_boundary_proposal_time() {
+        let mut builder = make_builder();
+        create_proposal(&mut builder, 1, 100, 1000);
+        let _ = builder.add_l2_draft_block(make_draft_block(1001, 100));
+
+        assert_eq!(builder.submission_boundary_reason(true, 1120), None);
+        assert_eq!(
+            builder.submission_boundary_reason(true, 1121),
+            Some(BatchBoundaryReason::ProposalTime)
+        );
+        builder.finalize_current_proposal_if_ready(true, 1121);
+        assert!(is_boundary_recorded(
+            &builder,
+            BatchBoundaryReason::ProposalTime
+        ));
+    }
+
+    #[test]
+    fn test_batch_boundary_batch_age() {
+        let mut config = make_config();
+        config.max_batch_age_sec = Some(30);
+        let mut builder = make_builder_with_config(config);
+        create_proposal(&mut builder, 1, 100, 1000);
+        let _ = builder.add_l2_draft_block(make_draft_block(1001, 100));
+
+        builder.finalize_current_proposal_if_ready(true, 1030);
+        assert!(is_boundary_recorded(
+            &builder,
+            BatchBoundaryReason::BatchAge
+        ));
+    }
+
+    #[test]
+    fn test_batch_boundary_submission() {
+        let mut builder = make_builder();
+        create_proposal(&mut builder, 1, 100, 1000);
+        let _ = builder.add_l2_draft_block(make_draft_block(1001, 100));
+
+        builder.finalize_current_proposal_if_ready(false, 1001);
+        assert!(is_boundary_recorded(
+            &builder,
+            BatchBoundaryReason::Submission
+        ));
+    }
+
+    #[test]
+    fn test_batch_boundary_anchor_offset() {
+        let mut builder = make_builder();
+        create_proposal(&mut builder, 1, 100, 1000);
+        let _ = builder.add_l2_draft_block(make_draft_block(1001, 100));
+
+        builder.close_current_proposal(BatchBoundaryReason::AnchorOffset);
+        assert!(is_boundary_recorded(
+            &builder,
+            BatchBoundaryReason::AnchorOffset
+        ));
+        assert_eq!(builder.get_number_of_proposals_ready_to_send(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_batch_boundary_recovery() {
+        let mut builder = make_builder();
+        for proposal_id in [1, 2] {
+            builder
+                .recover_from(
+                    proposal_id,
+                    make_anchor(100, 1000),
+                    COINBASE,
+                    vec![],
+                    1000 + proposal_id,
+                    1_000_000,
+                    false,
+                )
+                .await
+                .expect("recovery should succeed");
+        }
+
+        assert!(is_boundary_recorded(
+            &builder,
+            BatchBoundaryReason::Recovery
+        ));
+    }
+
+    #[test]
+    fn test_batch_boundary_flush() {
+        let mut builder = make_builder();
+        create_proposal(&mut builder, 1, 100, 1000);
+        let _ = builder.add_l2_draft_block(make_draft_block(1001, 100));
+
+        builder
+            .try_finalize_current_proposal()
+            .expect("finalize should succeed");
+        assert!(is_boundary_recorded(&builder, BatchBoundaryReason::Flush));
+    }
+
+    #[test]
+    fn test_empty_proposal_has_no_batch_boundary() {
+        let mut builder = make_builder();
+        create_proposal(&mut builder, 1, 100, 1000);
+
+        builder.close_current_proposal(BatchBoundaryReason::Flush);
+        assert!(!is_boundary_recorded(&builder, BatchBoundaryReason::Flush));
+    }
 }
@@ -55,6 +55,19 @@ impl ProposalQueue {
         self.proposals = proposals;
     }
 
+    /// Forced inclusions in proposals not yet dispatched to the transaction monitor.
+    pub fn num_unsent_forced_inclusions(&self) -> u64 {
+        self.proposals
+            .iter()
+            .filter(|p| !p.pending_confirmation)
+            .map(|p| u64::from(p.num_forced_inclusion))
+            .sum()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Proposal> {
+        self.proposals.iter()
+    }
+
     pub fn front_mut(&mut self) -> Option<&mut Proposal> {
         self.proposals.front_mut()
     }
@@ -0,0 +1,363 @@
+use super::split_anchor_transaction;
+use crate::shared::l2_tx_lists::PreBuiltTxList;
+use alloy::rpc::types::Block;
+use anyhow::Error;
+use common::l2::taiko_driver::models::BuildPreconfBlockResponse;
+use std::borrow::Cow;
+use tracing::{debug, error};
+
+/// L2 operations the reanchor loop relies on.
+pub(super) trait ReanchorL2 {
+    /// Fetches the canonical block at the given height with its full transactions.
+    async fn get_block_with_txs(&self, number: u64) -> Result<Block, Error>;
+
+    /// Builds the next L2 block from the given transactions, `processed_blocks` being the number
+    /// of blocks already built by this reanchor.
+    async fn reanchor_block(
+        &mut self,
+        pending_tx_list: PreBuiltTxList,
+        processed_blocks: u64,
+        allow_forced_inclusion: bool,
+    ) -> Result<BuildPreconfBlockResponse, Error>;
+}
+
+/// Rebuilds the transactions of `blocks` on top of the new parent, skipping forced inclusions
+/// and empty blocks. Returns the number of blocks built.
+pub(super) async fn reanchor_blocks<L: ReanchorL2>(
+    l2: &mut L,
+    blocks: &[Block],
+    forced_inclusion_flags: &[bool],
+    max_blocks_to_reanchor: u64,
+) -> Result<u64, Error> {
+    let bodies =
+        fetch_block_bodies(l2, blocks, forced_inclusion_flags, max_blocks_to_reanchor).await?;
+
+    let mut current_block_pos = 0;
+    let mut processed_blocks = 0;
+    let mut is_common_block_processed = false;
+
+    while current_block_pos < blocks.len() && processed_blocks < max_blocks_to_reanchor {
+        debug!(
+            "Reanchoring block position {}/{}, processed: {}/{}",
+            current_block_pos,
+            blocks.len(),
+            processed_blocks,
+            max_blocks_to_reanchor
+        );
+
+        if forced_inclusion_flags[current_block_pos] {
+            debug!(
+                "Skipping forced inclusion block {}",
+                blocks[current_block_pos].header.number,
+            );
+            current_block_pos += 1;
+            continue;
+        }
+
+        let Some(block) = bodies[current_block_pos].as_deref() else {
+            debug!(
+                "Skipping empty block {}",
+                blocks[current_block_pos].header.number
+            );
+            current_block_pos += 1;
+            continue;
+        };
+        let (_, txs) = split_anchor_transaction(block)?;
+
+        // Skip empty blocks, except the first one
+        if txs.is_empty() && is_common_block_processed {
+            debug!("Skipping empty block {}", block.header.number);
+            current_block_pos += 1;
+            continue;
+        }
+
+        debug!(
+            "Reanchoring block {} with {} txs",
+            block.header.number,
+            txs.len()
+        );
+        let pending_tx_list = PreBuiltTxList::new(txs.to_vec());
+
+        let is_last_reanchored_block =
+            current_block_pos + 1 == blocks.len() || processed_blocks + 1 == max_blocks_to_reanchor;
+        let allow_forced_inclusion = !is_last_reanchored_block;
+
+        match l2
+            .reanchor_block(pending_tx_list, processed_blocks, allow_forced_inclusion)
+            .await
+        {
+            Ok(preconfed_block) => {
+                debug!(
+                    "Reanchored block {} hash {}, is_forced_inclusion: {}",
+                    preconfed_block.number,
+                    preconfed_block.hash,
+                    preconfed_block.is_forced_inclusion,
+                );
+                processed_blocks += 1;
+                if !preconfed_block.is_forced_inclusion {
+                    is_common_block_processed = true;
+                    current_block_pos += 1;
+                }
+            }
+            Err(err) => {
+                error!("Failed to reanchor block {}: {}", block.header.number, err);
+                return Err(anyhow::anyhow!(
+                    "Failed to reanchor block {}: {}",
+                    block.header.number,
+                    err
+                ));
+            }
+        }
+    }
+    Ok(processed_blocks)
+}
+
+/// Fetches the full transactions of the blocks fetched with hashes only. The bodies are fetched
+/// before the first block is rebuilt, since from then on the heights point to the new blocks.
+/// Forced inclusions, empty blocks after the first reanchored one and blocks past
+/// `max_blocks_to_reanchor` are skipped without a fetch.
+async fn fetch_block_bodies<'a, L: ReanchorL2>(
+    l2: &L,
+    blocks: &'a [Block],
+    forced_inclusion_flags: &[bool],
+    max_blocks_to_reanchor: u64,
+) -> Result<Vec<Option<Cow<'a, Block>>>, Error> {
+    let first_reanchored_pos = forced_inclusion_flags.iter().position(|is_fi| !is_fi);
+
+    let mut bodies = Vec::with_capacity(blocks.len());
+    let mut blocks_to_reanchor = 0;
+    for (pos, block) in blocks.iter().enumerate() {
+        if blocks_to_reanchor >= max_blocks_to_reanchor
+            || forced_inclusion_flags[pos]
+            || (Some(pos) != first_reanchored_pos && is_anchor_only_block(block))
+        {
+            bodies.push(None);
+            continue;
+        }
+        blocks_to_reanchor += 1;
+        if block.transactions.as_hashes().is_none() {
+            bodies.push(Some(Cow::Borrowed(block)));
+            continue;
+        }
+
+        let full_block = l2.get_block_with_txs(block.header.number).await?;
+        if full_block.header.hash != block.header.hash {
+            return Err(anyhow::anyhow!(
+                "L2 block {} changed before reanchoring, expected hash {}, got {}",
+                block.header.number,
+                block.header.hash,
+                full_block.header.hash
+            ));
+        }
+        bodies.push(Some(Cow::Owned(full_block)));
+    }
+    Ok(bodies)
+}
+
+/// Returns true for a block that only contains the anchor transaction, whether it was fetched
+/// with full transactions or hashes only.
+fn is_anchor_only_block(block: &Block) -> bool {
+    block.transactions.len() == 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::{
+        consensus::{Signed, Transaction as _, TxEnvelope, TxLegacy, transaction::Recovered},
+        primitives::{Address, B256, Bytes, Signature},
+        rpc::types::{BlockTransactions, Transaction},
+        sol_types::SolCall,
+    };
+    use common::crypto::GOLDEN_TOUCH_ADDRESS;
+    use std::{cell::Cell, collections::BTreeMap};
+    use taiko_bindings::anchor::Anchor;
+
+    fn transaction(nonce: u64) -> Transaction {
+        transaction_from(nonce, Address::ZERO, Bytes::new())
+    }
+
+    fn anchor_transaction() -> Transaction {
+        transaction_from(
+            0,
+            GOLDEN_TOUCH_ADDRESS,
+            Bytes::copy_from_slice(&Anchor::anchorV4Call::SELECTOR),
+        )
+    }
+
+    fn transaction_from(nonce: u64, sender: Address, input: Bytes) -> Transaction {
+        let tx = TxEnvelope::Legacy(Signed::new_unchecked(
+            TxLegacy {
+                nonce,
+                input,
+                ..Default::default()
+            },
+            Signature::test_signature(),
+            B256::with_last_byte(u8::try_from(nonce).expect("small nonce")),
+        ));
+        Transaction {
+            inner: Recovered::new_unchecked(tx, sender),
+            block_hash: None,
+            block_number: None,
+            transaction_index: None,
+            effective_gas_price: None,
+        }
+    }
+
+    /// Block with an anchor transaction followed by transactions with the given nonces.
+    fn block(number: u64, hash: u8, nonces: &[u64]) -> Block {
+        let mut block = Block::default();
+        block.header.inner.number = number;
+        block.header.hash = B256::repeat_byte(hash);
+        block.transactions = BlockTransactions::Full(
+            std::iter::once(anchor_transaction())
+                .chain(nonces.iter().map(|&nonce| transaction(nonce)))
+                .collect(),
+        );
+        block
+    }
+
+    fn without_bodies(block: &Block) -> Block {
+        let mut block = block.clone();
+        block.transactions = BlockTransactions::Hashes(block.transactions.hashes().collect());
+        block
+    }
+
+    /// L2 where reanchoring replaces the blocks from the parent on, and the first built block
+    /// is a forced inclusion.
+    struct FakeL2 {
+        chain: BTreeMap<u64, Block>,
+        head: u64,
+        built: Vec<Vec<u64>>,
+        fetched: Cell<usize>,
+    }
+
+    impl ReanchorL2 for FakeL2 {
+        async fn get_block_with_txs(&self, number: u64) -> Result<Block, Error> {
+            self.fetched.set(self.fetched.get() + 1);
+            self.chain
+                .get(&number)
+                .cloned()
+                .ok_or_else(|| anyhow::anyhow!("no block {number}"))
+        }
+
+        async fn reanchor_block(
+            &mut self,
+            pending_tx_list: PreBuiltTxList,
+            _processed_blocks: u64,
+            allow_forced_inclusion: bool,
+        ) -> Result<BuildPreconfBlockResponse, Error> {
+            let is_forced_inclusion = allow_forced_inclusion && self.built.is_empty();
+            let nonces: Vec<u64> = if is_forced_inclusion {
+                vec![]
+            } else {
+                pending_tx_list
+                    .get_tx_list()
+                    .iter()
+                    .map(|tx| tx.nonce())
+                    .collect()
+            };
+            self.head += 1;
+            let new_block = block(self.head, 0xf0, &nonces);
+            self.chain.insert(self.head, new_block);
+            self.built.push(nonces);
+            Ok(BuildPreconfBlockResponse {
+                number: self.head,
+                hash: B256::repeat_byte(0xf0),
+                state_root: B256::ZERO,
+                parent_hash: B256::ZERO,
+                is_forced_inclusion,
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reanchor_with_lazy_fetched_bodies() {
+        let original = [
+            block(11, 1, &[1]),
+            block(12, 2, &[2]),
+            block(13, 3, &[]),
+            block(14, 4, &[3, 4]),
+        ];
+        let mut l2 = FakeL2 {
+            chain: original
+                .iter()
+                .map(|b| (b.header.number, b.clone()))
+                .collect(),
+            head: 10,
+            built: vec![],
+            fetched: Cell::new(0),
+        };
+        let blocks: Vec<Block> = original.iter().map(without_bodies).collect();
+
+        let processed = reanchor_blocks(&mut l2, &blocks, &[false; 4], 10)
+            .await
+            .expect("reanchor failed");
+
+        // A forced inclusion first, then every user transaction exactly once,
+        // although the original heights were rebuilt in between
+        assert_eq!(processed, 4);
+        assert_eq!(l2.built, vec![vec![], vec![1], vec![2], vec![3, 4]]);
+    }
+
+    #[tokio::test]
+    async fn test_reanchor_fetches_only_blocks_within_the_limit() {
+        let original = [
+            block(11, 1, &[1]),
+            block(12, 2, &[2]),
+            block(13, 3, &[3]),
+            block(14, 4, &[4]),
+        ];
+        let mut l2 = FakeL2 {
+            chain: original
+                .iter()
+                .map(|b| (b.header.number, b.clone()))
+                .collect(),
+            head: 10,
+            built: vec![],
+            fetched: Cell::new(0),
+        };
+        let blocks: Vec<Block> = original.iter().map(without_bodies).collect();
+
+        let processed = reanchor_blocks(&mut l2, &blocks, &[false; 4], 2)
+            .await
+            .expect("reanchor failed");
+
+        assert_eq!(processed, 2);
+        assert_eq!(l2.built, vec![vec![], vec![1]]);
+        assert_eq!(l2.fetched.get(), 2);
+    }
+
+    #[test]
+    fn test_lazy_reanchor_fetch_payload_size() {
+        let full_block = block(11, 1, &[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        let hashes_block = without_bodies(&full_block);
+
+        let full_payload = serde_json::to_vec(&full_block).expect("serializable block");
+        let hashes_payload = serde_json::to_vec(&hashes_block).expect("serializable block");
+        assert!(hashes_payload.len() < full_payload.len());
+
+        // Empty blocks can be skipped from hashes, without fetching their body
+        assert!(is_anchor_only_block(&without_bodies(&block(12, 2, &[]))));
+        assert!(!is_anchor_only_block(&hashes_block));
+        assert!(!is_anchor_only_block(&full_block));
+    }
+
+    #[tokio::test]
+    async fn test_reanchor_fails_when_block_changed_before_fetch() {
+        let original = block(11, 1, &[1]);
+        let mut l2 = FakeL2 {
+            chain: BTreeMap::from([(11, block(11, 9, &[5]))]),
+            head: 10,
+            built: vec![],
+            fetched: Cell::new(0),
+        };
+
+        assert!(
+            reanchor_blocks(&mut l2, &[without_bodies(&original)], &[false], 10)
+                .await
+                .is_err()
+        );
+        assert!(l2.built.is_empty());
+    }
+}
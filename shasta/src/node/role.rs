@@ -0,0 +1,89 @@
+use axum::{Router, extract::State, http::header, response::IntoResponse, routing::get};
+use pacaya::node::operator::Status as OperatorStatus;
+use serde_json::{Value, json};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// Latest operator status observed by the node, refreshed every heartbeat.
+#[derive(Clone, Default)]
+pub struct NodeRoleState {
+    status: Arc<RwLock<Option<Value>>>,
+}
+
+impl NodeRoleState {
+    pub async fn update(&self, status: &OperatorStatus) {
+        let role = json!({
+            "preconfer": status.is_preconfer(),
+            "submitter": status.is_submitter(),
+            "synced": status.is_driver_synced(),
+            "preconfirmation_started": status.is_preconfirmation_start_slot(),
+            "end_of_sequencing": status.is_end_of_sequencing(),
+            "role_detail": status.to_string(),
+        });
+        *self.status.write().await = Some(role);
+    }
+
+    /// Returns the last recorded status as JSON, or `None` before the first heartbeat.
+    pub async fn current(&self) -> Option<Value> {
+        self.status.read().await.clone()
+    }
+}
+
+pub fn role_router(state: NodeRoleState) -> Router {
+    Router::new()
+        .route("/role", get(role_handler))
+        .with_state(state)
+}
+
+async fn role_handler(State(state): State<NodeRoleState>) -> impl IntoResponse {
+    let response = state.current().await.unwrap_or(Value::Null);
+    (
+        [(header::CONTENT_TYPE, "application/json")],
+        response.to_string(),
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+
+    #[tokio::test]
+    async fn test_role_endpoint_after_heartbeat() {
+        let state = NodeRoleState::default();
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("failed to bind listener");
+        let url = format!(
+            "http://{}/role",
+            listener.local_addr().expect("failed to get local addr")
+        );
+        let app = role_router(state.clone());
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        let before: Value = reqwest::get(&url)
+            .await
+            .expect("request failed")
+            .json()
+            .await
+            .expect("invalid json");
+        assert_eq!(before, Value::Null);
+
+        // One heartbeat as preconfer, not yet submitter
+        state
+            .update(&OperatorStatus::new(true, false, true, false, true, None))
+            .await;
+
+        let after: Value = reqwest::get(&url)
+            .await
+            .expect("request failed")
+            .json()
+            .await
+            .expect("invalid json");
+        assert_eq!(after["preconfer"], true);
+        assert_eq!(after["submitter"], false);
+        assert_eq!(after["synced"], true);
+        assert_eq!(after["preconfirmation_started"], true);
+    }
+}
@@ -0,0 +1,54 @@
+/// Delays resetting the builder and verifier after the operator role is lost, so a role lost
+/// for a single heartbeat, e.g. due to an RPC flap, does not discard the built proposals.
+pub struct RoleLossCooldown {
+    cooldown_heartbeats: u64,
+    heartbeats_without_role: u64,
+}
+
+impl RoleLossCooldown {
+    /// A cooldown of zero heartbeats resets the state as soon as the role is lost.
+    pub fn new(cooldown_heartbeats: u64) -> Self {
+        Self {
+            cooldown_heartbeats,
+            heartbeats_without_role: 0,
+        }
+    }
+
+    /// Records whether the node holds an operator role in this heartbeat.
+    /// Returns true once the role has been lost for longer than the cooldown.
+    pub fn should_reset(&mut self, has_role: bool) -> bool {
+        if has_role {
+            self.heartbeats_without_role = 0;
+            return false;
+        }
+        self.heartbeats_without_role = self.heartbeats_without_role.saturating_add(1);
+        self.heartbeats_without_role > self.cooldown_heartbeats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_role_flap_for_one_heartbeat_preserves_state() {
+        let mut cooldown = RoleLossCooldown::new(2);
+        assert!(!cooldown.should_reset(true));
+        // Role lost for a single heartbeat
+        assert!(!cooldown.should_reset(false));
+        assert!(!cooldown.should_reset(true));
+
+        // Sustained role loss
+        assert!(!cooldown.should_reset(false));
+        assert!(!cooldown.should_reset(false));
+        assert!(cooldown.should_reset(false));
+        assert!(cooldown.should_reset(false));
+    }
+
+    #[test]
+    fn test_immediate_reset_without_cooldown() {
+        let mut cooldown = RoleLossCooldown::new(0);
+        assert!(!cooldown.should_reset(true));
+        assert!(cooldown.should_reset(false));
+    }
+}
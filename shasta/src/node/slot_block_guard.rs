@@ -0,0 +1,41 @@
+/// Guards against producing more than one block per L2 slot,
+/// e.g. when the heartbeat fires twice within the same slot.
+#[derive(Default)]
+pub struct SlotBlockGuard {
+    last_produced_slot_timestamp: Option<u64>,
+}
+
+impl SlotBlockGuard {
+    /// Returns true if a block for the given slot timestamp can be produced.
+    pub fn can_produce(&self, slot_timestamp: u64) -> bool {
+        self.last_produced_slot_timestamp
+            .is_none_or(|last| slot_timestamp > last)
+    }
+
+    pub fn last_produced_slot_timestamp(&self) -> Option<u64> {
+        self.last_produced_slot_timestamp
+    }
+
+    pub fn record(&mut self, slot_timestamp: u64) {
+        self.last_produced_slot_timestamp = Some(slot_timestamp);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_second_heartbeat_in_same_slot_is_refused() {
+        let mut guard = SlotBlockGuard::default();
+        assert!(guard.can_produce(1000));
+        guard.record(1000);
+
+        // Second heartbeat within the same slot
+        assert!(!guard.can_produce(1000));
+        // Earlier slot timestamp
+        assert!(!guard.can_produce(998));
+        // Next slot
+        assert!(guard.can_produce(1002));
+    }
+}
@@ -0,0 +1,105 @@
+use pacaya::node::operator::Status as OperatorStatus;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusTransition {
+    BecamePreconfer,
+    LostPreconfer,
+    BecameSubmitter,
+    LostSubmitter,
+    EndOfSequencingReached,
+}
+
+impl StatusTransition {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StatusTransition::BecamePreconfer => "became_preconfer",
+            StatusTransition::LostPreconfer => "lost_preconfer",
+            StatusTransition::BecameSubmitter => "became_submitter",
+            StatusTransition::LostSubmitter => "lost_submitter",
+            StatusTransition::EndOfSequencingReached => "end_of_sequencing_reached",
+        }
+    }
+}
+
+#[derive(Default, Clone, Copy)]
+struct Roles {
+    preconfer: bool,
+    submitter: bool,
+    end_of_sequencing: bool,
+}
+
+/// Tracks the operator status between heartbeats, so role changes
+/// are reported once at the transition instead of every slot.
+#[derive(Default)]
+pub struct StatusTransitionTracker {
+    previous: Roles,
+}
+
+impl StatusTransitionTracker {
+    /// Records the current status and returns the transitions since the previous one.
+    pub fn observe(&mut self, status: &OperatorStatus) -> Vec<StatusTransition> {
+        let current = Roles {
+            preconfer: status.is_preconfer(),
+            submitter: status.is_submitter(),
+            end_of_sequencing: status.is_end_of_sequencing(),
+        };
+        let previous = std::mem::replace(&mut self.previous, current);
+
+        let mut transitions = Vec::new();
+        match (previous.preconfer, current.preconfer) {
+            (false, true) => transitions.push(StatusTransition::BecamePreconfer),
+            (true, false) => transitions.push(StatusTransition::LostPreconfer),
+            _ => {}
+        }
+        match (previous.submitter, current.submitter) {
+            (false, true) => transitions.push(StatusTransition::BecameSubmitter),
+            (true, false) => transitions.push(StatusTransition::LostSubmitter),
+            _ => {}
+        }
+        if !previous.end_of_sequencing && current.end_of_sequencing {
+            transitions.push(StatusTransition::EndOfSequencingReached);
+        }
+        transitions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(preconfer: bool, submitter: bool, end_of_sequencing: bool) -> OperatorStatus {
+        OperatorStatus::new(preconfer, submitter, false, end_of_sequencing, true, None)
+    }
+
+    #[test]
+    fn test_one_event_per_transition() {
+        let mut tracker = StatusTransitionTracker::default();
+        let sequence = [
+            status(false, false, false),
+            status(true, false, false),
+            status(true, false, false),
+            status(true, true, false),
+            status(true, true, false),
+            status(true, true, true),
+            status(true, true, true),
+            status(false, true, false),
+            status(false, false, false),
+        ];
+
+        let transitions: Vec<StatusTransition> = sequence
+            .iter()
+            .flat_map(|status| tracker.observe(status))
+            .collect();
+
+        assert_eq!(
+            transitions,
+            vec![
+                StatusTransition::BecamePreconfer,
+                StatusTransition::BecameSubmitter,
+                StatusTransition::EndOfSequencingReached,
+                StatusTransition::LostPreconfer,
+                StatusTransition::LostSubmitter,
+            ]
+        );
+    }
+}
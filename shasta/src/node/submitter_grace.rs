@@ -0,0 +1,63 @@
+/// Lets the node finish submitting proposals it built as the submitter when the submitter
+/// status flaps, instead of stranding them until the role is regained.
+pub struct SubmitterGraceWindow {
+    grace_window_sec: u64,
+    last_submitter_timestamp: Option<u64>,
+}
+
+impl SubmitterGraceWindow {
+    /// A grace window of zero seconds disables submissions without the submitter role.
+    pub fn new(grace_window_sec: u64) -> Self {
+        Self {
+            grace_window_sec,
+            last_submitter_timestamp: None,
+        }
+    }
+
+    /// Records the submitter status for the given slot. Returns true when the node is not the
+    /// submitter but still holds unsent proposals and was the submitter within the grace window.
+    pub fn is_in_grace_window(
+        &mut self,
+        is_submitter: bool,
+        has_unsent_proposals: bool,
+        slot_timestamp: u64,
+    ) -> bool {
+        if is_submitter {
+            self.last_submitter_timestamp = Some(slot_timestamp);
+            return false;
+        }
+        has_unsent_proposals
+            && self.grace_window_sec > 0
+            && self
+                .last_submitter_timestamp
+                .is_some_and(|last| slot_timestamp.saturating_sub(last) <= self.grace_window_sec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_submitter_status_flap_with_pending_proposals() {
+        let mut grace = SubmitterGraceWindow::new(24);
+        assert!(!grace.is_in_grace_window(true, true, 1000));
+
+        // Submitter status lost for one slot while holding unsent proposals
+        assert!(grace.is_in_grace_window(false, true, 1012));
+        assert!(!grace.is_in_grace_window(true, true, 1024));
+
+        // Nothing left to submit
+        assert!(!grace.is_in_grace_window(false, false, 1036));
+        // Grace window elapsed
+        assert!(grace.is_in_grace_window(false, true, 1048));
+        assert!(!grace.is_in_grace_window(false, true, 1060));
+    }
+
+    #[test]
+    fn test_disabled_grace_window() {
+        let mut grace = SubmitterGraceWindow::new(0);
+        assert!(!grace.is_in_grace_window(true, true, 1000));
+        assert!(!grace.is_in_grace_window(false, true, 1000));
+    }
+}
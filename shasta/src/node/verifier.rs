@@ -19,6 +19,18 @@ pub enum VerificationResult {
     VerificationInProgress,
 }
 
+impl VerificationResult {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VerificationResult::SuccessNoProposals => "success_no_proposals",
+            VerificationResult::SuccessWithProposals(_) => "success_with_proposals",
+            VerificationResult::ReanchorNeeded(..) => "reanchor_needed",
+            VerificationResult::SlotNotValid => "slot_not_valid",
+            VerificationResult::VerificationInProgress => "verification_in_progress",
+        }
+    }
+}
+
 #[derive(Clone)]
 struct PreconfirmationRootBlock {
     number: u64,
@@ -26,7 +38,8 @@ struct PreconfirmationRootBlock {
 }
 
 pub struct Verifier {
-    verification_timestamp: u64,
+    /// L1 timestamp after which verification may start, `None` to verify immediately.
+    verification_timestamp: Option<u64>,
     verifier_thread: Option<VerifierThread>,
     verifier_thread_handle: Option<JoinHandle<Result<Proposals, Error>>>,
     last_safe_l2_block_finder: Arc<LastSafeL2BlockFinder>,
@@ -44,13 +57,13 @@ impl Verifier {
         l2_height: u64,
         taiko: Arc<Taiko>,
         proposal_manager: ProposalManager,
-        verification_timestamp: u64,
+        verification_timestamp: Option<u64>,
         cancel_token: CancellationToken,
         last_safe_l2_block_finder: Arc<LastSafeL2BlockFinder>,
     ) -> Result<Self, Error> {
         let hash = taiko.get_l2_block_hash(l2_height).await?;
         debug!(
-            "Verifier created with l2_height: {}, hash: {}, verification_timestamp: {}",
+            "Verifier created with l2_height: {}, hash: {}, verification_timestamp: {:?}",
             l2_height, hash, verification_timestamp
         );
         let preconfirmation_root = PreconfirmationRootBlock {
@@ -110,14 +123,19 @@ impl Verifier {
                 Ok(VerificationResult::VerificationInProgress)
             }
         } else {
-            let taiko_inbox_height = self
-                .last_safe_l2_block_finder
-                .get_when_timestamp_reached(self.verification_timestamp)
-                .await?;
+            let taiko_inbox_height = resolve_verification_height(
+                self.verification_timestamp,
+                |timestamp| {
+                    self.last_safe_l2_block_finder
+                        .get_when_timestamp_reached(timestamp)
+                },
+                self.last_safe_l2_block_finder.get(),
+            )
+            .await?;
 
             let Some(taiko_inbox_height) = taiko_inbox_height else {
                 info!(
-                    "Taiko inbox height is not yet reached for verification timestamp {}, skipping",
+                    "Taiko inbox height is not yet reached for verification timestamp {:?}, skipping",
                     self.verification_timestamp
                 );
                 return Ok(VerificationResult::SlotNotValid);
@@ -131,6 +149,75 @@ impl Verifier {
     }
 }
 
+/// Returns the Taiko inbox height to verify against. Without a verification timestamp the
+/// current height is used right away, otherwise `None` is returned until the timestamp is reached.
+async fn resolve_verification_height<F>(
+    verification_timestamp: Option<u64>,
+    when_timestamp_reached: impl FnOnce(u64) -> F,
+    immediate: impl Future<Output = Result<u64, Error>>,
+) -> Result<Option<u64>, Error>
+where
+    F: Future<Output = Result<Option<u64>, Error>>,
+{
+    match verification_timestamp {
+        Some(timestamp) => when_timestamp_reached(timestamp).await,
+        None => immediate.await.map(Some),
+    }
+}
+
+/// Verification of the unsent proposals, implemented by the `Verifier`.
+pub(super) trait VerifyProposals {
+    async fn verify(&mut self, metrics: Arc<Metrics>) -> Result<VerificationResult, Error>;
+}
+
+impl VerifyProposals for Verifier {
+    async fn verify(&mut self, metrics: Arc<Metrics>) -> Result<VerificationResult, Error> {
+        Verifier::verify(self, metrics).await
+    }
+}
+
+/// Node actions taken on a finished verification.
+pub(super) trait VerificationOutcome {
+    async fn reanchor(&mut self, parent_block_id: u64, reason: &str) -> Result<(), Error>;
+
+    fn prepend_proposals(&mut self, proposals: Proposals);
+}
+
+/// Runs the verifier, if any, and applies its outcome. Returns true when the unsent proposals
+/// are verified and can be submitted. The verifier is kept until its verification finishes.
+pub(super) async fn has_verified_unsent_proposals<V, O>(
+    verifier: &mut Option<V>,
+    outcome: &mut O,
+    metrics: Arc<Metrics>,
+) -> Result<bool, Error>
+where
+    V: VerifyProposals,
+    O: VerificationOutcome,
+{
+    let Some(active_verifier) = verifier.as_mut() else {
+        return Ok(true);
+    };
+    let res = active_verifier.verify(metrics.clone()).await?;
+    metrics.inc_verification_result(res.as_str());
+    match res {
+        VerificationResult::SlotNotValid | VerificationResult::VerificationInProgress => Ok(false),
+        VerificationResult::ReanchorNeeded(block, reason) => {
+            *verifier = None;
+            outcome.reanchor(block, &reason).await?;
+            Ok(true)
+        }
+        VerificationResult::SuccessWithProposals(proposals) => {
+            *verifier = None;
+            outcome.prepend_proposals(proposals);
+            Ok(true)
+        }
+        VerificationResult::SuccessNoProposals => {
+            *verifier = None;
+            Ok(true)
+        }
+    }
+}
+
 impl VerifierThread {
     async fn verify_submitted_blocks(
         &mut self,
@@ -240,3 +327,115 @@ impl VerifierThread {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_verify_immediately_without_timestamp() {
+        let height = resolve_verification_height(
+            None,
+            |_| async {
+                Err::<Option<u64>, Error>(anyhow::anyhow!("timestamp should not be checked"))
+            },
+            async { Ok::<u64, Error>(42) },
+        )
+        .await
+        .expect("should resolve height");
+        assert_eq!(height, Some(42));
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_verification_timestamp() {
+        let height = resolve_verification_height(
+            Some(1_000),
+            |timestamp| async move {
+                assert_eq!(timestamp, 1_000);
+                Ok::<Option<u64>, Error>(None)
+            },
+            async { Ok::<u64, Error>(42) },
+        )
+        .await
+        .expect("should resolve height");
+        assert_eq!(height, None);
+    }
+
+    /// Verifier returning the given result once.
+    struct FakeVerifier(Option<VerificationResult>);
+
+    impl VerifyProposals for FakeVerifier {
+        async fn verify(&mut self, _metrics: Arc<Metrics>) -> Result<VerificationResult, Error> {
+            self.0
+                .take()
+                .ok_or_else(|| anyhow::anyhow!("verified twice"))
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordedOutcome {
+        reanchors: Vec<(u64, String)>,
+        prepended_proposals: usize,
+    }
+
+    impl VerificationOutcome for RecordedOutcome {
+        async fn reanchor(&mut self, parent_block_id: u64, reason: &str) -> Result<(), Error> {
+            self.reanchors.push((parent_block_id, reason.to_string()));
+            Ok(())
+        }
+
+        fn prepend_proposals(&mut self, _proposals: Proposals) {
+            self.prepended_proposals += 1;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_verification_result_metric_per_variant() {
+        let metrics = Arc::new(Metrics::new());
+        let mut outcome = RecordedOutcome::default();
+        // Result, whether the proposals are verified, and whether the verifier is kept
+        let cases = [
+            (VerificationResult::SuccessNoProposals, true, false),
+            (
+                VerificationResult::SuccessWithProposals(Proposals::new()),
+                true,
+                false,
+            ),
+            (
+                VerificationResult::ReanchorNeeded(10, "hash mismatch".to_string()),
+                true,
+                false,
+            ),
+            (VerificationResult::SlotNotValid, false, true),
+            (VerificationResult::VerificationInProgress, false, true),
+            (VerificationResult::VerificationInProgress, false, true),
+        ];
+        for (result, expected_verified, expected_kept) in cases {
+            let label = result.as_str();
+            let mut verifier = Some(FakeVerifier(Some(result)));
+
+            let verified =
+                has_verified_unsent_proposals(&mut verifier, &mut outcome, metrics.clone())
+                    .await
+                    .expect("verification failed");
+
+            assert_eq!(verified, expected_verified, "{label}");
+            assert_eq!(verifier.is_some(), expected_kept, "{label}");
+        }
+        assert_eq!(outcome.reanchors, vec![(10, "hash mismatch".to_string())]);
+        assert_eq!(outcome.prepended_proposals, 1);
+
+        let gathered = metrics.gather();
+        for (label, count) in [
+            ("success_no_proposals", 1),
+            ("success_with_proposals", 1),
+            ("reanchor_needed", 1),
+            ("slot_not_valid", 1),
+            ("verification_in_progress", 2),
+        ] {
+            assert!(gathered.contains(&format!(
+                "catalyst_verification_results_total{{result=\"{label}\"}} {count}"
+            )));
+        }
+    }
+}